@@ -1,4 +1,4 @@
-from typing import (Callable, Iterator, Literal, Mapping, Optional, Sequence,
+from typing import (Any, Callable, Iterator, Literal, Mapping, Optional, Sequence,
                     Set, TypedDict, Union, overload)
 
 LOG_NOTHING: Literal[0]
@@ -1396,6 +1396,10 @@ class AsynchronousGraph:
     def restrict(self, set: Union[ColoredVertexSet, VertexSet, ColorSet]) -> AsynchronousGraph: ...
 
 class TrapSpaces:
+    """
+    **Deprecated**: Use `TrapSpacesComp` instead. The methods of this class are forwarded to `TrapSpacesComp`
+    with default configuration and emit a `DeprecationWarning`.
+    """
     @staticmethod
     def minimize(ctx: SymbolicSpaceContext, set: ColoredSpaceSet) -> ColoredSpaceSet: ...
     @staticmethod
@@ -1406,6 +1410,10 @@ class TrapSpaces:
     def minimal_symbolic(ctx: SymbolicSpaceContext, graph: AsynchronousGraph, restriction: Optional[ColoredSpaceSet] = None) -> ColoredSpaceSet: ...
 
 class FixedPoints:
+    """
+    **Deprecated**: Use `FixedPointsComp` instead. The methods of this class are forwarded to `FixedPointsComp`
+    with default configuration and emit a `DeprecationWarning`.
+    """
     @staticmethod
     def symbolic(graph: AsynchronousGraph, set: Optional[ColoredVertexSet] = None) -> ColoredVertexSet: ...
     @staticmethod
@@ -1414,6 +1422,10 @@ class FixedPoints:
     def symbolic_colors(graph: AsynchronousGraph, set: Optional[ColoredVertexSet] = None) -> ColorSet: ...
 
 class Attractors:
+    """
+    **Deprecated**: Use `AttractorsComp` instead. The methods of this class are forwarded to `AttractorsComp`
+    with default configuration and emit a `DeprecationWarning`.
+    """
     @staticmethod
     def attractors(graph: AsynchronousGraph, restriction: Optional[ColoredVertexSet] = None, to_reduce: Optional[Sequence[VariableIdType]] = None) -> list[ColoredVertexSet]: ...
     @staticmethod
@@ -1422,10 +1434,18 @@ class Attractors:
     def xie_beerel(graph: AsynchronousGraph, restriction: Optional[ColoredVertexSet] = None) -> list[ColoredVertexSet]: ...
 
 class Percolation:
+    """
+    **Deprecated**: Use `PercolationComp` instead. The methods of this class are forwarded to `PercolationComp`
+    with default configuration and emit a `DeprecationWarning`.
+    """
     @staticmethod
     def percolate_subspace(graph: AsynchronousGraph, subspace: Union[Mapping[VariableId, BoolType], Mapping[str, BoolType]]) -> dict[VariableId, bool]: ...
 
 class Reachability:
+    """
+    **Deprecated**: Use `ReachabilityComp` instead. The methods of this class are forwarded to `ReachabilityComp`
+    with default configuration and emit a `DeprecationWarning`.
+    """
     @staticmethod
     def reach_fwd(graph: AsynchronousGraph, initial: ColoredVertexSet) -> ColoredVertexSet: ...
     @staticmethod
@@ -1650,6 +1670,16 @@ class Classification:
     @staticmethod
     def load_classification(path: str) -> tuple[BooleanNetwork, dict[Class, ColorSet], ModelAnnotation]: ...
     @staticmethod
+    def refine_classification(path: str, network: BooleanNetwork, classification: dict[Class, ColorSet], annotations: ModelAnnotation, properties: Mapping[str, str | HctlFormula]) -> dict[Class, ColorSet]: ...
+    @staticmethod
+    def classify(model_path: str, output_zip: str) -> None: ...
+    @staticmethod
+    def diff_classification(left: dict[Class, ColorSet], right: dict[Class, ColorSet]) -> dict[tuple[Optional[Class], Optional[Class]], ColorSet]: ...
+    @staticmethod
+    def merge_classification(left: dict[Class, ColorSet], right: dict[Class, ColorSet]) -> dict[Class, ColorSet]: ...
+    @staticmethod
+    def check_compatibility(left_network: BooleanNetwork, left: dict[Class, ColorSet], right_network: BooleanNetwork, right: dict[Class, ColorSet]) -> list[str]: ...
+    @staticmethod
     def classify_long_term_behavior(graph: AsynchronousGraph, component: ColoredVertexSet) -> dict[Class, ColorSet]: ...
     @staticmethod
     def classify_attractor_bifurcation(graph: AsynchronousGraph, attractor: Optional[list[ColoredVertexSet]] = None) -> dict[Class, ColorSet]: ...
@@ -1847,6 +1877,153 @@ class NamedRegulation(TypedDict):
     essential: BoolType
 Regulation = Union[IdRegulation, NamedRegulation]
 
+class CancelledError(Exception):
+    """
+    Raised when an algorithm is cancelled, e.g. because its time limit has been exceeded.
+
+    The `partial_result` attribute contains the result that was computed before the
+    cancellation. Its type depends on the algorithm (e.g. `ColoredVertexSet` for reachability,
+    `list[ColoredVertexSet]` for attractors, or `ColoredSpaceSet` for trap spaces). For most
+    algorithms, this is an over-approximation (or a subset) of the full result, so it can still
+    be useful when the computation times out.
+    """
+    partial_result: Any
+
+class StepsLimitExceededError(Exception):
+    """
+    Raised when an algorithm exceeds its steps limit. The `partial_result` attribute has the
+    same meaning as in `CancelledError`.
+    """
+    partial_result: Any
+
+class BddSizeLimitExceededError(Exception):
+    """
+    Raised when an algorithm exceeds its BDD size limit. The `partial_result` attribute has the
+    same meaning as in `CancelledError`.
+    """
+    partial_result: Any
+
+class CancelToken:
+    """
+    A cancellation token that can be passed to the `with_cancellation` method of any algorithm
+    configuration object (e.g. `ReachabilityConfig.with_cancellation`).
+
+    The same token can be shared by multiple configurations. This way, a whole pipeline of
+    algorithms can be limited by a single deadline, memory limit, or step budget. Tokens can be
+    combined using `CancelToken.any_of` and `CancelToken.all_of`. Every token can be also
+    cancelled manually using `CancelToken.cancel`.
+
+    Tokens created using `CancelToken.timer` can be additionally paused, resumed, extended, or
+    reset. This way, a single time budget can be split between multiple computations.
+    """
+    @staticmethod
+    def manual() -> CancelToken:
+        """
+        A token that is only cancelled manually, using `CancelToken.cancel`.
+        """
+    @staticmethod
+    def timer(duration_in_millis: int) -> CancelToken:
+        """
+        A token that is cancelled once the given time limit (in milliseconds) elapses.
+        The timer starts when the token is first used by an algorithm (or when
+        `CancelToken.start` is called).
+        """
+    @staticmethod
+    def memory(rss_limit: Optional[int] = None, bdd_size_limit: Optional[int] = None) -> CancelToken:
+        """
+        A token that is cancelled once the resident set size of the process exceeds `rss_limit`
+        bytes, or once an algorithm reports an intermediate result with more than `bdd_size_limit`
+        BDD nodes.
+
+        The `rss_limit` is currently only supported on Linux and ignored elsewhere.
+        """
+    @staticmethod
+    def step_budget(steps: int) -> CancelToken:
+        """
+        A token that is cancelled once the algorithms using it perform `steps` steps in total.
+        A step corresponds to a single `ProgressEvent`.
+        """
+    @staticmethod
+    def any_of(tokens: list[CancelToken]) -> CancelToken:
+        """
+        A token that is cancelled once at least one of the given `tokens` is cancelled.
+        """
+    @staticmethod
+    def all_of(tokens: list[CancelToken]) -> CancelToken:
+        """
+        A token that is cancelled once all of the given `tokens` are cancelled.
+        """
+    def cancel(self) -> bool:
+        """
+        Cancel this token manually. Returns `True` if the token was not cancelled manually before.
+        """
+    def is_cancelled(self) -> bool:
+        """
+        Returns `True` if this token is cancelled.
+        """
+    def start(self) -> bool:
+        """
+        Start the timer of this token. Returns `False` if the timer was already started.
+
+        Raises `TypeError` if this token was not created using `CancelToken.timer`. The same
+        applies to all remaining timer methods.
+        """
+    def pause(self) -> bool:
+        """
+        Pause the timer of this token. Returns `False` if the timer was not running.
+        """
+    def resume(self) -> bool:
+        """
+        Resume the paused timer of this token. Returns `False` if the timer was not paused.
+        """
+    def reset(self) -> None:
+        """
+        Reset the timer of this token: the elapsed time is cleared and the timer is stopped.
+        """
+    def extend(self, duration_in_millis: int) -> None:
+        """
+        Increase the time limit of the timer of this token by the given number of milliseconds.
+        """
+    def elapsed_millis(self) -> int:
+        """
+        The time (in milliseconds) that counted towards the time limit of this token so far.
+        """
+    def remaining_millis(self) -> int:
+        """
+        The time (in milliseconds) remaining until this token is cancelled by its timer.
+        """
+
+class ProgressEvent:
+    """
+    A single progress update of a long-running algorithm. Progress events are reported to the
+    `progress` callback of the algorithm configuration objects (e.g. `ReachabilityConfig.with_progress`).
+
+    The callback can be either a function that accepts a `ProgressEvent`, or a `tqdm` progress
+    bar. In the second case, the counter of the bar is set to `step` and the remaining data
+    is shown as the bar postfix.
+    """
+    @property
+    def target(self) -> str:
+        """
+        The algorithm that reported the event, e.g. `Reachability::forward_closed_superset`.
+        """
+    @property
+    def step(self) -> int:
+        """
+        The number of steps performed so far. The meaning of a step depends on the algorithm.
+        """
+    @property
+    def bdd_size(self) -> int:
+        """
+        The BDD size of the current intermediate result.
+        """
+    @property
+    def candidates(self) -> float:
+        """
+        The approximate number of elements in the current intermediate result (e.g. reached
+        states or remaining candidates).
+        """
+
 class ReachabilityConfig:
     """
     A configuration class for the `ReachabilityComp` class. It allows you to specify various
@@ -1863,15 +2040,19 @@ class ReachabilityConfig:
         graph_representation: Union[AsynchronousGraph, BooleanNetwork],
         subgraph: Optional[ColoredVertexSet] = None,
         variables: Optional[Set[VariableId]] = None,
+        strategy: Optional[Literal["saturation", "bfs", "chaining"]] = None,
+        variable_order: Optional[list[VariableId]] = None,
         time_limit_millis: Optional[int] = None,
         bdd_size_limit: Optional[int] = None,
         steps_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
     ) -> None:
         """
         Create a new `ReachabilityConfig` object. The `graph_representation` parameter is required and
         can be either an `AsynchronousGraph` or a `BooleanNetwork`. The other parameters
-        are optional and can be used to specify a subgraph, a set of variables, a time limit,
-        a BDD size limit, and a steps limit for the reachability analysis.
+        are optional and can be used to specify a subgraph, a set of variables, a reachability strategy,
+        a custom variable order, a time limit, a BDD size limit, and a steps limit for the reachability analysis.
 
         For the meaning of the parameters, see the documentation of their respective with_
         methods (e.g. `with_subgraph`, `with_variables`, etc.).
@@ -1880,7 +2061,11 @@ class ReachabilityConfig:
     def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> ReachabilityConfig: ...
     def with_subgraph(self, subgraph: ColoredVertexSet) -> ReachabilityConfig: ...
     def with_variables(self, variables: Set[VariableId]) -> ReachabilityConfig: ...
+    def with_strategy(self, strategy: Literal["saturation", "bfs", "chaining"]) -> ReachabilityConfig: ...
+    def with_variable_order(self, variable_order: list[VariableId]) -> ReachabilityConfig: ...
     def with_time_limit(self, duration_in_millis: int) -> ReachabilityConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> ReachabilityConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> ReachabilityConfig: ...
     def with_bdd_size_limit(self, bdd_size_limit: int) -> ReachabilityConfig: ...
     def with_steps_limit(self, steps_limit: int) -> ReachabilityConfig: ...
 
@@ -1898,6 +2083,14 @@ class ReachabilityComp:
     def backward_closed_superset(self, initial: ColoredVertexSet) -> ColoredVertexSet: ...
     def forward_closed_subset(self, initial: ColoredVertexSet) -> ColoredVertexSet: ...
     def backward_closed_subset(self, initial: ColoredVertexSet) -> ColoredVertexSet: ...
+    def forward_layers(self, initial: ColoredVertexSet, max_depth: Optional[int] = None) -> list[ColoredVertexSet]: ...
+    def backward_layers(self, initial: ColoredVertexSet, max_depth: Optional[int] = None) -> list[ColoredVertexSet]: ...
+    def witness_path(
+        self,
+        source: ColoredVertexSet,
+        target: ColoredVertexSet,
+        color: Optional[ColorModel] = None,
+    ) -> Optional[tuple[list[VertexModel], list[VariableId]]]: ...
 
 class PercolationConfig:
     """
@@ -1914,6 +2107,8 @@ class PercolationConfig:
         self,
         graph_representation: Union[AsynchronousGraph, BooleanNetwork],
         time_limit_millis: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
     ) -> None:
         """
         Create a new `PercolationConfig` object. The `graph_representation` parameter is required and
@@ -1926,6 +2121,8 @@ class PercolationConfig:
     @staticmethod
     def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> PercolationConfig: ...
     def with_time_limit(self, duration_in_millis: int) -> PercolationConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> PercolationConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> PercolationConfig: ...
 
 class PercolationComp:
     """
@@ -1939,6 +2136,142 @@ class PercolationComp:
         self,
         subspace: Union[list[tuple[VariableId, bool]], dict[VariableId, bool]]
     ) -> dict[VariableId, bool]: ...
+    def percolate_spaces(
+        self,
+        spaces: SpaceSet
+    ) -> list[tuple[dict[VariableId, bool], dict[VariableId, bool], list[VariableId], bool]]:
+        """
+        Percolate every space of `spaces`. For each space, the result contains an
+        `(original, percolated, conflicts, is_trap_space)` tuple, where `conflicts` are the
+        variables fixed in `original` whose value percolates to the opposite value.
+        """
+    def percolate_symbolic(self, spaces: ColoredSpaceSet) -> ColoredSpaceSet:
+        """
+        Percolate every space of `spaces` separately for each of its colours. Unlike
+        `percolate_subspace`, this also percolates parameters of partially specified networks.
+        The `SymbolicSpaceContext` of `spaces` must be compatible with the underlying graph.
+        """
+
+class AttractorsConfig:
+    def __init__(
+        self,
+        graph_representation: Union[AsynchronousGraph, BooleanNetwork],
+        restriction: Optional[ColoredVertexSet] = None,
+        active_variables: Optional[list[VariableIdType]] = None,
+        reduced_variables: Optional[list[VariableIdType]] = None,
+        time_limit_millis: Optional[int] = None,
+        bdd_size_limit: Optional[int] = None,
+        steps_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
+    ) -> None:
+        """
+        Create a new `AttractorsConfig` object. The `graph_representation` parameter is required and
+        specifies the underlying `AsynchronousGraph` or `BooleanNetwork`. The other parameters are optional
+        and can be used to specify a restriction, active and reduced variables, a time limit, a BDD size limit,
+        and a steps limit for the attractor computation.
+
+        For the meaning of the parameters, see the documentation of their respective with_
+        methods (e.g. `with_restriction`, `with_time_limit`, etc.).
+        """
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> AttractorsConfig: ...
+    def with_restriction(self, restriction: ColoredVertexSet) -> AttractorsConfig: ...
+    def with_active_variables(self, active_variables: list[VariableIdType]) -> AttractorsConfig: ...
+    def with_reduced_variables(self, reduced_variables: list[VariableIdType]) -> AttractorsConfig: ...
+    def with_time_limit(self, duration_in_millis: int) -> AttractorsConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> AttractorsConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> AttractorsConfig: ...
+    def with_bdd_size_limit(self, bdd_size_limit: int) -> AttractorsConfig: ...
+    def with_steps_limit(self, steps_limit: int) -> AttractorsConfig: ...
+
+class AttractorsComp:
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> AttractorsComp: ...
+    @staticmethod
+    def with_config(config: AttractorsConfig) -> AttractorsComp: ...
+    def transition_guided_reduction(self) -> ColoredVertexSet: ...
+    def xie_beerel(self) -> list[ColoredVertexSet]: ...
+    def xie_beerel_iter(self) -> AttractorsIterator: ...
+    def attractors(self) -> list[ColoredVertexSet]: ...
+    def attractors_iter(self) -> AttractorsIterator: ...
+
+class AttractorsIterator:
+    def __iter__(self) -> AttractorsIterator: ...
+    def __next__(self) -> ColoredVertexSet: ...
+    def found(self) -> list[ColoredVertexSet]: ...
+
+class BasinsConfig:
+    def __init__(
+        self,
+        graph_representation: Union[AsynchronousGraph, BooleanNetwork],
+        time_limit_millis: Optional[int] = None,
+        bdd_size_limit: Optional[int] = None,
+        steps_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
+    ) -> None:
+        """
+        Create a new `BasinsConfig` object. The `graph_representation` parameter is required and
+        specifies the underlying `AsynchronousGraph` or `BooleanNetwork`. The other parameters are optional
+        and can be used to specify a time limit, a BDD size limit, and a steps limit for the basin computation.
+
+        For the meaning of the parameters, see the documentation of their respective with_
+        methods (e.g. `with_time_limit`, `with_bdd_size_limit`, etc.).
+        """
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> BasinsConfig: ...
+    def with_time_limit(self, duration_in_millis: int) -> BasinsConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> BasinsConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> BasinsConfig: ...
+    def with_bdd_size_limit(self, bdd_size_limit: int) -> BasinsConfig: ...
+    def with_steps_limit(self, steps_limit: int) -> BasinsConfig: ...
+
+class BasinsComp:
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> BasinsComp: ...
+    @staticmethod
+    def with_config(config: BasinsConfig) -> BasinsComp: ...
+    def basins(
+        self,
+        attractors: list[ColoredVertexSet]
+    ) -> list[tuple[ColoredVertexSet, ColoredVertexSet, ColoredVertexSet]]: ...
+
+class SccDecompositionConfig:
+    def __init__(
+        self,
+        graph_representation: Union[AsynchronousGraph, BooleanNetwork],
+        restriction: Optional[ColoredVertexSet] = None,
+        time_limit_millis: Optional[int] = None,
+        bdd_size_limit: Optional[int] = None,
+        steps_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
+    ) -> None:
+        """
+        Create a new `SccDecompositionConfig` object. The `graph_representation` parameter is required and
+        specifies the underlying `AsynchronousGraph` or `BooleanNetwork`. The other parameters are optional
+        and can be used to specify a restriction, a time limit, a BDD size limit, and a steps limit
+        for the SCC decomposition.
+
+        For the meaning of the parameters, see the documentation of their respective with_
+        methods (e.g. `with_restriction`, `with_time_limit`, etc.).
+        """
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> SccDecompositionConfig: ...
+    def with_restriction(self, restriction: ColoredVertexSet) -> SccDecompositionConfig: ...
+    def with_time_limit(self, duration_in_millis: int) -> SccDecompositionConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> SccDecompositionConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> SccDecompositionConfig: ...
+    def with_bdd_size_limit(self, bdd_size_limit: int) -> SccDecompositionConfig: ...
+    def with_steps_limit(self, steps_limit: int) -> SccDecompositionConfig: ...
+
+class SccDecompositionComp:
+    @staticmethod
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> SccDecompositionComp: ...
+    @staticmethod
+    def with_config(config: SccDecompositionConfig) -> SccDecompositionComp: ...
+    def decomposition(self) -> list[ColoredVertexSet]: ...
 
 class FixedPointsConfig:
     def __init__(
@@ -1947,6 +2280,8 @@ class FixedPointsConfig:
         restriction: Optional[ColoredVertexSet] = None,
         time_limit_millis: Optional[int] = None,
         bdd_size_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
     ) -> None:
         """
         Create a new `FixedPointsConfig` object. The `graph_representation` parameter is required and
@@ -1961,6 +2296,8 @@ class FixedPointsConfig:
     def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> FixedPointsConfig: ...
     def with_restriction(self, restriction: ColoredVertexSet) -> FixedPointsConfig: ...
     def with_time_limit(self, duration_in_millis: int) -> FixedPointsConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> FixedPointsConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> FixedPointsConfig: ...
     def with_bdd_size_limit(self, bdd_size_limit: int) -> FixedPointsConfig: ...
 
 class FixedPointsComp:
@@ -1972,25 +2309,55 @@ class FixedPointsComp:
     def symbolic(self) -> ColoredVertexSet: ...
     def symbolic_vertices(self) -> VertexSet: ...
     def symbolic_colors(self) -> ColorSet: ...
+    def solver_iterator(self) -> FixedPointsSolverIterator:
+        """
+        Enumerate the fixed points within `restriction` one by one using the Z3 solver. Each item
+        is a `(ColorModel, VertexModel)` pair, such that the vertex is a fixed-point for the
+        given color.
+
+        This avoids the symbolic explosion of `symbolic()` on large networks, but each fixed
+        point has to be enumerated explicitly.
+        """
+    def symbolic_projection(
+        self,
+        retained_variables: Optional[Sequence[VariableIdType]] = None,
+        retained_functions: Optional[Sequence[Union[VariableIdType, ParameterIdType]]] = None,
+    ) -> ColoredVertexSet:
+        """
+        Compute the set of fixed-points projected onto the `retained_variables` and
+        `retained_functions`. The result contains every vertex-color pair that agrees with some
+        fixed-point on all retained variables and functions. To enumerate the unique combinations
+        of the retained values, use `ColoredVertexSet.items` with the same arguments.
+
+        If a collection is not given, all variables (or functions) are retained.
+        """
+
+class FixedPointsSolverIterator:
+    def __iter__(self) -> FixedPointsSolverIterator: ...
+    def __next__(self) -> tuple[ColorModel, VertexModel]: ...
+    def found(self) -> ColoredVertexSet: ...
 
 class TrapSpacesConfig:
     def __init__(
         self,
-        graph_representation: BooleanNetwork,
+        graph_representation: Union[AsynchronousGraph, BooleanNetwork],
         restriction: Optional[ColoredSpaceSet] = None,
         time_limit_millis: Optional[int] = None,
         bdd_size_limit: Optional[int] = None,
+        cancellation: Optional[CancelToken] = None,
+        progress: Optional[Any] = None,
     ) -> None:
         """
         Create a new `TrapSpacesConfig` object. The `graph_representation` parameter is required and
-        specifies the underlying `BooleanNetwork`, from which an `AsynchronousGraph` with its `SymbolicSpaceContext`
-        is created. The other parameters are optional and can be used to specify a restriction,
+        specifies the underlying `BooleanNetwork` or `AsynchronousGraph`, from which an `AsynchronousGraph` with
+        its `SymbolicSpaceContext` is created. For an `AsynchronousGraph`, only spaces within its
+        `unit_colored_vertices` are considered by default. The other parameters are optional and can be used to specify a restriction,
         a time limit, and a BDD size limit for the trap spaces computation.
         For the meaning of the parameters, see the documentation of their respective with_
         methods (e.g. `with_restriction`, `with_time_limit`, etc.).
         """
     @staticmethod
-    def create_from(graph_representation: BooleanNetwork) -> TrapSpacesConfig: ...
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> TrapSpacesConfig: ...
     @staticmethod
     def create_from_graph_with_context(
         graph: AsynchronousGraph,
@@ -1998,15 +2365,46 @@ class TrapSpacesConfig:
     ) -> TrapSpacesConfig: ...
     def with_restriction(self, restriction: ColoredSpaceSet) -> TrapSpacesConfig: ...
     def with_time_limit(self, duration_in_millis: int) -> TrapSpacesConfig: ...
+    def with_cancellation(self, cancellation: CancelToken) -> TrapSpacesConfig: ...
+    def with_progress(self, progress: Union[Callable[[ProgressEvent], None], Any]) -> TrapSpacesConfig: ...
     def with_bdd_size_limit(self, bdd_size_limit: int) -> TrapSpacesConfig: ...
 
 class TrapSpacesComp:
     @staticmethod
-    def create_from(graph_representation: BooleanNetwork) -> TrapSpacesComp: ...
+    def create_from(graph_representation: Union[AsynchronousGraph, BooleanNetwork]) -> TrapSpacesComp: ...
     @staticmethod
     def with_config(config: TrapSpacesConfig) -> TrapSpacesComp: ...
     def essential_symbolic(self) -> ColoredSpaceSet: ...
     def minimal_symbolic(self) -> ColoredSpaceSet: ...
+    def maximal_symbolic(self) -> ColoredSpaceSet:
+        """
+        Compute the maximal coloured trap spaces within the configured `restriction` set. The whole
+        state space is trivially a trap space and is therefore not considered.
+        """
+    def count_per_color(self, set: ColoredSpaceSet) -> dict[int, ColorSet]:
+        """
+        Count the number of spaces in the given `set` for each color. The result maps each count
+        to the colors which have exactly this number of spaces. For example,
+        `count_per_color(minimal_symbolic())` classifies the colors by the number of their
+        minimal trap spaces.
+        """
+    def minimal_solver(self, limit: Optional[int] = None) -> ColoredSpaceSet:
+        """
+        Compute the minimal coloured trap spaces within the configured `restriction` set using
+        the Z3 solver. If `limit` is given, at most `limit` minimal trap spaces (i.e. space-color
+        pairs) are returned.
+        """
+    def minimal_solver_iterator(self) -> TrapSpacesSolverIterator:
+        """
+        Enumerate the minimal coloured trap spaces within the configured `restriction` set one
+        by one using the Z3 solver. Each item is a `(ColorModel, SpaceModel)` pair, such that
+        the space is a minimal trap space for the given color.
+        """
     def minimize(self, set: ColoredSpaceSet) -> ColoredSpaceSet: ...
     def maximize(self, set: ColoredSpaceSet) -> ColoredSpaceSet: ...
 
+class TrapSpacesSolverIterator:
+    def __iter__(self) -> TrapSpacesSolverIterator: ...
+    def __next__(self) -> tuple[ColorModel, SpaceModel]: ...
+    def found(self) -> ColoredSpaceSet: ...
+
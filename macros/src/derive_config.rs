@@ -49,6 +49,27 @@ pub(crate) fn derive_config_impl(input: TokenStream) -> TokenStream {
         .into();
     }
 
+    // Check if the struct has a progress field of type Box<dyn ProgressReporter>
+    let has_progress_field = fields.iter().any(|field| {
+        if let Some(ident) = &field.ident {
+            if ident == "progress" {
+                // The same simplistic check as for the cancellation field.
+                let type_str = quote!(#field.ty).to_string();
+                return type_str.contains("Box") && type_str.contains("ProgressReporter");
+            }
+        }
+        false
+    });
+
+    if !has_progress_field {
+        return syn::Error::new_spanned(
+            &input,
+            "Config requires a field named 'progress' of type Box<dyn ProgressReporter>",
+        )
+        .to_compile_error()
+        .into();
+    }
+
     // Generate the implementation of the Config trait
     let expanded = quote! {
         impl Config for #name {
@@ -59,6 +80,14 @@ pub(crate) fn derive_config_impl(input: TokenStream) -> TokenStream {
             fn set_cancellation(&mut self, cancellation: Box<dyn CancellationHandler>) {
                 self.cancellation = cancellation;
             }
+
+            fn progress(&self) -> &dyn ProgressReporter {
+                self.progress.as_ref()
+            }
+
+            fn set_progress(&mut self, progress: Box<dyn ProgressReporter>) {
+                self.progress = progress;
+            }
         }
     };
 
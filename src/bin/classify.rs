@@ -0,0 +1,168 @@
+//! A command-line interface for the BN Classifier workflow (see [biodivine_aeon::classify]).
+//!
+//! Usage: `classify --model MODEL.aeon --output ARCHIVE.zip [--time-limit SECONDS] [-v | -q]`
+
+use std::process::ExitCode;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use log::{LevelFilter, Log, Metadata, Record, error};
+
+const USAGE: &str = "\
+Classify the interpretations of a partially specified Boolean network based on the
+HCTL properties in its annotations, and save the result into a classification archive.
+
+Usage: classify --model <MODEL> --output <ARCHIVE> [OPTIONS]
+
+Options:
+  -m, --model <MODEL>          Path to the annotated `.aeon` model.
+  -o, --output <ARCHIVE>       Path to the resulting `.zip` archive.
+  -t, --time-limit <SECONDS>   Abort the computation after the given number of seconds.
+  -v, --verbose                Print more progress information (can be repeated).
+  -q, --quiet                  Only print errors.
+  -h, --help                   Print this help message.
+
+Exit codes:
+  0  The classification archive was successfully created.
+  1  The classification failed (see the error message).
+  2  Invalid command-line arguments.
+  3  The time limit was exceeded (no archive is created).";
+
+/// Command-line arguments of the `classify` binary.
+struct Arguments {
+    model_path: String,
+    output_zip: String,
+    time_limit: Option<Duration>,
+    log_level: LevelFilter,
+}
+
+impl Arguments {
+    /// Parse the arguments of the current process. Returns `Ok(None)` if only help was requested.
+    fn parse() -> Result<Option<Arguments>, String> {
+        let mut model_path = None;
+        let mut output_zip = None;
+        let mut time_limit = None;
+        let mut verbosity = 0u8;
+        let mut quiet = false;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for argument `{name}`."))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "-m" | "--model" => model_path = Some(value(&arg)?),
+                "-o" | "--output" => output_zip = Some(value(&arg)?),
+                "-t" | "--time-limit" => {
+                    let seconds = value(&arg)?;
+                    let Ok(seconds) = seconds.parse::<u64>() else {
+                        return Err(format!("Invalid time limit `{seconds}`."));
+                    };
+                    time_limit = Some(Duration::from_secs(seconds));
+                }
+                "-v" | "--verbose" => verbosity = verbosity.saturating_add(1),
+                "-q" | "--quiet" => quiet = true,
+                _ => return Err(format!("Unknown argument `{arg}`.")),
+            }
+        }
+
+        let log_level = match (quiet, verbosity) {
+            (true, _) => LevelFilter::Error,
+            (false, 0) => LevelFilter::Info,
+            (false, 1) => LevelFilter::Debug,
+            (false, _) => LevelFilter::Trace,
+        };
+
+        Ok(Some(Arguments {
+            model_path: model_path.ok_or("Missing input model (`--model`).")?,
+            output_zip: output_zip.ok_or("Missing output archive (`--output`).")?,
+            time_limit,
+            log_level,
+        }))
+    }
+}
+
+/// A minimal logger that prints all enabled messages to the standard error output.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+fn main() -> ExitCode {
+    let args = match Arguments::parse() {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("{message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(args.log_level);
+    }
+
+    // The classification runs in a separate thread such that we can stop waiting for it once
+    // the time limit is exceeded (the process then exits, which also stops the computation).
+    // The archive is first written into a temporary file, such that an interrupted computation
+    // never leaves a half-written archive in the output path.
+    let partial_zip = format!("{}.partial", args.output_zip);
+    let (sender, receiver) = mpsc::channel();
+    let (model_path, worker_zip) = (args.model_path.clone(), partial_zip.clone());
+    thread::spawn(move || {
+        let result = biodivine_aeon::classify(&model_path, &worker_zip);
+        // The receiver can be gone if the time limit is exceeded.
+        let _ = sender.send(result);
+    });
+
+    let result = match args.time_limit {
+        Some(limit) => match receiver.recv_timeout(limit) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                error!("Time limit of {}s exceeded.", limit.as_secs());
+                // The file may not exist yet if the archive is not being written.
+                let _ = std::fs::remove_file(&partial_zip);
+                return ExitCode::from(3);
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err("Classification terminated unexpectedly.".to_string())
+            }
+        },
+        None => receiver
+            .recv()
+            .unwrap_or_else(|_| Err("Classification terminated unexpectedly.".to_string())),
+    };
+
+    let result = result.and_then(|()| {
+        std::fs::rename(&partial_zip, &args.output_zip)
+            .map_err(|e| format!("Cannot write `{}`: {e}", args.output_zip))
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            let _ = std::fs::remove_file(&partial_zip);
+            error!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
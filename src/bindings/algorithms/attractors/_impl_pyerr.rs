@@ -0,0 +1,55 @@
+use pyo3::{Py, PyErr, Python};
+
+use crate::{
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                StepsLimitExceededError, with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::attractors::AttractorsError,
+};
+
+impl From<AttractorsError> for PyErr {
+    fn from(err: AttractorsError) -> Self {
+        match err {
+            AttractorsError::CreationFailed(error) => {
+                PyErr::new::<CreationFailedError, _>(format!("Config creation failed: {}", error))
+            }
+            AttractorsError::Cancelled(sets) => {
+                PyErr::new::<CancelledError, _>(format!("Cancelled: partial_result={}", sets.len()))
+            }
+            AttractorsError::StepsLimitExceeded(sets) => PyErr::new::<StepsLimitExceededError, _>(
+                format!("Steps limit exceeded: partial_result={}", sets.len()),
+            ),
+            AttractorsError::BddSizeLimitExceeded(sets) => {
+                PyErr::new::<BddSizeLimitExceededError, _>(format!(
+                    "BDD size limit exceeded: partial_result={}",
+                    sets.len()
+                ))
+            }
+        }
+    }
+}
+
+impl IntoPyErrWithContext<SymbolicContext> for AttractorsError {
+    /// The partial result is a `list[ColoredVertexSet]` of the attractors found so far.
+    fn into_py_err(self, ctx: &Py<SymbolicContext>) -> PyErr {
+        let sets = match &self {
+            AttractorsError::CreationFailed(_) => return self.into(),
+            AttractorsError::Cancelled(sets)
+            | AttractorsError::StepsLimitExceeded(sets)
+            | AttractorsError::BddSizeLimitExceeded(sets) => sets
+                .iter()
+                .map(|set| ColoredVertexSet::mk_native(ctx.clone(), set.clone()))
+                .collect::<Vec<_>>(),
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), sets))
+    }
+}
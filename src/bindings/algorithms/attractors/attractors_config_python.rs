@@ -0,0 +1,265 @@
+use std::{collections::HashSet, time::Duration};
+
+use pyo3::{Bound, Py, PyAny, PyResult, pyclass, pymethods, types::PyList};
+
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
+        },
+        lib_param_bn::{
+            NetworkVariableContext as _,
+            symbolic::{set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext},
+        },
+    },
+    internal::algorithms::{
+        attractors::{Attractors, AttractorsConfig},
+        cancellation::CancelTokenTimer,
+        configurable::{Config as _, Configurable as _},
+    },
+};
+
+/// A configuration class for the `AttractorsComp` class. It allows you to specify various
+/// parameters for the attractor detection, such as the underlying `AsynchronousGraph`,
+/// a restriction set for the vertices, the active and reduced variables, a time limit, a BDD
+/// size limit, and a steps limit. The configuration can be created using a Python constructor or
+/// the `create_from` method, and you can modify it using the `with_*` methods.
+/// The configuration is immutable, meaning that each `with_*` method
+/// returns a new instance of `AttractorsConfig` with the specified modifications.
+/// This API design means the method calls can be chained together.
+#[pyclass(name = "AttractorsConfig", module = "biodivine_aeon", frozen)]
+#[derive(Clone)]
+pub struct PyAttractorsConfig {
+    pub inner: Attractors,
+    pub ctx: Py<SymbolicContext>,
+}
+
+impl PyAttractorsConfig {
+    fn extract_inner(self) -> (AttractorsConfig, Py<SymbolicContext>) {
+        (self.inner.into_config(), self.ctx)
+    }
+
+    fn resolve_variables(
+        &self,
+        variables: &Bound<'_, PyList>,
+    ) -> PyResult<HashSet<biodivine_lib_param_bn::VariableId>> {
+        variables
+            .iter()
+            .map(|it| self.ctx.get().resolve_network_variable(&it))
+            .collect()
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyAttractorsConfig {
+    #[new]
+    #[pyo3(signature = (graph_representation, restriction = None, active_variables = None, reduced_variables = None, time_limit_millis = None, bdd_size_limit = None, steps_limit = None, cancellation = None, progress = None))]
+    pub fn new_py(
+        graph_representation: PyGraphRepresentation,
+        restriction: Option<&ColoredVertexSet>,
+        active_variables: Option<&Bound<'_, PyList>>,
+        reduced_variables: Option<&Bound<'_, PyList>>,
+        time_limit_millis: Option<u64>,
+        bdd_size_limit: Option<usize>,
+        steps_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let py_config = PyAttractorsConfig::try_from(graph_representation)?;
+        let active_variables = active_variables
+            .map(|it| py_config.resolve_variables(it))
+            .transpose()?;
+        let reduced_variables = reduced_variables
+            .map(|it| py_config.resolve_variables(it))
+            .transpose()?;
+        let (mut config, ctx) = py_config.extract_inner();
+
+        if let Some(restriction) = restriction {
+            config = config.with_restriction(restriction.as_native().clone())
+        }
+
+        if let Some(active_variables) = active_variables {
+            config = config.with_active_variables(active_variables)
+        }
+
+        if let Some(reduced_variables) = reduced_variables {
+            config = config.with_reduced_variables(reduced_variables)
+        }
+
+        if let Some(millis) = time_limit_millis {
+            config = config.with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(millis),
+            )))
+        }
+
+        if let Some(size_limit) = bdd_size_limit {
+            config = config.with_bdd_size_limit(size_limit)
+        }
+
+        if let Some(steps_limit) = steps_limit {
+            config = config.with_steps_limit(steps_limit)
+        }
+
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
+        Ok(PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx,
+        })
+    }
+
+    /// Create a new `AttractorsConfig` from the given `AsynchronousGraph` or `BooleanNetwork`,
+    /// with otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        PyAttractorsConfig::try_from(graph_representation)
+    }
+
+    /// Restricts result to the given set of vertices.
+    ///
+    /// Default: `graph.unit_colored_vertices()`.
+    pub fn with_restriction(&self, restriction: &ColoredVertexSet) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_restriction(restriction.as_native().clone());
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Specifies the variables whose transitions are considered by the algorithms.
+    /// Remaining variables stay constant, because they are never updated.
+    ///
+    /// Default: all network variables.
+    pub fn with_active_variables(&self, active_variables: &Bound<'_, PyList>) -> PyResult<Self> {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_active_variables(self.resolve_variables(active_variables)?);
+
+        Ok(PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        })
+    }
+
+    /// Specifies the variables for which transition guided reduction starts a reduction
+    /// process. This does not affect which transitions are considered (see
+    /// `with_active_variables`).
+    ///
+    /// Default: all network variables.
+    pub fn with_reduced_variables(&self, reduced_variables: &Bound<'_, PyList>) -> PyResult<Self> {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_reduced_variables(self.resolve_variables(reduced_variables)?);
+
+        Ok(PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        })
+    }
+
+    /// Sets a time limit for the attractor computation, in milliseconds.
+    ///
+    /// Default: no time limit.
+    // TODO: if we ever move away from abi3-py37, use Duration as an argument
+    pub fn with_time_limit(&self, duration_in_millis: u64) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(duration_in_millis),
+            )));
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a `CancelToken` that can be used to cancel the attractor computation.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    pub fn with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation));
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a callback that is notified about the progress of the attractor computation.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    pub fn with_progress(&self, progress: Py<PyAny>) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_progress(ProgressReporterPython::new(progress));
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum BDD size of the intermediate sets of vertices.
+    ///
+    /// Note that the algorithms can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_bdd_size_limit(&self, bdd_size_limit: usize) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_bdd_size_limit(bdd_size_limit);
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum number of steps that the algorithm can take before terminating.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_steps_limit(&self, steps_limit: usize) -> Self {
+        let config = self.inner.config().clone().with_steps_limit(steps_limit);
+
+        PyAttractorsConfig {
+            inner: Attractors::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+}
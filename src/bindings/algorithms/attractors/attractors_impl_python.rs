@@ -0,0 +1,100 @@
+use pyo3::{PyResult, pyclass, pymethods};
+
+use crate::bindings::{
+    algorithms::{graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext},
+    lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet,
+};
+
+use super::{PyAttractorsConfig, PyAttractorsIterator};
+
+/// Implements attractor detection over an `AsynchronousGraph`.
+#[pyclass(name = "AttractorsComp", module = "biodivine_aeon", frozen)]
+pub struct PyAttractors(PyAttractorsConfig);
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyAttractors {
+    /// Create a new `AttractorsComp` instance with the given `AsynchronousGraph` or
+    /// `BooleanNetwork` and otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        Ok(PyAttractors(PyAttractorsConfig::try_from(
+            graph_representation,
+        )?))
+    }
+
+    /// Create a new `AttractorsComp` instance with the given `AttractorsConfig`.
+    #[staticmethod]
+    pub fn with_config(config: PyAttractorsConfig) -> Self {
+        PyAttractors(config)
+    }
+
+    /// Compute a subset of the `restriction` set that is guaranteed to be a superset
+    /// of all the attractors within the `restriction` set.
+    ///
+    /// The exact characterisation of the retained set is a bit complicated. You shouldn't
+    /// assume that the result is a trap set or that it is forward/backward closed. Just that
+    /// any attractor that is a subset of `restriction` is still a subset of the result.
+    pub fn transition_guided_reduction(&self) -> PyResult<ColoredVertexSet> {
+        Ok(ColoredVertexSet::mk_native(
+            self.0.ctx.clone(),
+            self.0
+                .inner
+                .transition_guided_reduction()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+        ))
+    }
+
+    /// Perform attractor detection on the `restriction` set without any preprocessing.
+    ///
+    /// Note that the result is a collection of sets, such that for each set and each color
+    /// holds that if the color is present in the set, the vertices of this color in the set
+    /// together form an attractor. It is not guaranteed that this "decomposition" into colored
+    /// sets is in some sense canonical (but the method should be deterministic).
+    pub fn xie_beerel(&self) -> PyResult<Vec<ColoredVertexSet>> {
+        Ok(self
+            .0
+            .inner
+            .xie_beerel()
+            .map_err(|e| e.into_py_err(&self.0.ctx))?
+            .into_iter()
+            .map(|it| ColoredVertexSet::mk_native(self.0.ctx.clone(), it))
+            .collect())
+    }
+
+    /// The same as `AttractorsComp.xie_beerel`, but returns an `AttractorsIterator` that
+    /// computes the attractor sets lazily, one at a time.
+    pub fn xie_beerel_iter(&self) -> PyAttractorsIterator {
+        PyAttractorsIterator::new(self.0.inner.xie_beerel_iter(), self.0.ctx.clone())
+    }
+
+    /// Compute the (colored) attractors within the `restriction` set.
+    ///
+    /// See `AttractorsComp.xie_beerel` and `AttractorsComp.transition_guided_reduction`
+    /// for relevant documentation.
+    pub fn attractors(&self) -> PyResult<Vec<ColoredVertexSet>> {
+        Ok(self
+            .0
+            .inner
+            .attractors()
+            .map_err(|e| e.into_py_err(&self.0.ctx))?
+            .into_iter()
+            .map(|it| ColoredVertexSet::mk_native(self.0.ctx.clone(), it))
+            .collect())
+    }
+
+    /// The same as `AttractorsComp.attractors`, but returns an `AttractorsIterator` that
+    /// computes the attractor sets lazily, one at a time.
+    ///
+    /// The transition guided reduction is performed before the iterator is returned.
+    pub fn attractors_iter(&self) -> PyResult<PyAttractorsIterator> {
+        Ok(PyAttractorsIterator::new(
+            self.0
+                .inner
+                .attractors_iter()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+            self.0.ctx.clone(),
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use pyo3::{Py, PyResult, pyclass, pymethods};
+
+use crate::{
+    bindings::{
+        algorithms::token_python::IntoPyErrWithContext,
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::attractors::AttractorsIterator,
+};
+
+/// An iterator that lazily computes the attractors of an `AsynchronousGraph`, one attractor
+/// set at a time. The computation only progresses when the next item is requested, so you
+/// can stop early once you have found the attractor you are looking for.
+///
+/// If the computation is cancelled or a limit is exceeded, the corresponding exception is
+/// raised and the iterator is exhausted. The attractors returned before the exception remain
+/// available through `found`.
+#[pyclass(name = "AttractorsIterator", module = "biodivine_aeon")]
+pub struct PyAttractorsIterator {
+    inner: AttractorsIterator,
+    ctx: Py<SymbolicContext>,
+}
+
+impl PyAttractorsIterator {
+    pub fn new(inner: AttractorsIterator, ctx: Py<SymbolicContext>) -> Self {
+        PyAttractorsIterator { inner, ctx }
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyAttractorsIterator {
+    fn __iter__(self_: Py<Self>) -> Py<Self> {
+        self_
+    }
+
+    fn __next__(&mut self) -> PyResult<Option<ColoredVertexSet>> {
+        match self.inner.next() {
+            Some(attractor) => Ok(Some(ColoredVertexSet::mk_native(
+                self.ctx.clone(),
+                attractor.map_err(|e| e.into_py_err(&self.ctx))?,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// The attractor sets that were returned by this iterator so far.
+    pub fn found(&self) -> Vec<ColoredVertexSet> {
+        self.inner
+            .found()
+            .iter()
+            .map(|it| ColoredVertexSet::mk_native(self.ctx.clone(), it.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,21 @@
+use pyo3::{
+    Bound, PyResult,
+    types::{PyModule, PyModuleMethods as _},
+};
+
+mod _impl_pyerr;
+mod attractors_config_python;
+mod attractors_impl_python;
+mod attractors_iterator_python;
+
+pub use attractors_config_python::PyAttractorsConfig;
+use attractors_impl_python::PyAttractors;
+use attractors_iterator_python::PyAttractorsIterator;
+
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyAttractors>()?;
+    module.add_class::<PyAttractorsConfig>()?;
+    module.add_class::<PyAttractorsIterator>()?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use pyo3::{Py, PyErr, Python};
+
+use crate::{
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                StepsLimitExceededError, with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::basins::BasinsError,
+};
+
+impl From<BasinsError> for PyErr {
+    fn from(err: BasinsError) -> Self {
+        match err {
+            BasinsError::CreationFailed(error) => {
+                PyErr::new::<CreationFailedError, _>(format!("Config creation failed: {}", error))
+            }
+            BasinsError::Cancelled(basins) => PyErr::new::<CancelledError, _>(format!(
+                "Cancelled: partial_result={}",
+                basins.len()
+            )),
+            BasinsError::StepsLimitExceeded(basins) => PyErr::new::<StepsLimitExceededError, _>(
+                format!("Steps limit exceeded: partial_result={}", basins.len()),
+            ),
+            BasinsError::BddSizeLimitExceeded(basins) => {
+                PyErr::new::<BddSizeLimitExceededError, _>(format!(
+                    "BDD size limit exceeded: partial_result={}",
+                    basins.len()
+                ))
+            }
+        }
+    }
+}
+
+impl IntoPyErrWithContext<SymbolicContext> for BasinsError {
+    /// The partial result is a `list[tuple[ColoredVertexSet, ColoredVertexSet, ColoredVertexSet]]`
+    /// of the `(weak, strong, shared)` basins computed so far.
+    fn into_py_err(self, ctx: &Py<SymbolicContext>) -> PyErr {
+        let basins = match &self {
+            BasinsError::CreationFailed(_) => return self.into(),
+            BasinsError::Cancelled(basins)
+            | BasinsError::StepsLimitExceeded(basins)
+            | BasinsError::BddSizeLimitExceeded(basins) => basins
+                .iter()
+                .map(|basin| {
+                    (
+                        ColoredVertexSet::mk_native(ctx.clone(), basin.weak.clone()),
+                        ColoredVertexSet::mk_native(ctx.clone(), basin.strong.clone()),
+                        ColoredVertexSet::mk_native(ctx.clone(), basin.shared.clone()),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), basins))
+    }
+}
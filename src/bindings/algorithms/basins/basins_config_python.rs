@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use pyo3::{Py, PyAny, PyResult, pyclass, pymethods};
+
+use crate::{
+    bindings::{
+        algorithms::{
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
+        },
+        lib_param_bn::symbolic::symbolic_context::SymbolicContext,
+    },
+    internal::algorithms::{
+        basins::{Basins, BasinsConfig},
+        cancellation::CancelTokenTimer,
+        configurable::{Config as _, Configurable as _},
+    },
+};
+
+/// A configuration class for the `BasinsComp` class. It allows you to specify various
+/// parameters for the basin computation, such as the underlying `AsynchronousGraph`,
+/// a time limit, a BDD size limit, and a steps limit.
+/// The configuration can be created using a Python constructor or the `create_from` method,
+/// and you can modify it using the `with_*` methods.
+/// The configuration is immutable, meaning that each `with_*` method
+/// returns a new instance of `BasinsConfig` with the specified modifications.
+/// This API design means the method calls can be chained together.
+#[pyclass(name = "BasinsConfig", module = "biodivine_aeon", frozen)]
+#[derive(Clone)]
+pub struct PyBasinsConfig {
+    pub inner: Basins,
+    pub ctx: Py<SymbolicContext>,
+}
+
+impl PyBasinsConfig {
+    fn extract_inner(self) -> (BasinsConfig, Py<SymbolicContext>) {
+        (self.inner.into_config(), self.ctx)
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyBasinsConfig {
+    #[new]
+    #[pyo3(signature = (graph_representation, time_limit_millis = None, bdd_size_limit = None, steps_limit = None, cancellation = None, progress = None))]
+    pub fn new_py(
+        graph_representation: PyGraphRepresentation,
+        time_limit_millis: Option<u64>,
+        bdd_size_limit: Option<usize>,
+        steps_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let (mut config, ctx) = PyBasinsConfig::try_from(graph_representation)?.extract_inner();
+
+        if let Some(millis) = time_limit_millis {
+            config = config.with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(millis),
+            )))
+        }
+
+        if let Some(size_limit) = bdd_size_limit {
+            config = config.with_bdd_size_limit(size_limit)
+        }
+
+        if let Some(steps_limit) = steps_limit {
+            config = config.with_steps_limit(steps_limit)
+        }
+
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
+        Ok(PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx,
+        })
+    }
+
+    /// Create a new `BasinsConfig` from the given `AsynchronousGraph` or
+    /// `BooleanNetwork`, with otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        PyBasinsConfig::try_from(graph_representation)
+    }
+
+    /// Sets a time limit for the basin computation, in milliseconds.
+    ///
+    /// Default: no time limit.
+    // TODO: if we ever move away from abi3-py37, use Duration as an argument
+    pub fn with_time_limit(&self, duration_in_millis: u64) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(duration_in_millis),
+            )));
+
+        PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a `CancelToken` that can be used to cancel the basin computation.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    pub fn with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation));
+
+        PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a callback that is notified about the progress of the basin computation.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    pub fn with_progress(&self, progress: Py<PyAny>) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_progress(ProgressReporterPython::new(progress));
+
+        PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum BDD size of the intermediate reachable sets.
+    ///
+    /// Note that the algorithms can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_bdd_size_limit(&self, bdd_size_limit: usize) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_bdd_size_limit(bdd_size_limit);
+
+        PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum number of steps that the computation of a single weak or strong basin
+    /// can take before terminating. The limit applies to every basin separately, i.e. the
+    /// whole computation can perform more steps in total.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_steps_limit(&self, steps_limit: usize) -> Self {
+        let config = self.inner.config().clone().with_steps_limit(steps_limit);
+
+        PyBasinsConfig {
+            inner: Basins::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use pyo3::{PyResult, pyclass, pymethods};
+
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext,
+        },
+        lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet,
+    },
+};
+
+use super::PyBasinsConfig;
+
+/// Implements the computation of attractor basins over an `AsynchronousGraph`.
+#[pyclass(name = "BasinsComp", module = "biodivine_aeon", frozen)]
+pub struct PyBasins(PyBasinsConfig);
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyBasins {
+    /// Create a new `BasinsComp` instance with the given `AsynchronousGraph` or
+    /// `BooleanNetwork` and otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        Ok(PyBasins(PyBasinsConfig::try_from(graph_representation)?))
+    }
+
+    /// Create a new `BasinsComp` instance with the given `BasinsConfig`.
+    #[staticmethod]
+    pub fn with_config(config: PyBasinsConfig) -> Self {
+        PyBasins(config)
+    }
+
+    /// Compute the basins of each of the given `attractors`. For each attractor, the result
+    /// contains a `(weak, strong, shared)` tuple:
+    ///
+    ///  - `weak` are all states that can reach the attractor.
+    ///  - `strong` are all states that always reach the attractor.
+    ///  - `shared` are the states of the weak basin that can also reach another attractor.
+    ///
+    /// To obtain meaningful shared states, `attractors` should contain all attractors
+    /// of the graph.
+    pub fn basins(
+        &self,
+        attractors: Vec<ColoredVertexSet>,
+    ) -> PyResult<Vec<(ColoredVertexSet, ColoredVertexSet, ColoredVertexSet)>> {
+        let attractors = attractors
+            .into_iter()
+            .map(|it| it.as_native().clone())
+            .collect::<Vec<_>>();
+
+        Ok(self
+            .0
+            .inner
+            .basins(&attractors)
+            .map_err(|e| e.into_py_err(&self.0.ctx))?
+            .into_iter()
+            .map(|basin| {
+                (
+                    ColoredVertexSet::mk_native(self.0.ctx.clone(), basin.weak),
+                    ColoredVertexSet::mk_native(self.0.ctx.clone(), basin.strong),
+                    ColoredVertexSet::mk_native(self.0.ctx.clone(), basin.shared),
+                )
+            })
+            .collect())
+    }
+}
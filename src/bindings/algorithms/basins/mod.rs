@@ -0,0 +1,18 @@
+use pyo3::{
+    Bound, PyResult,
+    types::{PyModule, PyModuleMethods as _},
+};
+
+mod _impl_pyerr;
+mod basins_config_python;
+mod basins_impl_python;
+
+pub use basins_config_python::PyBasinsConfig;
+use basins_impl_python::PyBasins;
+
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyBasins>()?;
+    module.add_class::<PyBasinsConfig>()?;
+
+    Ok(())
+}
@@ -1,8 +1,19 @@
-use pyo3::{PyErr, create_exception, exceptions::PyException};
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use pyo3::{Py, PyErr, Python};
 
 use crate::{
-    bindings::algorithms::{
-        graph_representation::CreationFailedError, token_python::CancelledError,
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
     },
     internal::algorithms::fixed_points::FixedPointsError,
 };
@@ -27,4 +38,18 @@ impl From<FixedPointsError> for PyErr {
     }
 }
 
-create_exception!(fixed_points, BddSizeLimitExceededError, PyException);
+impl IntoPyErrWithContext<SymbolicContext> for FixedPointsError {
+    /// The partial result is a `ColoredVertexSet` that over-approximates the fixed points.
+    /// For the vertex and color variants, some of the projected-out variables may already
+    /// be eliminated from this set.
+    fn into_py_err(self, ctx: &Py<SymbolicContext>) -> PyErr {
+        let set = match &self {
+            FixedPointsError::CreationFailed(_) => return self.into(),
+            FixedPointsError::Cancelled(bdd) | FixedPointsError::BddSizeLimitExceeded(bdd) => {
+                let native = GraphColoredVertices::new(bdd.clone(), ctx.get().as_native());
+                ColoredVertexSet::mk_native(ctx.clone(), native)
+            }
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), set))
+    }
+}
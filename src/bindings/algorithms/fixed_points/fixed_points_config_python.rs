@@ -1,12 +1,14 @@
 use std::time::Duration;
 
-use pyo3::{Py, PyResult, pyclass, pymethods};
+use pyo3::{Py, PyAny, PyResult, pyclass, pymethods};
 
 use crate::{
     AsNative as _,
     bindings::{
         algorithms::{
-            graph_representation::PyGraphRepresentation, token_python::CancelTokenPython,
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
         },
         lib_param_bn::symbolic::{
             set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
@@ -45,12 +47,14 @@ impl PyFixedPointsConfig {
 #[pymethods]
 impl PyFixedPointsConfig {
     #[new]
-    #[pyo3(signature = (graph_representation, restriction = None, time_limit_millis = None, bdd_size_limit = None))]
+    #[pyo3(signature = (graph_representation, restriction = None, time_limit_millis = None, bdd_size_limit = None, cancellation = None, progress = None))]
     pub fn new_py(
         graph_representation: PyGraphRepresentation,
         restriction: Option<&ColoredVertexSet>,
         time_limit_millis: Option<u64>,
         bdd_size_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let (mut config, ctx) =
             PyFixedPointsConfig::try_from(graph_representation)?.extract_inner();
@@ -69,6 +73,14 @@ impl PyFixedPointsConfig {
             config = config.with_bdd_size_limit(size_limit)
         }
 
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
         Ok(PyFixedPointsConfig {
             inner: FixedPoints::with_config(config),
             ctx,
@@ -117,6 +129,42 @@ impl PyFixedPointsConfig {
         }
     }
 
+    /// Sets a `CancelToken` that can be used to cancel the fixed points computation.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    pub fn with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation));
+
+        PyFixedPointsConfig {
+            inner: FixedPoints::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a callback that is notified about the progress of the fixed points computation.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    pub fn with_progress(&self, progress: Py<PyAny>) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_progress(ProgressReporterPython::new(progress));
+
+        PyFixedPointsConfig {
+            inner: FixedPoints::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
     /// The maximum size of the BDD used in the merging process.
     ///
     /// Note that the algorithms can use other auxiliary BDDs that do not
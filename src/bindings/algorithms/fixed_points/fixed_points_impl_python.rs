@@ -1,13 +1,22 @@
-use pyo3::{PyResult, pyclass, pymethods};
+use either::{Left, Right};
+use pyo3::{Bound, PyResult, pyclass, pymethods, types::PyList};
 
-use crate::bindings::{
-    algorithms::graph_representation::PyGraphRepresentation,
-    lib_param_bn::symbolic::{
-        set_color::ColorSet, set_colored_vertex::ColoredVertexSet, set_vertex::VertexSet,
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext,
+        },
+        lib_param_bn::{
+            NetworkVariableContext as _,
+            symbolic::{
+                set_color::ColorSet, set_colored_vertex::ColoredVertexSet, set_vertex::VertexSet,
+            },
+        },
     },
 };
 
-use super::PyFixedPointsConfig;
+use super::{PyFixedPointsConfig, PyFixedPointsSolverIterator};
 
 /// Implements fixed point search over an `AsynchronousGraph`
 #[pyclass(name = "FixedPointsComp", module = "biodivine_aeon", frozen)]
@@ -45,7 +54,10 @@ impl PyFixedPoints {
     pub fn naive_symbolic(&self) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             self.0.ctx.clone(),
-            self.0.inner.naive_symbolic()?,
+            self.0
+                .inner
+                .naive_symbolic()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 
@@ -57,7 +69,10 @@ impl PyFixedPoints {
     pub fn symbolic(&self) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             self.0.ctx.clone(),
-            self.0.inner.symbolic()?,
+            self.0
+                .inner
+                .symbolic()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 
@@ -72,7 +87,10 @@ impl PyFixedPoints {
     pub fn symbolic_vertices(&self) -> PyResult<VertexSet> {
         Ok(VertexSet::mk_native(
             self.0.ctx.clone(),
-            self.0.inner.symbolic_vertices()?,
+            self.0
+                .inner
+                .symbolic_vertices()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 
@@ -86,7 +104,77 @@ impl PyFixedPoints {
     pub fn symbolic_colors(&self) -> PyResult<ColorSet> {
         Ok(ColorSet::mk_native(
             self.0.ctx.clone(),
-            self.0.inner.symbolic_colors()?,
+            self.0
+                .inner
+                .symbolic_colors()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+        ))
+    }
+
+    /// Enumerate the fixed points within `restriction` one by one using the Z3 solver.
+    /// The result is a `FixedPointsSolverIterator` of `(ColorModel, VertexModel)` pairs.
+    ///
+    /// This avoids the symbolic explosion of `symbolic()` on large networks, but each fixed
+    /// point has to be enumerated explicitly. Hence, it is best suited for finding a few fixed
+    /// points, or for networks with few fixed points.
+    pub fn solver_iterator(&self) -> PyResult<PyFixedPointsSolverIterator> {
+        Ok(PyFixedPointsSolverIterator::new(
+            self.0
+                .inner
+                .solver_iterator()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+            self.0.ctx.clone(),
+        ))
+    }
+
+    /// Iteratively compute the set of fixed-points in an `AsynchronousGraph`, projected onto
+    /// the `retained_variables` and `retained_functions`.
+    ///
+    /// The result contains every vertex-color pair that agrees with some fixed-point on all
+    /// retained variables and functions. To enumerate the unique combinations of the retained
+    /// values, use `ColoredVertexSet.items` with the same `retained_variables` and
+    /// `retained_functions`.
+    ///
+    /// If a collection is not given, all variables (or functions) are retained. Hence,
+    /// `symbolic_projection(retained_functions=[])` is equivalent to `symbolic_vertices()`, and
+    /// `symbolic_projection(retained_variables=[])` is equivalent to `symbolic_colors()`
+    /// (up to the type of the result).
+    #[pyo3(signature = (retained_variables = None, retained_functions = None))]
+    pub fn symbolic_projection(
+        &self,
+        retained_variables: Option<&Bound<'_, PyList>>,
+        retained_functions: Option<&Bound<'_, PyList>>,
+    ) -> PyResult<ColoredVertexSet> {
+        let ctx = self.0.ctx.get();
+        let variables = if let Some(retained) = retained_variables {
+            retained
+                .iter()
+                .map(|it| ctx.resolve_network_variable(&it))
+                .collect::<PyResult<Vec<_>>>()?
+        } else {
+            ctx.as_native().network_variables().collect()
+        };
+        let functions = if let Some(retained) = retained_functions {
+            retained
+                .iter()
+                .map(|it| ctx.resolve_function(&it))
+                .collect::<PyResult<Vec<_>>>()?
+        } else {
+            let implicit = ctx.as_native().network_implicit_parameters();
+            let explicit = ctx.as_native().network_parameters();
+            implicit
+                .into_iter()
+                .map(Left)
+                .chain(explicit.map(Right))
+                .collect()
+        };
+
+        Ok(ColoredVertexSet::mk_native(
+            self.0.ctx.clone(),
+            self.0
+                .inner
+                .symbolic_projection(&variables, &functions)
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 }
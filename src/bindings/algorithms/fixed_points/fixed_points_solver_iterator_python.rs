@@ -0,0 +1,84 @@
+use biodivine_lib_bdd::BddPartialValuation;
+use pyo3::{Py, PyResult, Python, pyclass, pymethods};
+
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::token_python::{IntoPyErrWithContext, allow_threads_unsend},
+        lib_param_bn::symbolic::{
+            model_color::ColorModel, model_vertex::VertexModel,
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::fixed_points::FixedPointsSolverIterator,
+};
+
+/// An iterator that lazily enumerates the fixed points of an `AsynchronousGraph` using the Z3
+/// solver. Each item is a `(ColorModel, VertexModel)` pair, such that the vertex is
+/// a fixed-point for the given color.
+///
+/// If the computation is cancelled, the corresponding exception is raised and the iterator is
+/// exhausted. The fixed points returned before the exception remain available through `found`.
+#[pyclass(
+    name = "FixedPointsSolverIterator",
+    module = "biodivine_aeon",
+    unsendable
+)]
+pub struct PyFixedPointsSolverIterator {
+    inner: FixedPointsSolverIterator,
+    ctx: Py<SymbolicContext>,
+}
+
+impl PyFixedPointsSolverIterator {
+    pub fn new(inner: FixedPointsSolverIterator, ctx: Py<SymbolicContext>) -> Self {
+        PyFixedPointsSolverIterator { inner, ctx }
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyFixedPointsSolverIterator {
+    fn __iter__(self_: Py<Self>) -> Py<Self> {
+        self_
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<(ColorModel, VertexModel)>> {
+        // The solver can run for a long time, hence it should not block other Python threads.
+        // SAFETY: The native iterator does not hold any GIL-bound values.
+        let next = unsafe { allow_threads_unsend(py, || self.inner.next()) };
+        let Some(fixed_point) = next else {
+            return Ok(None);
+        };
+        let fixed_point = fixed_point.map_err(|e| e.into_py_err(&self.ctx))?;
+        let valuation = fixed_point
+            .as_bdd()
+            .sat_witness()
+            .expect("Fixed point set is not empty.");
+
+        // Split the valuation into the color and the vertex part (see also
+        // `ColoredVertexSet.items`).
+        let mut color_val = BddPartialValuation::from(valuation);
+        let mut state_val = color_val.clone();
+        let native_ctx = self.ctx.get().as_native();
+        for s_var in native_ctx.state_variables() {
+            color_val.unset_value(*s_var);
+        }
+        for p_var in native_ctx.parameter_variables() {
+            state_val.unset_value(*p_var);
+        }
+        let color = ColorModel::new_native(
+            self.ctx.clone(),
+            color_val,
+            native_ctx.network_implicit_parameters(),
+            native_ctx.network_parameters().collect(),
+        );
+        let vertex = VertexModel::new_native(self.ctx.clone(), state_val);
+        Ok(Some((color, vertex)))
+    }
+
+    /// The union of all fixed points that were returned by this iterator so far.
+    pub fn found(&self) -> ColoredVertexSet {
+        ColoredVertexSet::mk_native(self.ctx.clone(), self.inner.found())
+    }
+}
@@ -6,13 +6,16 @@ use pyo3::{
 mod _impl_pyerr;
 mod fixed_points_config_python;
 mod fixed_points_impl_python;
+mod fixed_points_solver_iterator_python;
 
 pub use fixed_points_config_python::PyFixedPointsConfig;
 use fixed_points_impl_python::PyFixedPoints;
+use fixed_points_solver_iterator_python::PyFixedPointsSolverIterator;
 
 pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyFixedPoints>()?;
     module.add_class::<PyFixedPointsConfig>()?;
+    module.add_class::<PyFixedPointsSolverIterator>()?;
 
     Ok(())
 }
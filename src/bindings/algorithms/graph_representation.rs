@@ -4,8 +4,9 @@ use crate::{
     AsNative as _,
     bindings::{
         algorithms::{
-            fixed_points::PyFixedPointsConfig, token_python::CancelTokenPython,
-            trap_spaces::PyTrapSpacesConfig,
+            attractors::PyAttractorsConfig, basins::PyBasinsConfig,
+            fixed_points::PyFixedPointsConfig, scc_decomposition::PySccDecompositionConfig,
+            token_python::CancelTokenPython, trap_spaces::PyTrapSpacesConfig,
         },
         lib_param_bn::{
             boolean_network::BooleanNetwork,
@@ -16,11 +17,14 @@ use crate::{
         },
     },
     internal::algorithms::{
+        attractors::{Attractors, AttractorsConfig},
+        basins::{Basins, BasinsConfig},
         configurable::{Config as _, Configurable as _},
         fixed_points::{FixedPoints, FixedPointsConfig},
         percolation::{PercolationConfig, PercolationError},
         reachability::{ReachabilityConfig, ReachabilityError},
-        trap_spaces::{TrapSpaces, TrapSpacesConfig, TrapSpacesError},
+        scc_decomposition::{SccDecomposition, SccDecompositionConfig},
+        trap_spaces::{TrapSpaces, TrapSpacesConfig},
     },
 };
 
@@ -95,22 +99,119 @@ impl TryFrom<PyGraphRepresentation> for PyFixedPointsConfig {
     }
 }
 
+impl TryFrom<PyGraphRepresentation> for PyAttractorsConfig {
+    type Error = PyErr;
+
+    /// Create a new "default" [PyAttractorsConfig] from the given [PyGraphRepresentation].
+    fn try_from(representation: PyGraphRepresentation) -> Result<Self, Self::Error> {
+        match representation {
+            PyGraphRepresentation::Graph(graph) => {
+                let config = AttractorsConfig::from(graph.get().as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PyAttractorsConfig {
+                    inner: Attractors::with_config(config),
+                    ctx: graph.get().symbolic_context().clone(),
+                })
+            }
+            PyGraphRepresentation::Network(network) => Python::with_gil(|py| {
+                let stg = AsynchronousGraph::new(py, network, None, None)?;
+                let config = AttractorsConfig::from(stg.as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PyAttractorsConfig {
+                    inner: Attractors::with_config(config),
+                    ctx: stg.symbolic_context().clone(),
+                })
+            }),
+        }
+    }
+}
+
+impl TryFrom<PyGraphRepresentation> for PyBasinsConfig {
+    type Error = PyErr;
+
+    /// Create a new "default" [PyBasinsConfig] from the given [PyGraphRepresentation].
+    fn try_from(representation: PyGraphRepresentation) -> Result<Self, Self::Error> {
+        match representation {
+            PyGraphRepresentation::Graph(graph) => {
+                let config = BasinsConfig::from(graph.get().as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PyBasinsConfig {
+                    inner: Basins::with_config(config),
+                    ctx: graph.get().symbolic_context().clone(),
+                })
+            }
+            PyGraphRepresentation::Network(network) => Python::with_gil(|py| {
+                let stg = AsynchronousGraph::new(py, network, None, None)?;
+                let config = BasinsConfig::from(stg.as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PyBasinsConfig {
+                    inner: Basins::with_config(config),
+                    ctx: stg.symbolic_context().clone(),
+                })
+            }),
+        }
+    }
+}
+
+impl TryFrom<PyGraphRepresentation> for PySccDecompositionConfig {
+    type Error = PyErr;
+
+    /// Create a new "default" [PySccDecompositionConfig] from the given [PyGraphRepresentation].
+    fn try_from(representation: PyGraphRepresentation) -> Result<Self, Self::Error> {
+        match representation {
+            PyGraphRepresentation::Graph(graph) => {
+                let config = SccDecompositionConfig::from(graph.get().as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PySccDecompositionConfig {
+                    inner: SccDecomposition::with_config(config),
+                    ctx: graph.get().symbolic_context().clone(),
+                })
+            }
+            PyGraphRepresentation::Network(network) => Python::with_gil(|py| {
+                let stg = AsynchronousGraph::new(py, network, None, None)?;
+                let config = SccDecompositionConfig::from(stg.as_native().clone())
+                    .with_cancellation(CancelTokenPython::default());
+
+                Ok(PySccDecompositionConfig {
+                    inner: SccDecomposition::with_config(config),
+                    ctx: stg.symbolic_context().clone(),
+                })
+            }),
+        }
+    }
+}
+
 impl TryFrom<PyGraphRepresentation> for PyTrapSpacesConfig {
     type Error = PyErr;
 
     /// Create a new "default" [PyTrapSpacesConfig] from the given [PyGraphRepresentation].
     fn try_from(representation: PyGraphRepresentation) -> Result<Self, Self::Error> {
         match representation {
-            PyGraphRepresentation::Graph(_graph) => {
-                Err(TrapSpacesError::CreationFailed(
-                    "Currently, trap spaces cannot be created from just a graph. Use a boolean network or from_graph_with_context() instead."
-                        .to_string()).into())
-            },
+            PyGraphRepresentation::Graph(graph) => Python::with_gil(|py| {
+                let config = TrapSpacesConfig::try_from(graph.get().as_native().clone())?
+                    .with_cancellation(CancelTokenPython::default());
+
+                let ctx = Py::new(
+                    py,
+                    (
+                        SymbolicSpaceContext::new(config.ctx.clone()),
+                        SymbolicContext::wrap_native(py, config.ctx.inner_context().clone())?,
+                    ),
+                )?;
+
+                Ok(PyTrapSpacesConfig {
+                    inner: TrapSpaces::with_config(config),
+                    ctx,
+                })
+            }),
             PyGraphRepresentation::Network(network) => Python::with_gil(|py| {
                 let config = TrapSpacesConfig::try_from(network.borrow(py).as_native())?
-                    .with_cancellation(
-                        CancelTokenPython::default(),
-                    );
+                    .with_cancellation(CancelTokenPython::default());
 
                 let ctx = Py::new(
                     py,
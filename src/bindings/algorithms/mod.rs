@@ -1,19 +1,48 @@
-use pyo3::{Bound, PyResult, types::PyModule};
+use pyo3::{
+    Bound, PyResult,
+    types::{PyModule, PyModuleMethods as _},
+};
 
+use progress_python::PyProgressEvent;
+use token_python::{
+    BddSizeLimitExceededError, CancelledError, PyCancelToken, StepsLimitExceededError,
+};
+
+pub mod attractors;
+pub mod basins;
 pub mod fixed_points;
 pub mod graph_representation;
 pub mod percolation;
+pub mod progress_python;
 pub mod reachability;
+pub mod scc_decomposition;
 pub mod token_python;
 pub mod trap_spaces;
 
 pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     pyo3_log::init();
 
+    attractors::register(module)?;
+    basins::register(module)?;
     fixed_points::register(module)?;
     trap_spaces::register(module)?;
     percolation::register(module)?;
     reachability::register(module)?;
+    scc_decomposition::register(module)?;
+
+    module.add_class::<PyCancelToken>()?;
+    module.add_class::<PyProgressEvent>()?;
+
+    let py = module.py();
+    module.add("CancelledError", py.get_type::<CancelledError>())?;
+    module.add(
+        "StepsLimitExceededError",
+        py.get_type::<StepsLimitExceededError>(),
+    )?;
+    module.add(
+        "BddSizeLimitExceededError",
+        py.get_type::<BddSizeLimitExceededError>(),
+    )?;
 
     Ok(())
 }
@@ -1,21 +1,79 @@
-use pyo3::PyErr;
+use std::collections::HashMap;
+
+use biodivine_lib_param_bn::trap_spaces::NetworkColoredSpaces;
+use pyo3::{Py, PyErr, Python, create_exception, exceptions::PyException};
 
 use crate::{
-    bindings::algorithms::{
-        graph_representation::CreationFailedError, token_python::CancelledError,
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{CancelledError, IntoPyErrWithContext, with_partial_result},
+        },
+        lib_param_bn::{
+            symbolic::{
+                set_colored_space::ColoredSpaceSet, symbolic_space_context::SymbolicSpaceContext,
+            },
+            variable_id::VariableId,
+        },
     },
     internal::algorithms::percolation::PercolationError,
 };
 
+use super::percolation_impl_python::percolated_space_into_py;
+
 impl From<PercolationError> for PyErr {
+    /// The partial result of a cancelled percolation is a `dict[VariableId, bool]` of the
+    /// variables that were fixed so far.
     fn from(err: PercolationError) -> Self {
         match err {
             PercolationError::CreationFailed(x) => {
                 PyErr::new::<CreationFailedError, _>(format!("Config creation failed: {}", x))
             }
             PercolationError::Cancelled(x) => {
-                PyErr::new::<CancelledError, _>(format!("Cancelled: partial_result={:#?}", x))
+                let error =
+                    PyErr::new::<CancelledError, _>(format!("Cancelled: partial_result={:#?}", x));
+                let subspace = x
+                    .into_iter()
+                    .enumerate()
+                    .filter_map(|(i, value)| value.map(|value| (VariableId::new(i), value)))
+                    .collect::<HashMap<_, _>>();
+                Python::with_gil(|py| with_partial_result(py, error, subspace))
+            }
+            PercolationError::CancelledSymbolic(bdd) => {
+                PyErr::new::<CancelledError, _>(format!("Cancelled: {}", bdd.exact_cardinality()))
             }
+            PercolationError::CancelledSpaces(spaces) => {
+                let error = PyErr::new::<CancelledError, _>(format!(
+                    "Cancelled: partial_result={}",
+                    spaces.len()
+                ));
+                let spaces = spaces
+                    .into_iter()
+                    .map(percolated_space_into_py)
+                    .collect::<Vec<_>>();
+                Python::with_gil(|py| with_partial_result(py, error, spaces))
+            }
+            PercolationError::IncompatibleSpaces => PyErr::new::<IncompatibleSpacesError, _>(
+                "Spaces not compatible with the given graph",
+            ),
         }
     }
 }
+
+impl IntoPyErrWithContext<SymbolicSpaceContext> for PercolationError {
+    /// The partial result of a cancelled symbolic percolation is a `ColoredSpaceSet` of the
+    /// spaces percolated so far.
+    fn into_py_err(self, ctx: &Py<SymbolicSpaceContext>) -> PyErr {
+        let set = match &self {
+            PercolationError::CancelledSymbolic(bdd) => {
+                let native = NetworkColoredSpaces::new(bdd.clone(), ctx.get().as_native());
+                ColoredSpaceSet::wrap_native(ctx.clone(), native)
+            }
+            _ => return self.into(),
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), set))
+    }
+}
+
+create_exception!(percolation, IncompatibleSpacesError, PyException);
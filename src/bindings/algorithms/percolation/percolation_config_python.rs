@@ -1,10 +1,12 @@
 use std::time::Duration;
 
-use pyo3::{PyResult, pymethods};
+use pyo3::{Py, PyAny, PyResult, pymethods};
 
 use crate::{
     bindings::algorithms::{
-        graph_representation::PyGraphRepresentation, token_python::CancelTokenPython,
+        graph_representation::PyGraphRepresentation,
+        progress_python::ProgressReporterPython,
+        token_python::{CancelTokenPython, PyCancelToken},
     },
     internal::algorithms::{
         cancellation::CancelTokenTimer, configurable::Config as _, percolation::PercolationConfig,
@@ -16,10 +18,12 @@ use crate::{
 #[pymethods]
 impl PercolationConfig {
     #[new]
-    #[pyo3(signature = (graph_representation, time_limit_millis = None))]
+    #[pyo3(signature = (graph_representation, time_limit_millis = None, cancellation = None, progress = None))]
     pub fn python_new(
         graph_representation: PyGraphRepresentation,
         time_limit_millis: Option<u64>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let mut config = PercolationConfig::try_from(graph_representation)?;
 
@@ -29,6 +33,14 @@ impl PercolationConfig {
             )))
         }
 
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
         Ok(config)
     }
 
@@ -51,4 +63,26 @@ impl PercolationConfig {
                 Duration::from_millis(duration_in_millis),
             )))
     }
+
+    /// Sets a `CancelToken` that can be used to cancel the percolation algorithm.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    #[pyo3(name = "with_cancellation")]
+    pub fn python_with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        self.clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation))
+    }
+
+    /// Sets a callback that is notified about the progress of the percolation algorithm.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    #[pyo3(name = "with_progress")]
+    pub fn python_with_progress(&self, progress: Py<PyAny>) -> Self {
+        self.clone()
+            .with_progress(ProgressReporterPython::new(progress))
+    }
 }
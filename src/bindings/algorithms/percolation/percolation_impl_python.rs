@@ -3,15 +3,40 @@ use std::collections::HashMap;
 use pyo3::{PyResult, pymethods};
 
 use crate::{
+    AsNative as _,
     bindings::{
-        algorithms::graph_representation::PyGraphRepresentation,
-        lib_param_bn::variable_id::VariableId,
+        algorithms::{
+            graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext,
+        },
+        lib_param_bn::{
+            symbolic::{set_colored_space::ColoredSpaceSet, set_spaces::SpaceSet},
+            variable_id::VariableId,
+        },
     },
-    internal::algorithms::percolation::{Percolation, PercolationConfig},
+    internal::algorithms::percolation::{PercolatedSpace, Percolation, PercolationConfig},
 };
 
 use super::SubspaceRepresentation;
 
+/// A Python representation of a [PercolatedSpace]: the `(original, percolated, conflicts,
+/// is_trap_space)` tuple.
+pub type PyPercolatedSpace = (
+    HashMap<VariableId, bool>,
+    HashMap<VariableId, bool>,
+    Vec<VariableId>,
+    bool,
+);
+
+/// Convert a [PercolatedSpace] into its Python representation.
+pub fn percolated_space_into_py(space: PercolatedSpace) -> PyPercolatedSpace {
+    (
+        SubspaceRepresentation::from(space.original).into(),
+        SubspaceRepresentation::from(space.percolated).into(),
+        space.conflicts.into_iter().map(VariableId::from).collect(),
+        space.is_trap_space,
+    )
+}
+
 /// These methods are Python facing wrappers of native methods and thus should not be used from
 /// within Rust.
 #[pymethods]
@@ -42,7 +67,7 @@ impl Percolation {
     ///
     /// This method should technically work on parametrized networks as well, but the constant
     /// check is performed across all interpretations, hence a lot of sub-spaces will not
-    /// percolate meaningfully. We recommend using other symbolic methods for such systems.
+    /// percolate meaningfully. We recommend using `percolate_symbolic` for such systems.
     #[pyo3(name = "percolate_subspace")]
     pub fn python_percolate_subspace(
         &self,
@@ -52,4 +77,37 @@ impl Percolation {
             .map_err(|e| e.into())
             .map(|result| SubspaceRepresentation::from(result).into())
     }
+
+    /// Performs a percolation of every space in the given `SpaceSet`.
+    ///
+    /// The result contains an `(original, percolated, conflicts, is_trap_space)` tuple for each
+    /// space. Each space is percolated the same way as in `percolate_subspace`, but the variables
+    /// fixed in the original space whose value percolates to the opposite value are reported in
+    /// `conflicts`. Finally, `is_trap_space` indicates that the percolated space is a trap space.
+    /// Spaces that contain no states are skipped.
+    ///
+    /// The `SymbolicSpaceContext` of `spaces` must be compatible with the underlying graph.
+    #[pyo3(name = "percolate_spaces")]
+    pub fn python_percolate_spaces(&self, spaces: &SpaceSet) -> PyResult<Vec<PyPercolatedSpace>> {
+        let ctx = spaces.__ctx__();
+        let result = self.percolate_spaces(ctx.get().as_native(), spaces.as_native())?;
+        Ok(result.into_iter().map(percolated_space_into_py).collect())
+    }
+
+    /// Performs a colour-aware percolation of all the given colored subspaces.
+    ///
+    /// The result contains, for every colour and space in `spaces`, the space percolated
+    /// for this particular colour. Unlike `percolate_subspace`, the constant check is performed
+    /// for each colour separately, meaning that parameters of partially specified networks do not
+    /// prevent percolation. Values fixed in the original space are never overwritten.
+    ///
+    /// The `SymbolicSpaceContext` of `spaces` must be compatible with the underlying graph.
+    #[pyo3(name = "percolate_symbolic")]
+    pub fn python_percolate_symbolic(&self, spaces: &ColoredSpaceSet) -> PyResult<ColoredSpaceSet> {
+        let ctx = spaces.__ctx__();
+        let result = self
+            .percolate_symbolic(ctx.get().as_native(), spaces.as_native())
+            .map_err(|e| e.into_py_err(&ctx))?;
+        Ok(spaces.mk_derived(result))
+    }
 }
@@ -0,0 +1,105 @@
+use pyo3::{
+    Py, PyAny, PyResult, Python, pyclass, pymethods,
+    types::{PyAnyMethods as _, PyDict, PyDictMethods as _},
+};
+
+use crate::internal::algorithms::progress::{ProgressEvent, ProgressReporter};
+
+/// A single progress update of a long-running algorithm, as passed to the `progress`
+/// callback of the algorithm configuration objects.
+#[pyclass(name = "ProgressEvent", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Debug)]
+pub struct PyProgressEvent {
+    target: String,
+    step: usize,
+    bdd_size: usize,
+    candidates: f64,
+}
+
+#[pymethods]
+impl PyProgressEvent {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ProgressEvent(target='{}', step={}, bdd_size={}, candidates={})",
+            self.target, self.step, self.bdd_size, self.candidates
+        )
+    }
+
+    /// The algorithm that reported the event, e.g. `Reachability::forward_closed_superset`.
+    #[getter]
+    pub fn target(&self) -> String {
+        self.target.clone()
+    }
+
+    /// The number of steps performed so far. The meaning of a step depends on the algorithm.
+    #[getter]
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// The BDD size of the current intermediate result.
+    #[getter]
+    pub fn bdd_size(&self) -> usize {
+        self.bdd_size
+    }
+
+    /// The approximate number of elements in the current intermediate result (e.g. reached
+    /// states or remaining candidates).
+    #[getter]
+    pub fn candidates(&self) -> f64 {
+        self.candidates
+    }
+}
+
+impl From<&ProgressEvent<'_>> for PyProgressEvent {
+    fn from(event: &ProgressEvent) -> Self {
+        PyProgressEvent {
+            target: event.target.to_string(),
+            step: event.step,
+            bdd_size: event.bdd_size,
+            candidates: event.candidates,
+        }
+    }
+}
+
+/// A [ProgressReporter] that forwards the progress events to a Python object.
+///
+/// If the object is callable, it is called with a `ProgressEvent` as its only argument.
+/// Otherwise, the object is treated as a `tqdm` progress bar: its counter is set to the
+/// current step and the remaining event data is shown as the bar postfix.
+///
+/// Exceptions raised by the Python object cannot be propagated through the algorithm,
+/// hence they are reported as "unraisable" (similar to exceptions in `__del__`).
+#[derive(Clone, Debug)]
+pub struct ProgressReporterPython(Py<PyAny>);
+
+impl ProgressReporter for ProgressReporterPython {
+    fn report(&self, event: &ProgressEvent) {
+        Python::with_gil(|py| {
+            if let Err(error) = self.report_python(py, event) {
+                error.write_unraisable(py, Some(self.0.bind(py)));
+            }
+        })
+    }
+}
+
+impl ProgressReporterPython {
+    pub fn new(callback: Py<PyAny>) -> Self {
+        ProgressReporterPython(callback)
+    }
+
+    fn report_python(&self, py: Python, event: &ProgressEvent) -> PyResult<()> {
+        let callback = self.0.bind(py);
+        if callback.is_callable() {
+            callback.call1((PyProgressEvent::from(event),))?;
+            return Ok(());
+        }
+
+        let postfix = PyDict::new(py);
+        postfix.set_item("bdd_size", event.bdd_size)?;
+        postfix.set_item("candidates", event.candidates)?;
+        callback.setattr("n", event.step)?;
+        callback.call_method1("set_postfix", (postfix,))?;
+        Ok(())
+    }
+}
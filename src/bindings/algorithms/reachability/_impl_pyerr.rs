@@ -1,8 +1,17 @@
-use pyo3::{PyErr, create_exception, exceptions::PyException};
+use pyo3::{Py, PyErr, Python, create_exception, exceptions::PyException};
 
 use crate::{
-    bindings::algorithms::{
-        graph_representation::CreationFailedError, token_python::CancelledError,
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                StepsLimitExceededError, with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
     },
     internal::algorithms::reachability::ReachabilityError,
 };
@@ -36,6 +45,21 @@ impl From<ReachabilityError> for PyErr {
     }
 }
 
-create_exception!(reachability, StepsLimitExceededError, PyException);
-create_exception!(reachability, BddSizeLimitExceededError, PyException);
+impl IntoPyErrWithContext<SymbolicContext> for ReachabilityError {
+    /// The partial result is a `ColoredVertexSet` of the states that were reached so far.
+    fn into_py_err(self, ctx: &Py<SymbolicContext>) -> PyErr {
+        let set = match &self {
+            ReachabilityError::CreationFailed(_) | ReachabilityError::InvalidSubgraph => {
+                return self.into();
+            }
+            ReachabilityError::Cancelled(gcv)
+            | ReachabilityError::StepsLimitExceeded(gcv)
+            | ReachabilityError::BddSizeLimitExceeded(gcv) => {
+                ColoredVertexSet::mk_native(ctx.clone(), gcv.clone())
+            }
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), set))
+    }
+}
+
 create_exception!(reachability, InvalidSubgraphError, PyException);
@@ -1,18 +1,23 @@
 use std::{collections::HashSet, time::Duration};
 
-use pyo3::{PyResult, pymethods};
+use pyo3::{Py, PyAny, PyResult, pymethods};
 
 use crate::{
     AsNative as _,
     bindings::{
         algorithms::{
-            graph_representation::PyGraphRepresentation, token_python::CancelTokenPython,
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
         },
         lib_param_bn::{symbolic::set_colored_vertex::ColoredVertexSet, variable_id::VariableId},
     },
     internal::algorithms::{
-        cancellation::CancelTokenTimer, configurable::Config as _, reachability::ReachabilityConfig,
+        cancellation::CancelTokenTimer,
+        configurable::Config as _,
+        reachability::{ReachabilityConfig, ReachabilityStrategy},
     },
+    throw_type_error,
 };
 
 /// These methods are Python facing wrappers of native methods and thus should not be used from
@@ -20,14 +25,18 @@ use crate::{
 #[pymethods]
 impl ReachabilityConfig {
     #[new]
-    #[pyo3(signature = (graph_representation, subgraph = None, variables = None, time_limit_millis = None, bdd_size_limit = None, steps_limit = None))]
+    #[pyo3(signature = (graph_representation, subgraph = None, variables = None, strategy = None, variable_order = None, time_limit_millis = None, bdd_size_limit = None, steps_limit = None, cancellation = None, progress = None))]
     pub fn python_new(
         graph_representation: PyGraphRepresentation,
         subgraph: Option<&ColoredVertexSet>,
         variables: Option<HashSet<VariableId>>,
+        strategy: Option<&str>,
+        variable_order: Option<Vec<VariableId>>,
         time_limit_millis: Option<u64>,
         bdd_size_limit: Option<usize>,
         steps_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let mut config = ReachabilityConfig::try_from(graph_representation)?;
 
@@ -39,6 +48,14 @@ impl ReachabilityConfig {
             config = config.with_variables(variables.iter().map(|var| *var.as_native()).collect())
         }
 
+        if let Some(strategy) = strategy {
+            config = config.python_with_strategy(strategy)?
+        }
+
+        if let Some(order) = variable_order {
+            config = config.python_with_variable_order(order)
+        }
+
         if let Some(millis) = time_limit_millis {
             config = config.with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
                 Duration::from_millis(millis),
@@ -53,6 +70,14 @@ impl ReachabilityConfig {
             config = config.with_steps_limit(limit)
         }
 
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
         Ok(config)
     }
 
@@ -92,6 +117,41 @@ impl ReachabilityConfig {
             .with_variables(variables.iter().map(|var| *var.as_native()).collect())
     }
 
+    /// Specifies the order in which the transitions of individual variables are applied.
+    /// All strategies compute the same result, but their performance can differ.
+    ///
+    ///  - `saturation`: Variables are tried in descending order, and after every successful
+    ///    step, the process restarts from the first variable.
+    ///  - `bfs`: Every step applies the transitions of all variables at once.
+    ///  - `chaining`: Variables are tried in descending order, but the process only restarts
+    ///    once a full pass over all variables is completed.
+    ///
+    /// To use saturation with a custom variable order, see `with_variable_order`.
+    ///
+    /// Default: `saturation`.
+    #[pyo3(name = "with_strategy")]
+    pub fn python_with_strategy(&self, strategy: &str) -> PyResult<Self> {
+        let strategy = match strategy {
+            "saturation" => ReachabilityStrategy::Saturation,
+            "bfs" => ReachabilityStrategy::Bfs,
+            "chaining" => ReachabilityStrategy::Chaining,
+            _ => return throw_type_error("Expected one of `saturation`/`bfs`/`chaining`."),
+        };
+        Ok(self.clone().with_strategy(strategy))
+    }
+
+    /// Use the saturation strategy, but try the variables in the given order. Variables that
+    /// are not part of the order are tried last.
+    ///
+    /// Default: descending order of variables.
+    #[pyo3(name = "with_variable_order")]
+    pub fn python_with_variable_order(&self, variable_order: Vec<VariableId>) -> Self {
+        self.clone()
+            .with_strategy(ReachabilityStrategy::CustomOrder(
+                variable_order.iter().map(|var| *var.as_native()).collect(),
+            ))
+    }
+
     /// Sets a time limit for the reachability operation, in milliseconds.
     ///
     /// Default: no time limit.
@@ -104,6 +164,28 @@ impl ReachabilityConfig {
             )))
     }
 
+    /// Sets a `CancelToken` that can be used to cancel the reachability operation.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    #[pyo3(name = "with_cancellation")]
+    pub fn python_with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        self.clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation))
+    }
+
+    /// Sets a callback that is notified about the progress of the reachability operation.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    #[pyo3(name = "with_progress")]
+    pub fn python_with_progress(&self, progress: Py<PyAny>) -> Self {
+        self.clone()
+            .with_progress(ProgressReporterPython::new(progress))
+    }
+
     /// The maximum BDD size of the reachable set.
     ///
     /// Note that the algorithm can use other auxiliary BDDs that do not
@@ -3,8 +3,16 @@ use pyo3::{PyResult, pymethods};
 use crate::{
     AsNative as _,
     bindings::{
-        algorithms::graph_representation::PyGraphRepresentation,
-        lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet,
+        algorithms::{
+            graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext,
+        },
+        lib_param_bn::{
+            symbolic::{
+                model_color::ColorModel, model_vertex::VertexModel,
+                set_colored_vertex::ColoredVertexSet, set_vertex::VertexSet,
+            },
+            variable_id::VariableId,
+        },
     },
     internal::algorithms::reachability::{Reachability, ReachabilityConfig},
 };
@@ -40,7 +48,8 @@ impl Reachability {
     ) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             initial.ctx(),
-            self.forward_closed_superset(initial.as_native())?,
+            self.forward_closed_superset(initial.as_native())
+                .map_err(|e| e.into_py_err(&initial.ctx()))?,
         ))
     }
 
@@ -54,7 +63,8 @@ impl Reachability {
     ) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             initial.ctx(),
-            self.backward_closed_superset(initial.as_native())?,
+            self.backward_closed_superset(initial.as_native())
+                .map_err(|e| e.into_py_err(&initial.ctx()))?,
         ))
     }
 
@@ -69,7 +79,8 @@ impl Reachability {
     ) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             initial.ctx(),
-            self.forward_closed_subset(initial.as_native())?,
+            self.forward_closed_subset(initial.as_native())
+                .map_err(|e| e.into_py_err(&initial.ctx()))?,
         ))
     }
 
@@ -84,7 +95,88 @@ impl Reachability {
     ) -> PyResult<ColoredVertexSet> {
         Ok(ColoredVertexSet::mk_native(
             initial.ctx(),
-            self.backward_closed_subset(initial.as_native())?,
+            self.backward_closed_subset(initial.as_native())
+                .map_err(|e| e.into_py_err(&initial.ctx()))?,
         ))
     }
+
+    /// Compute the forward distance layers of the given `initial` set. The first layer is
+    /// the `initial` set itself, and the `i`-th layer contains the vertices that are reachable
+    /// from `initial` in exactly `i` transitions, but not fewer.
+    ///
+    /// If `max_depth` is given, at most `max_depth + 1` layers are computed.
+    #[pyo3(name = "forward_layers", signature = (initial, max_depth = None))]
+    pub fn python_forward_layers(
+        &self,
+        initial: &ColoredVertexSet,
+        max_depth: Option<usize>,
+    ) -> PyResult<Vec<ColoredVertexSet>> {
+        Ok(self
+            .forward_layers(initial.as_native(), max_depth)
+            .map_err(|e| e.into_py_err(&initial.ctx()))?
+            .into_iter()
+            .map(|layer| ColoredVertexSet::mk_native(initial.ctx(), layer))
+            .collect())
+    }
+
+    /// Compute the backward distance layers of the given `initial` set. The first layer is
+    /// the `initial` set itself, and the `i`-th layer contains the vertices that can reach
+    /// `initial` in exactly `i` transitions, but not fewer.
+    ///
+    /// If `max_depth` is given, at most `max_depth + 1` layers are computed.
+    #[pyo3(name = "backward_layers", signature = (initial, max_depth = None))]
+    pub fn python_backward_layers(
+        &self,
+        initial: &ColoredVertexSet,
+        max_depth: Option<usize>,
+    ) -> PyResult<Vec<ColoredVertexSet>> {
+        Ok(self
+            .backward_layers(initial.as_native(), max_depth)
+            .map_err(|e| e.into_py_err(&initial.ctx()))?
+            .into_iter()
+            .map(|layer| ColoredVertexSet::mk_native(initial.ctx(), layer))
+            .collect())
+    }
+
+    /// Compute a shortest path from `source` to a vertex in `target`.
+    ///
+    /// The path is valid for a single colour. If `color` is given, `source` is first restricted
+    /// to this colour. Then, if `source` contains more than one vertex-colour pair, one of them
+    /// is picked and the path is constructed for this vertex and colour.
+    ///
+    /// The result is a list of vertices along the path (starting with the source vertex and
+    /// ending in a `target` vertex) and a list of variables, such that the `i`-th variable is
+    /// updated in the transition from the `i`-th to the `(i+1)`-th vertex. If no `target`
+    /// vertex is reachable, the result is `None`.
+    #[pyo3(name = "witness_path", signature = (source, target, color = None))]
+    pub fn python_witness_path(
+        &self,
+        source: &ColoredVertexSet,
+        target: &ColoredVertexSet,
+        color: Option<&ColorModel>,
+    ) -> PyResult<Option<(Vec<VertexModel>, Vec<VariableId>)>> {
+        let mut source = source.as_native().clone();
+        if let Some(color) = color {
+            source = source.intersect_colors(color.to_symbolic().as_native());
+        }
+
+        let Some((vertices, variables)) = self
+            .witness_path(&source, target.as_native())
+            .map_err(|e| e.into_py_err(&target.ctx()))?
+        else {
+            return Ok(None);
+        };
+
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| {
+                VertexSet::mk_native(target.ctx(), vertex.vertices())
+                    .__iter__()
+                    .map(|mut it| it.next().expect("The path vertex is a singleton."))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        let variables = variables.into_iter().map(VariableId::from).collect();
+
+        Ok(Some((vertices, variables)))
+    }
 }
@@ -0,0 +1,58 @@
+use pyo3::{Py, PyErr, Python};
+
+use crate::{
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                StepsLimitExceededError, with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::scc_decomposition::SccDecompositionError,
+};
+
+impl From<SccDecompositionError> for PyErr {
+    fn from(err: SccDecompositionError) -> Self {
+        match err {
+            SccDecompositionError::CreationFailed(error) => {
+                PyErr::new::<CreationFailedError, _>(format!("Config creation failed: {}", error))
+            }
+            SccDecompositionError::Cancelled(sets) => {
+                PyErr::new::<CancelledError, _>(format!("Cancelled: partial_result={}", sets.len()))
+            }
+            SccDecompositionError::StepsLimitExceeded(sets) => {
+                PyErr::new::<StepsLimitExceededError, _>(format!(
+                    "Steps limit exceeded: partial_result={}",
+                    sets.len()
+                ))
+            }
+            SccDecompositionError::BddSizeLimitExceeded(sets) => {
+                PyErr::new::<BddSizeLimitExceededError, _>(format!(
+                    "BDD size limit exceeded: partial_result={}",
+                    sets.len()
+                ))
+            }
+        }
+    }
+}
+
+impl IntoPyErrWithContext<SymbolicContext> for SccDecompositionError {
+    /// The partial result is a `list[ColoredVertexSet]` of the components found so far.
+    fn into_py_err(self, ctx: &Py<SymbolicContext>) -> PyErr {
+        let sets = match &self {
+            SccDecompositionError::CreationFailed(_) => return self.into(),
+            SccDecompositionError::Cancelled(sets)
+            | SccDecompositionError::StepsLimitExceeded(sets)
+            | SccDecompositionError::BddSizeLimitExceeded(sets) => sets
+                .iter()
+                .map(|set| ColoredVertexSet::mk_native(ctx.clone(), set.clone()))
+                .collect::<Vec<_>>(),
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), sets))
+    }
+}
@@ -0,0 +1,18 @@
+use pyo3::{
+    Bound, PyResult,
+    types::{PyModule, PyModuleMethods as _},
+};
+
+mod _impl_pyerr;
+mod scc_decomposition_config_python;
+mod scc_decomposition_impl_python;
+
+pub use scc_decomposition_config_python::PySccDecompositionConfig;
+use scc_decomposition_impl_python::PySccDecomposition;
+
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PySccDecomposition>()?;
+    module.add_class::<PySccDecompositionConfig>()?;
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use pyo3::{Py, PyAny, PyResult, pyclass, pymethods};
+
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
+        },
+        lib_param_bn::symbolic::{
+            set_colored_vertex::ColoredVertexSet, symbolic_context::SymbolicContext,
+        },
+    },
+    internal::algorithms::{
+        cancellation::CancelTokenTimer,
+        configurable::{Config as _, Configurable as _},
+        scc_decomposition::{SccDecomposition, SccDecompositionConfig},
+    },
+};
+
+/// A configuration class for the `SccDecompositionComp` class. It allows you to specify various
+/// parameters for the SCC decomposition, such as the underlying `AsynchronousGraph`,
+/// a restriction set for the vertices, a time limit, a BDD size limit, and a steps limit.
+/// The configuration can be created using a Python constructor or the `create_from` method,
+/// and you can modify it using the `with_*` methods.
+/// The configuration is immutable, meaning that each `with_*` method
+/// returns a new instance of `SccDecompositionConfig` with the specified modifications.
+/// This API design means the method calls can be chained together.
+#[pyclass(name = "SccDecompositionConfig", module = "biodivine_aeon", frozen)]
+#[derive(Clone)]
+pub struct PySccDecompositionConfig {
+    pub inner: SccDecomposition,
+    pub ctx: Py<SymbolicContext>,
+}
+
+impl PySccDecompositionConfig {
+    fn extract_inner(self) -> (SccDecompositionConfig, Py<SymbolicContext>) {
+        (self.inner.into_config(), self.ctx)
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PySccDecompositionConfig {
+    #[new]
+    #[pyo3(signature = (graph_representation, restriction = None, time_limit_millis = None, bdd_size_limit = None, steps_limit = None, cancellation = None, progress = None))]
+    pub fn new_py(
+        graph_representation: PyGraphRepresentation,
+        restriction: Option<&ColoredVertexSet>,
+        time_limit_millis: Option<u64>,
+        bdd_size_limit: Option<usize>,
+        steps_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        let (mut config, ctx) =
+            PySccDecompositionConfig::try_from(graph_representation)?.extract_inner();
+
+        if let Some(restriction) = restriction {
+            config = config.with_restriction(restriction.as_native().clone())
+        }
+
+        if let Some(millis) = time_limit_millis {
+            config = config.with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(millis),
+            )))
+        }
+
+        if let Some(size_limit) = bdd_size_limit {
+            config = config.with_bdd_size_limit(size_limit)
+        }
+
+        if let Some(steps_limit) = steps_limit {
+            config = config.with_steps_limit(steps_limit)
+        }
+
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
+        Ok(PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx,
+        })
+    }
+
+    /// Create a new `SccDecompositionConfig` from the given `AsynchronousGraph` or
+    /// `BooleanNetwork`, with otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        PySccDecompositionConfig::try_from(graph_representation)
+    }
+
+    /// Restricts the decomposition to the sub-graph induced by the given set of vertices.
+    ///
+    /// Default: `graph.unit_colored_vertices()`.
+    pub fn with_restriction(&self, restriction: &ColoredVertexSet) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_restriction(restriction.as_native().clone());
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a time limit for the SCC decomposition, in milliseconds.
+    ///
+    /// Default: no time limit.
+    // TODO: if we ever move away from abi3-py37, use Duration as an argument
+    pub fn with_time_limit(&self, duration_in_millis: u64) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(CancelTokenTimer::new(
+                Duration::from_millis(duration_in_millis),
+            )));
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a `CancelToken` that can be used to cancel the SCC decomposition.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    pub fn with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation));
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a callback that is notified about the progress of the SCC decomposition.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    pub fn with_progress(&self, progress: Py<PyAny>) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_progress(ProgressReporterPython::new(progress));
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum BDD size of the intermediate reachable sets.
+    ///
+    /// Note that the algorithms can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_bdd_size_limit(&self, bdd_size_limit: usize) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_bdd_size_limit(bdd_size_limit);
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// The maximum number of steps of the forward (or backward) reachability procedure that
+    /// computes a single component from its pivot. The limit applies to every component
+    /// separately, i.e. the whole decomposition can perform more steps in total.
+    ///
+    /// Default: `usize::MAX`.
+    pub fn with_steps_limit(&self, steps_limit: usize) -> Self {
+        let config = self.inner.config().clone().with_steps_limit(steps_limit);
+
+        PySccDecompositionConfig {
+            inner: SccDecomposition::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use pyo3::{PyResult, pyclass, pymethods};
+
+use crate::bindings::{
+    algorithms::{graph_representation::PyGraphRepresentation, token_python::IntoPyErrWithContext},
+    lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet,
+};
+
+use super::PySccDecompositionConfig;
+
+/// Implements the decomposition of an `AsynchronousGraph` into its non-trivial
+/// strongly connected components.
+#[pyclass(name = "SccDecompositionComp", module = "biodivine_aeon", frozen)]
+pub struct PySccDecomposition(PySccDecompositionConfig);
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PySccDecomposition {
+    /// Create a new `SccDecompositionComp` instance with the given `AsynchronousGraph` or
+    /// `BooleanNetwork` and otherwise default configuration.
+    #[staticmethod]
+    pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
+        Ok(PySccDecomposition(PySccDecompositionConfig::try_from(
+            graph_representation,
+        )?))
+    }
+
+    /// Create a new `SccDecompositionComp` instance with the given `SccDecompositionConfig`.
+    #[staticmethod]
+    pub fn with_config(config: PySccDecompositionConfig) -> Self {
+        PySccDecomposition(config)
+    }
+
+    /// Compute all non-trivial strongly connected components of the sub-graph induced by
+    /// the `restriction` set.
+    ///
+    /// The result is a collection of sets, such that for each set and each color holds that
+    /// if the color is present in the set, the vertices of this color in the set together
+    /// form a non-trivial SCC. The same color can appear in multiple sets.
+    pub fn decomposition(&self) -> PyResult<Vec<ColoredVertexSet>> {
+        Ok(self
+            .0
+            .inner
+            .decomposition()
+            .map_err(|e| e.into_py_err(&self.0.ctx))?
+            .into_iter()
+            .map(|it| ColoredVertexSet::mk_native(self.0.ctx.clone(), it))
+            .collect())
+    }
+}
@@ -1,6 +1,20 @@
-use pyo3::{Python, create_exception, exceptions::PyException};
+use std::time::Duration;
 
-use crate::internal::algorithms::cancellation::CancellationHandler;
+use pyo3::{
+    IntoPyObject, Py, PyErr, PyResult, Python, create_exception, exceptions::PyException, pyclass,
+    pymethods,
+};
+
+use crate::{
+    internal::algorithms::{
+        cancellation::{
+            CancelTokenAllOf, CancelTokenAnyOf, CancelTokenAtomic, CancelTokenMemory,
+            CancelTokenNever, CancelTokenStepBudget, CancelTokenTimer, CancellationHandler,
+        },
+        progress::ProgressEvent,
+    },
+    throw_type_error,
+};
 
 /// A [CancellationHandler] that wraps any ohter [CancellationHandler] and also checks for Python
 /// interrupts.
@@ -16,9 +30,19 @@ impl CancellationHandler for CancelTokenPython {
         Python::with_gil(|py| py.check_signals()).is_err()
     }
 
+    /// Python interrupts can only be checked by the thread that holds the GIL (and only the
+    /// main thread receives them), hence this only checks the wrapped handler.
+    fn is_cancelled_off_thread(&self) -> bool {
+        self.0.is_cancelled_off_thread()
+    }
+
     fn start_timer(&self) {
         self.0.start_timer()
     }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        self.0.observe_progress(event)
+    }
 }
 
 impl CancelTokenPython {
@@ -27,4 +51,240 @@ impl CancelTokenPython {
     }
 }
 
-create_exception!(cancellation, CancelledError, PyException);
+/// Run `action` on the current thread, but with the GIL released, such that other Python
+/// threads can run while a long native computation is in progress.
+///
+/// Unlike [Python::allow_threads], `action` and its result do not have to be [Send]. This is
+/// needed for algorithms that hold non-thread-safe native state (e.g. a Z3 context). Python
+/// interrupts are still detected by [CancelTokenPython], since it re-acquires the GIL on the
+/// current thread.
+///
+/// # Safety
+///
+/// The `action` must not use any GIL-bound values (e.g. [Python] or [pyo3::Bound]) that were
+/// created outside of it.
+pub unsafe fn allow_threads_unsend<T, F>(py: Python, action: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    /// Asserts that the wrapped value can be sent to a different thread.
+    struct AssertSend<T>(T);
+
+    // SAFETY: `Python::allow_threads` runs the closure on the current thread, the `Send`
+    // bound only guards against GIL-bound values (which is the contract of the caller).
+    unsafe impl<T> Send for AssertSend<T> {}
+
+    impl<T> AssertSend<T> {
+        fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    let action = AssertSend(action);
+    py.allow_threads(move || AssertSend(action.into_inner()()))
+        .into_inner()
+}
+
+/// A cancellation token that can be passed to the `with_cancellation` method of any
+/// algorithm configuration object (e.g. `ReachabilityConfig`).
+///
+/// The same token can be shared by multiple configurations. This way, a whole pipeline
+/// of algorithms can be limited by a single deadline, memory limit, or step budget.
+/// Tokens can be combined using `CancelToken.any_of` and `CancelToken.all_of`.
+///
+/// Every token can be also cancelled manually using `CancelToken.cancel`.
+///
+/// Tokens created using `CancelToken.timer` can be additionally paused, resumed, extended,
+/// or reset. This way, a single time budget can be split between multiple computations.
+#[pyclass(name = "CancelToken", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Debug)]
+pub struct PyCancelToken {
+    manual: CancelTokenAtomic,
+    inner: Box<dyn CancellationHandler>,
+    /// Set if `inner` is a [CancelTokenTimer], such that the timer can be controlled
+    /// from Python.
+    timer: Option<CancelTokenTimer>,
+}
+
+impl CancellationHandler for PyCancelToken {
+    fn is_cancelled(&self) -> bool {
+        self.manual.is_cancelled() || self.inner.is_cancelled()
+    }
+
+    fn is_cancelled_off_thread(&self) -> bool {
+        self.manual.is_cancelled() || self.inner.is_cancelled_off_thread()
+    }
+
+    fn start_timer(&self) {
+        self.inner.start_timer()
+    }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        self.inner.observe_progress(event)
+    }
+}
+
+impl PyCancelToken {
+    fn new<T: CancellationHandler + 'static>(handler: T) -> Self {
+        PyCancelToken {
+            manual: CancelTokenAtomic::default(),
+            inner: Box::new(handler),
+            timer: None,
+        }
+    }
+
+    fn as_timer(&self) -> PyResult<&CancelTokenTimer> {
+        match &self.timer {
+            Some(timer) => Ok(timer),
+            None => throw_type_error("This cancellation token is not a timer."),
+        }
+    }
+
+    /// Wrap this token into a [CancelTokenPython]. If `time_limit_millis` is given,
+    /// the result is also cancelled once this time limit elapses.
+    pub fn into_handler(self, time_limit_millis: Option<u64>) -> CancelTokenPython {
+        match time_limit_millis {
+            None => CancelTokenPython::with_inner(self),
+            Some(millis) => CancelTokenPython::with_inner(CancelTokenAnyOf::new(vec![
+                Box::new(self),
+                Box::new(CancelTokenTimer::new(Duration::from_millis(millis))),
+            ])),
+        }
+    }
+
+    fn boxed(tokens: Vec<PyCancelToken>) -> Vec<Box<dyn CancellationHandler>> {
+        tokens
+            .into_iter()
+            .map(|it| Box::new(it) as Box<dyn CancellationHandler>)
+            .collect()
+    }
+}
+
+#[pymethods]
+impl PyCancelToken {
+    /// A token that is only cancelled manually, using `CancelToken.cancel`.
+    #[staticmethod]
+    pub fn manual() -> Self {
+        PyCancelToken::new(CancelTokenNever)
+    }
+
+    /// A token that is cancelled once the given time limit (in milliseconds) elapses.
+    /// The timer starts when the token is first used by an algorithm (or when
+    /// `CancelToken.start` is called).
+    #[staticmethod]
+    pub fn timer(duration_in_millis: u64) -> Self {
+        let timer = CancelTokenTimer::new(Duration::from_millis(duration_in_millis));
+        PyCancelToken {
+            timer: Some(timer.clone()),
+            ..PyCancelToken::new(timer)
+        }
+    }
+
+    /// A token that is cancelled once the resident set size of the process exceeds
+    /// `rss_limit` bytes, or once an algorithm reports an intermediate result with more
+    /// than `bdd_size_limit` BDD nodes.
+    ///
+    /// The `rss_limit` is currently only supported on Linux and ignored elsewhere.
+    #[staticmethod]
+    #[pyo3(signature = (rss_limit = None, bdd_size_limit = None))]
+    pub fn memory(rss_limit: Option<usize>, bdd_size_limit: Option<usize>) -> Self {
+        PyCancelToken::new(CancelTokenMemory::new(rss_limit, bdd_size_limit))
+    }
+
+    /// A token that is cancelled once the algorithms using it perform `steps` steps in total.
+    /// A step corresponds to a single `ProgressEvent`.
+    #[staticmethod]
+    pub fn step_budget(steps: usize) -> Self {
+        PyCancelToken::new(CancelTokenStepBudget::new(steps))
+    }
+
+    /// A token that is cancelled once at least one of the given `tokens` is cancelled.
+    #[staticmethod]
+    pub fn any_of(tokens: Vec<PyCancelToken>) -> Self {
+        PyCancelToken::new(CancelTokenAnyOf::new(Self::boxed(tokens)))
+    }
+
+    /// A token that is cancelled once all of the given `tokens` are cancelled.
+    #[staticmethod]
+    pub fn all_of(tokens: Vec<PyCancelToken>) -> Self {
+        PyCancelToken::new(CancelTokenAllOf::new(Self::boxed(tokens)))
+    }
+
+    /// Cancel this token manually. Returns `True` if the token was not cancelled manually
+    /// before.
+    pub fn cancel(&self) -> bool {
+        self.manual.cancel()
+    }
+
+    /// Returns `True` if this token is cancelled.
+    #[pyo3(name = "is_cancelled")]
+    pub fn python_is_cancelled(&self) -> bool {
+        self.manual.is_cancelled() || self.inner.is_cancelled()
+    }
+
+    /// Start the timer of this token. Returns `False` if the timer was already started.
+    ///
+    /// Raises `TypeError` if this token was not created using `CancelToken.timer`.
+    /// The same applies to all remaining timer methods.
+    pub fn start(&self) -> PyResult<bool> {
+        Ok(self.as_timer()?.start())
+    }
+
+    /// Pause the timer of this token. Returns `False` if the timer was not running.
+    pub fn pause(&self) -> PyResult<bool> {
+        Ok(self.as_timer()?.pause())
+    }
+
+    /// Resume the paused timer of this token. Returns `False` if the timer was not paused.
+    pub fn resume(&self) -> PyResult<bool> {
+        Ok(self.as_timer()?.resume())
+    }
+
+    /// Reset the timer of this token: the elapsed time is cleared and the timer is stopped.
+    pub fn reset(&self) -> PyResult<()> {
+        self.as_timer()?.reset();
+        Ok(())
+    }
+
+    /// Increase the time limit of the timer of this token by the given number of milliseconds.
+    pub fn extend(&self, duration_in_millis: u64) -> PyResult<()> {
+        self.as_timer()?
+            .extend(Duration::from_millis(duration_in_millis));
+        Ok(())
+    }
+
+    /// The time (in milliseconds) that counted towards the time limit of this token so far.
+    pub fn elapsed_millis(&self) -> PyResult<u128> {
+        Ok(self.as_timer()?.elapsed().as_millis())
+    }
+
+    /// The time (in milliseconds) remaining until this token is cancelled by its timer.
+    pub fn remaining_millis(&self) -> PyResult<u128> {
+        Ok(self.as_timer()?.remaining().as_millis())
+    }
+}
+
+create_exception!(biodivine_aeon, CancelledError, PyException);
+create_exception!(biodivine_aeon, StepsLimitExceededError, PyException);
+create_exception!(biodivine_aeon, BddSizeLimitExceededError, PyException);
+
+/// Conversion of an algorithm error into a [PyErr] that exposes the partial result of the
+/// computation as the `partial_result` attribute of the raised exception.
+///
+/// Wrapping the partial result into a Python object requires the symbolic context `C`.
+pub trait IntoPyErrWithContext<C> {
+    fn into_py_err(self, ctx: &Py<C>) -> PyErr;
+}
+
+/// Set the `partial_result` attribute of the given `error`.
+///
+/// If the attribute cannot be set, the error describing this failure is returned instead.
+pub fn with_partial_result<'py, T>(py: Python<'py>, error: PyErr, partial_result: T) -> PyErr
+where
+    T: IntoPyObject<'py>,
+{
+    match error.value(py).setattr("partial_result", partial_result) {
+        Ok(()) => error,
+        Err(setattr_error) => setattr_error,
+    }
+}
@@ -1,8 +1,19 @@
-use pyo3::{PyErr, create_exception, exceptions::PyException};
+use biodivine_lib_param_bn::trap_spaces::NetworkColoredSpaces;
+use pyo3::{Py, PyErr, Python};
 
 use crate::{
-    bindings::algorithms::{
-        graph_representation::CreationFailedError, token_python::CancelledError,
+    AsNative as _,
+    bindings::{
+        algorithms::{
+            graph_representation::CreationFailedError,
+            token_python::{
+                BddSizeLimitExceededError, CancelledError, IntoPyErrWithContext,
+                with_partial_result,
+            },
+        },
+        lib_param_bn::symbolic::{
+            set_colored_space::ColoredSpaceSet, symbolic_space_context::SymbolicSpaceContext,
+        },
     },
     internal::algorithms::trap_spaces::TrapSpacesError,
 };
@@ -26,4 +37,17 @@ impl From<TrapSpacesError> for PyErr {
     }
 }
 
-create_exception!(trap_spaces, BddSizeLimitExceededError, PyException);
+impl IntoPyErrWithContext<SymbolicSpaceContext> for TrapSpacesError {
+    /// The partial result is a `ColoredSpaceSet` of the candidate spaces at the time
+    /// of the failure.
+    fn into_py_err(self, ctx: &Py<SymbolicSpaceContext>) -> PyErr {
+        let set = match &self {
+            TrapSpacesError::CreationFailed(_) => return self.into(),
+            TrapSpacesError::Cancelled(bdd) | TrapSpacesError::BddSizeLimitExceeded(bdd) => {
+                let native = NetworkColoredSpaces::new(bdd.clone(), ctx.get().as_native());
+                ColoredSpaceSet::wrap_native(ctx.clone(), native)
+            }
+        };
+        Python::with_gil(|py| with_partial_result(py, self.into(), set))
+    }
+}
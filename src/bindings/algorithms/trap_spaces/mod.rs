@@ -6,13 +6,16 @@ use pyo3::{
 mod _impl_pyerr;
 mod trap_spaces_config_python;
 mod trap_spaces_impl_python;
+mod trap_spaces_solver_iterator_python;
 
 pub use trap_spaces_config_python::PyTrapSpacesConfig;
 use trap_spaces_impl_python::PyTrapSpaces;
+use trap_spaces_solver_iterator_python::PyTrapSpacesSolverIterator;
 
 pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PyTrapSpaces>()?;
     module.add_class::<PyTrapSpacesConfig>()?;
+    module.add_class::<PyTrapSpacesSolverIterator>()?;
 
     Ok(())
 }
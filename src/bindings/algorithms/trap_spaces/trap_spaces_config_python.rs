@@ -1,12 +1,14 @@
 use std::time::Duration;
 
-use pyo3::{Py, PyResult, pyclass, pymethods};
+use pyo3::{Py, PyAny, PyResult, pyclass, pymethods};
 
 use crate::{
     AsNative as _,
     bindings::{
         algorithms::{
-            graph_representation::PyGraphRepresentation, token_python::CancelTokenPython,
+            graph_representation::PyGraphRepresentation,
+            progress_python::ProgressReporterPython,
+            token_python::{CancelTokenPython, PyCancelToken},
         },
         lib_param_bn::symbolic::{
             asynchronous_graph::AsynchronousGraph, set_colored_space::ColoredSpaceSet,
@@ -25,8 +27,8 @@ use crate::{
 /// a restriction set for the spaces, a time limit, and a BDD size limit. The configuration
 /// can be created using a Python constructor or the `create_from` method, and you can modify it using the
 /// `with_*` methods.
-/// Currently, the only supported graph representation is `BooleanNetwork`. For creation from
-/// `AsynchronousGraph`, use `create_from_graph_with_context`.
+/// When created from an `AsynchronousGraph`, a new `SymbolicSpaceContext` is created for the
+/// graph. To reuse an existing context, use `create_from_graph_with_context`.
 /// The configuration is immutable, meaning that each `with_*` method
 /// returns a new instance of `TrapSpacesConfig` with the specified modifications.
 /// This API design means the method calls can be chained together.
@@ -48,12 +50,14 @@ impl PyTrapSpacesConfig {
 #[pymethods]
 impl PyTrapSpacesConfig {
     #[new]
-    #[pyo3(signature = (graph_representation, restriction = None, time_limit_millis = None, bdd_size_limit = None))]
+    #[pyo3(signature = (graph_representation, restriction = None, time_limit_millis = None, bdd_size_limit = None, cancellation = None, progress = None))]
     pub fn python_new(
         graph_representation: PyGraphRepresentation,
         restriction: Option<&ColoredSpaceSet>,
         time_limit_millis: Option<u64>,
         bdd_size_limit: Option<usize>,
+        cancellation: Option<PyCancelToken>,
+        progress: Option<Py<PyAny>>,
     ) -> PyResult<Self> {
         let (mut config, ctx) = PyTrapSpacesConfig::try_from(graph_representation)?.extract_inner();
 
@@ -71,15 +75,25 @@ impl PyTrapSpacesConfig {
             config = config.with_bdd_size_limit(size_limit)
         }
 
+        if let Some(cancellation) = cancellation {
+            config = config.with_cancellation(cancellation.into_handler(time_limit_millis))
+        }
+
+        if let Some(progress) = progress {
+            config = config.with_progress(ProgressReporterPython::new(progress))
+        }
+
         Ok(PyTrapSpacesConfig {
             inner: TrapSpaces::with_config(config),
             ctx,
         })
     }
 
-    /// Create a new `TrapSpacesConfig` from the given `BooleanNetwork`,
+    /// Create a new `TrapSpacesConfig` from the given `BooleanNetwork` or `AsynchronousGraph`,
     /// with otherwise default configuration.
-    /// `AsynchronousGraph` is currently not supported, use `create_from_graph_with_context` instead.
+    ///
+    /// For an `AsynchronousGraph`, the default restriction only contains the spaces that are
+    /// fully within the `unit_colored_vertices` of the graph.
     #[staticmethod]
     pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
         PyTrapSpacesConfig::try_from(graph_representation)
@@ -136,6 +150,42 @@ impl PyTrapSpacesConfig {
         }
     }
 
+    /// Sets a `CancelToken` that can be used to cancel the trap spaces computation.
+    /// The same token can be shared by multiple configurations. This replaces any previous
+    /// time limit.
+    ///
+    /// Default: no cancellation.
+    pub fn with_cancellation(&self, cancellation: PyCancelToken) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_cancellation(CancelTokenPython::with_inner(cancellation));
+
+        PyTrapSpacesConfig {
+            inner: TrapSpaces::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
+    /// Sets a callback that is notified about the progress of the trap spaces computation.
+    /// The callback is either a function that accepts a `ProgressEvent`, or a `tqdm`
+    /// progress bar.
+    ///
+    /// Default: no progress reporting.
+    pub fn with_progress(&self, progress: Py<PyAny>) -> Self {
+        let config = self
+            .inner
+            .config()
+            .clone()
+            .with_progress(ProgressReporterPython::new(progress));
+
+        PyTrapSpacesConfig {
+            inner: TrapSpaces::with_config(config),
+            ctx: self.ctx.clone(),
+        }
+    }
+
     /// Sets a limit on the size of the BDD used in the merging process.
     ///
     /// Note that the algorithm can use other auxiliary BDDs that do not
@@ -1,17 +1,23 @@
-use pyo3::{Py, PyResult, pyclass, pymethods};
+use std::collections::HashMap;
+
+use pyo3::{Py, PyResult, Python, pyclass, pymethods};
 
 use crate::{
     AsNative as _,
     bindings::{
-        algorithms::graph_representation::PyGraphRepresentation,
+        algorithms::{
+            graph_representation::PyGraphRepresentation,
+            token_python::{IntoPyErrWithContext, allow_threads_unsend},
+        },
         lib_param_bn::symbolic::{
-            asynchronous_graph::AsynchronousGraph, set_colored_space::ColoredSpaceSet,
+            asynchronous_graph::AsynchronousGraph, set_color::ColorSet,
+            set_colored_space::ColoredSpaceSet, symbolic_context::SymbolicContext,
             symbolic_space_context::SymbolicSpaceContext,
         },
     },
 };
 
-use super::PyTrapSpacesConfig;
+use super::{PyTrapSpacesConfig, PyTrapSpacesSolverIterator};
 
 /// Implments trap spaces search over an `AsynchronousGraph` and its
 /// `SymbolicSpaceContext`.
@@ -22,8 +28,11 @@ pub struct PyTrapSpaces(PyTrapSpacesConfig);
 /// within Rust.
 #[pymethods]
 impl PyTrapSpaces {
-    /// Create a new `TrapSpacesComp` instance with the given `BooleanNetwork`,
-    /// `AsynchronousGraph` is currently not supported.
+    /// Create a new `TrapSpacesComp` instance with the given `BooleanNetwork` or
+    /// `AsynchronousGraph`.
+    ///
+    /// For an `AsynchronousGraph`, a new `SymbolicSpaceContext` is created and only spaces that
+    /// are fully within the `unit_colored_vertices` of the graph are considered.
     #[staticmethod]
     pub fn create_from(graph_representation: PyGraphRepresentation) -> PyResult<Self> {
         Ok(PyTrapSpaces(PyTrapSpacesConfig::try_from(
@@ -56,7 +65,10 @@ impl PyTrapSpaces {
     pub fn essential_symbolic(&self) -> PyResult<ColoredSpaceSet> {
         Ok(ColoredSpaceSet::wrap_native(
             self.0.ctx.clone(),
-            self.0.inner.essential_symbolic()?,
+            self.0
+                .inner
+                .essential_symbolic()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 
@@ -68,7 +80,77 @@ impl PyTrapSpaces {
     pub fn minimal_symbolic(&self) -> PyResult<ColoredSpaceSet> {
         Ok(ColoredSpaceSet::wrap_native(
             self.0.ctx.clone(),
-            self.0.inner.minimal_symbolic()?,
+            self.0
+                .inner
+                .minimal_symbolic()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+        ))
+    }
+
+    /// Computes the maximal coloured trap spaces of the underlying `graph` within the configured
+    /// `restriction` set.
+    ///
+    /// The whole state space is trivially a trap space and is therefore not considered, i.e. the
+    /// result contains the maximal *proper* trap spaces.
+    pub fn maximal_symbolic(&self) -> PyResult<ColoredSpaceSet> {
+        Ok(ColoredSpaceSet::wrap_native(
+            self.0.ctx.clone(),
+            self.0
+                .inner
+                .maximal_symbolic()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+        ))
+    }
+
+    /// Count the number of spaces in the given `set` for each color.
+    ///
+    /// The result maps each count to the `ColorSet` of colors which have exactly this number
+    /// of spaces. Colors of the `restriction` set that have no spaces are mapped to zero.
+    /// For example, `count_per_color(minimal_symbolic())` classifies the colors by the number
+    /// of their minimal trap spaces.
+    pub fn count_per_color(
+        &self,
+        py: Python,
+        set: &ColoredSpaceSet,
+    ) -> PyResult<HashMap<usize, ColorSet>> {
+        let parent = self.0.ctx.extract::<Py<SymbolicContext>>(py)?;
+        Ok(self
+            .0
+            .inner
+            .count_per_color(set.as_native())
+            .map_err(|e| e.into_py_err(&self.0.ctx))?
+            .into_iter()
+            .map(|(count, colors)| (count, ColorSet::mk_native(parent.clone(), colors)))
+            .collect())
+    }
+
+    /// Computes the minimal coloured trap spaces within the configured `restriction` set using
+    /// the Z3 solver. If `limit` is given, at most `limit` minimal trap spaces (i.e. space-color
+    /// pairs) are returned.
+    ///
+    /// This is typically much faster than `minimal_symbolic()` for large networks with few
+    /// parameters, but each minimal trap space has to be enumerated explicitly.
+    #[pyo3(signature = (limit = None))]
+    pub fn minimal_solver(&self, py: Python, limit: Option<usize>) -> PyResult<ColoredSpaceSet> {
+        // The solver can run for a long time, hence it should not block other Python threads.
+        // SAFETY: The native component does not hold any GIL-bound values.
+        let result = unsafe { allow_threads_unsend(py, || self.0.inner.minimal_solver(limit)) };
+        Ok(ColoredSpaceSet::wrap_native(
+            self.0.ctx.clone(),
+            result.map_err(|e| e.into_py_err(&self.0.ctx))?,
+        ))
+    }
+
+    /// Enumerate the minimal coloured trap spaces within the configured `restriction` set one
+    /// by one using the Z3 solver. The result is a `TrapSpacesSolverIterator` of
+    /// `(ColorModel, SpaceModel)` pairs.
+    pub fn minimal_solver_iterator(&self) -> PyResult<PyTrapSpacesSolverIterator> {
+        Ok(PyTrapSpacesSolverIterator::new(
+            self.0
+                .inner
+                .minimal_solver_iterator()
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
+            self.0.ctx.clone(),
         ))
     }
 
@@ -76,7 +158,10 @@ impl PyTrapSpaces {
     pub fn minimize(&self, set: &ColoredSpaceSet) -> PyResult<ColoredSpaceSet> {
         Ok(ColoredSpaceSet::wrap_native(
             self.0.ctx.clone(),
-            self.0.inner.minimize(set.as_native())?,
+            self.0
+                .inner
+                .minimize(set.as_native())
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 
@@ -84,7 +169,10 @@ impl PyTrapSpaces {
     pub fn maximize(&self, set: &ColoredSpaceSet) -> PyResult<ColoredSpaceSet> {
         Ok(ColoredSpaceSet::wrap_native(
             self.0.ctx.clone(),
-            self.0.inner.maximize(set.as_native())?,
+            self.0
+                .inner
+                .maximize(set.as_native())
+                .map_err(|e| e.into_py_err(&self.0.ctx))?,
         ))
     }
 }
@@ -0,0 +1,89 @@
+use biodivine_lib_bdd::BddPartialValuation;
+use pyo3::{Py, PyResult, Python, pyclass, pymethods};
+
+use crate::{
+    AsNative as _,
+    bindings::{
+        algorithms::token_python::{IntoPyErrWithContext, allow_threads_unsend},
+        lib_param_bn::symbolic::{
+            model_color::ColorModel, model_space::SpaceModel, set_colored_space::ColoredSpaceSet,
+            symbolic_context::SymbolicContext, symbolic_space_context::SymbolicSpaceContext,
+        },
+    },
+    internal::algorithms::trap_spaces::TrapSpacesSolverIterator,
+};
+
+/// An iterator that lazily enumerates the minimal trap spaces of an `AsynchronousGraph` using
+/// the Z3 solver. Each item is a `(ColorModel, SpaceModel)` pair, such that the space is
+/// a minimal trap space for the given color.
+///
+/// If the computation is cancelled, the corresponding exception is raised and the iterator is
+/// exhausted. The trap spaces returned before the exception remain available through `found`.
+#[pyclass(
+    name = "TrapSpacesSolverIterator",
+    module = "biodivine_aeon",
+    unsendable
+)]
+pub struct PyTrapSpacesSolverIterator {
+    inner: TrapSpacesSolverIterator,
+    ctx: Py<SymbolicSpaceContext>,
+}
+
+impl PyTrapSpacesSolverIterator {
+    pub fn new(inner: TrapSpacesSolverIterator, ctx: Py<SymbolicSpaceContext>) -> Self {
+        PyTrapSpacesSolverIterator { inner, ctx }
+    }
+}
+
+/// These methods are Python facing wrappers of native methods and thus should not be used from
+/// within Rust.
+#[pymethods]
+impl PyTrapSpacesSolverIterator {
+    fn __iter__(self_: Py<Self>) -> Py<Self> {
+        self_
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<(ColorModel, SpaceModel)>> {
+        // The solver can run for a long time, hence it should not block other Python threads.
+        // SAFETY: The native iterator does not hold any GIL-bound values.
+        let next = unsafe { allow_threads_unsend(py, || self.inner.next()) };
+        let Some(trap_space) = next else {
+            return Ok(None);
+        };
+        let trap_space = trap_space.map_err(|e| e.into_py_err(&self.ctx))?;
+        let valuation = trap_space
+            .as_bdd()
+            .sat_witness()
+            .expect("Trap space set is not empty.");
+
+        // Split the valuation into the color and the space part (see also
+        // `ColoredSpaceSet.items`).
+        let parent = self.ctx.extract::<Py<SymbolicContext>>(py)?;
+        let mut color_val = BddPartialValuation::from(valuation);
+        let mut space_val = color_val.clone();
+        let native_ctx = self.ctx.get().as_native().inner_context();
+        for s_var in native_ctx.state_variables() {
+            color_val.unset_value(*s_var);
+            space_val.unset_value(*s_var);
+        }
+        for s_var in native_ctx.all_extra_state_variables() {
+            color_val.unset_value(*s_var);
+        }
+        for p_var in native_ctx.parameter_variables() {
+            space_val.unset_value(*p_var);
+        }
+        let color = ColorModel::new_native(
+            parent,
+            color_val,
+            native_ctx.network_implicit_parameters(),
+            native_ctx.network_parameters().collect(),
+        );
+        let space = SpaceModel::new_native(self.ctx.clone(), space_val);
+        Ok(Some((color, space)))
+    }
+
+    /// The union of all minimal trap spaces that were returned by this iterator so far.
+    pub fn found(&self) -> ColoredSpaceSet {
+        ColoredSpaceSet::wrap_native(self.ctx.clone(), self.inner.found())
+    }
+}
@@ -115,7 +115,7 @@ impl Class {
     }
 
     /// Return the `list` of features, including duplicates, that appear in this `Class`.
-    fn feature_list(&self) -> Vec<String> {
+    pub fn feature_list(&self) -> Vec<String> {
         self.items.clone()
     }
 
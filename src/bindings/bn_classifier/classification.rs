@@ -1,5 +1,5 @@
 use std::cmp::max;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use biodivine_hctl_model_checker::mc_utils::get_extended_symbolic_graph;
 use biodivine_hctl_model_checker::model_checking::model_check_multiple_extended_formulae_dirty;
@@ -24,6 +24,7 @@ use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSe
 use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
 use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
 use crate::bindings::pbn_control::extract_phenotype_type;
+use crate::internal::classification::classify::refine_classification;
 use crate::internal::classification::load_inputs::load_classification_archive;
 use crate::internal::classification::write_output::build_classification_archive;
 use crate::internal::scc::{Behaviour, Classifier};
@@ -301,6 +302,107 @@ impl Classification {
         Ok((network, classification, annotations))
     }
 
+    /// Refine a loaded `classification` (see `Classification.load_classification`) using new
+    /// named HCTL `properties`, and save the updated classification into the archive at `path`.
+    ///
+    /// Only the new properties are model checked, and only within the colors that already
+    /// appear in the `classification`. Each existing class is then split based on which of the
+    /// new properties are satisfied. The updated classification is also returned.
+    ///
+    /// The new properties are added into the `annotations` dictionary, such that the updated
+    /// archive contains all the properties. If a property of the same name already exists,
+    /// the method fails with a `RuntimeError`. The `annotations` are only updated once all
+    /// the new properties are successfully evaluated.
+    ///
+    #[staticmethod]
+    pub fn refine_classification(
+        py: Python,
+        path: String,
+        network: Py<BooleanNetwork>,
+        classification: HashMap<Class, ColorSet>,
+        annotations: ModelAnnotation,
+        properties: &Bound<'_, PyDict>,
+    ) -> PyResult<HashMap<Class, ColorSet>> {
+        let mut new_properties = Vec::new();
+        for (k, v) in properties {
+            let name = k.extract::<String>()?;
+            let formula = HctlFormula::new(&v, false, None)?;
+            new_properties.push((name, formula.__str__()));
+        }
+        new_properties.sort_by(|(x, _), (y, _)| x.cmp(y));
+
+        // Fail early if one of the properties already exists. The annotations are only
+        // updated once the new properties are successfully evaluated.
+        let properties_node = annotations.__getitem__("dynamic_property");
+        for (name, _) in &new_properties {
+            if properties_node.__getitem__(name).get_value(py).is_some() {
+                return throw_runtime_error(format!("Property `{name}` is already set."));
+            }
+        }
+
+        let native_network = network.borrow(py).as_native().clone();
+        let ctx = RsSymbolicContext::new(&native_network).map_err(runtime_error)?;
+        let classes = classification
+            .into_iter()
+            .map(|(k, v)| {
+                if let Some(bdd) =
+                    ctx.transfer_from(v.as_native().as_bdd(), v.__ctx__().get().as_native())
+                {
+                    Ok((k.as_serial_string(), GraphColors::new(bdd, &ctx)))
+                } else {
+                    throw_runtime_error(
+                        "One of the class sets is not compatible with the given network.",
+                    )
+                }
+            })
+            .collect::<PyResult<HashMap<_, _>>>()?;
+
+        let classes = py
+            .allow_threads(|| refine_classification(&native_network, classes, &new_properties))
+            .map_err(runtime_error)?;
+
+        Classification::write_dynamic_properties(py, &annotations, new_properties)?;
+
+        let ctx = Py::new(py, SymbolicContext::new(py, network.clone_ref(py), None)?)?;
+        let classification = classes
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    Class::from_serial_string(k),
+                    ColorSet::mk_native(ctx.clone(), v),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        Classification::save_classification(
+            py,
+            path,
+            &network.borrow(py),
+            classification.clone(),
+            Some(annotations),
+        )?;
+        Ok(classification)
+    }
+
+    /// Run the whole [BN Classifier](https://github.com/sybila/biodivine-bn-classifier/)
+    /// workflow on the annotated `.aeon` model stored in `model_path`.
+    ///
+    /// The dynamic assertions and properties are read from the model annotations (see
+    /// `Classification.read_dynamic_assertions` and `Classification.read_dynamic_properties`).
+    /// The resulting classification archive is written into `output_zip` and can be loaded
+    /// using `Classification.load_classification`. Besides the classes, the archive contains
+    /// a human-readable `report.txt` summary.
+    ///
+    /// The same workflow is also available as a standalone `classify` command-line tool.
+    ///
+    #[staticmethod]
+    pub fn classify(py: Python, model_path: String, output_zip: String) -> PyResult<()> {
+        py.allow_threads(|| {
+            crate::internal::classification::classify::classify(&model_path, &output_zip)
+        })
+        .map_err(runtime_error)
+    }
+
     /// Classify the interpretations (colors) of the provided `component` based on their long-term
     /// behavior. That is:
     ///
@@ -613,6 +715,10 @@ impl Classification {
     /// are always transformed back into an encoding that is valid for the `graph` that is given
     /// as the first argument.
     ///
+    /// All assertions and properties are checked together, i.e. the results of subformulae that
+    /// appear in multiple assertions or properties are only computed once.
+    /// The resulting classes only contain colors that satisfy all assertions.
+    ///
     #[staticmethod]
     #[pyo3(signature = (graph, properties, assertions = None, substitution = None))]
     pub fn classify_dynamic_properties(
@@ -667,15 +773,19 @@ impl Classification {
             HashMap::new()
         };
 
-        // First, filter assertions:
-
+        // Assertions and properties are checked together, such that the results of subformulae
+        // that are shared between them are only computed once.
         let str_vec = hctl_str_assertions
             .iter()
             .map(|it| it.as_str())
+            .chain(hctl_str_properties.iter().map(|(_, value)| value.as_str()))
             .collect::<Vec<_>>();
-        let results =
+        let mut results =
             model_check_multiple_extended_formulae_dirty(str_vec, &mc_graph, &native_substitution)
                 .map_err(runtime_error)?;
+        let property_results = results.split_off(hctl_str_assertions.len());
+
+        // First, filter assertions:
 
         let mut valid_colors = mc_graph.mk_unit_colors();
         for set in results {
@@ -692,14 +802,6 @@ impl Classification {
 
         // Then, we look at the actual properties:
 
-        let str_vec = hctl_str_properties
-            .iter()
-            .map(|(_, value)| value.as_str())
-            .collect::<Vec<_>>();
-        let results =
-            model_check_multiple_extended_formulae_dirty(str_vec, &mc_graph, &native_substitution)
-                .map_err(runtime_error)?;
-
         let valid_colors_sanitized = graph
             .as_native()
             .transfer_colors_from(&valid_colors, &mc_graph)
@@ -711,9 +813,12 @@ impl Classification {
             ColorSet::mk_native(graph.symbolic_context(), valid_colors_sanitized),
         );
 
-        for ((name, _), set) in hctl_properties.into_iter().zip(results) {
+        for ((name, _), set) in hctl_properties.into_iter().zip(property_results) {
             let invalid_set = mc_graph.unit_colored_vertices().minus(&set);
-            let valid_set = mc_graph.unit_colors().minus(&invalid_set.colors());
+            let valid_set = mc_graph
+                .unit_colors()
+                .minus(&invalid_set.colors())
+                .intersect(&valid_colors);
             let valid_set_sanitized = graph
                 .as_native()
                 .transfer_colors_from(&valid_set, &mc_graph)
@@ -730,6 +835,217 @@ impl Classification {
 
         Ok(classification)
     }
+
+    /// Compare two classifications of the same network (e.g. as returned by
+    /// `Classification.load_classification`) and return the colors that "moved" between
+    /// classes. That is, for every pair of *different* classes `(a, b)`, the result contains
+    /// the colors that belong to `a` in the `left` classification and to `b` in the `right`
+    /// classification (pairs with no such colors are omitted).
+    ///
+    /// Colors that only appear in one of the two classifications are reported with `None`
+    /// in place of the missing class. That is, `(a, None)` contains the colors of class `a`
+    /// that are missing in the `right` classification, and `(None, b)` contains the colors
+    /// of class `b` that are missing in the `left` classification.
+    ///
+    /// The method fails with a `RuntimeError` if the two classifications use incompatible
+    /// symbolic encodings. To also check that the underlying networks are the same, see
+    /// `Classification.check_compatibility`.
+    ///
+    #[staticmethod]
+    pub fn diff_classification(
+        left: HashMap<Class, ColorSet>,
+        right: HashMap<Class, ColorSet>,
+    ) -> PyResult<HashMap<(Option<Class>, Option<Class>), ColorSet>> {
+        let Some((ctx, left, right)) =
+            unify_classifications(&left, &right).map_err(runtime_error)?
+        else {
+            return Ok(HashMap::new());
+        };
+        let mut diff = HashMap::new();
+        for (left_cls, left_set) in &left {
+            for (right_cls, right_set) in &right {
+                if left_cls == right_cls {
+                    continue;
+                }
+                let moved = left_set.intersect(right_set);
+                if !moved.is_empty() {
+                    let key = (Some(left_cls.clone()), Some(right_cls.clone()));
+                    diff.insert(key, ColorSet::mk_native(ctx.clone(), moved));
+                }
+            }
+        }
+
+        // Colors that only appear on one side are paired with `None`.
+        let union = |classification: &[(Class, GraphColors)]| {
+            classification
+                .iter()
+                .map(|(_, set)| set.clone())
+                .reduce(|a, b| a.union(&b))
+        };
+        let (left_colors, right_colors) = (union(&left), union(&right));
+        for (cls, set) in &left {
+            let missing = match &right_colors {
+                Some(right_colors) => set.minus(right_colors),
+                None => set.clone(),
+            };
+            if !missing.is_empty() {
+                let key = (Some(cls.clone()), None);
+                diff.insert(key, ColorSet::mk_native(ctx.clone(), missing));
+            }
+        }
+        for (cls, set) in &right {
+            let missing = match &left_colors {
+                Some(left_colors) => set.minus(left_colors),
+                None => set.clone(),
+            };
+            if !missing.is_empty() {
+                let key = (None, Some(cls.clone()));
+                diff.insert(key, ColorSet::mk_native(ctx.clone(), missing));
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Merge two classifications of the same network that are based on disjoint sets of
+    /// features (e.g. two archives with different properties) into a "product" classification.
+    ///
+    /// Each class of the result combines the features of one `left` class and one `right`
+    /// class, and contains the colors that belong to both. As such, colors that only appear
+    /// in one of the classifications are not included in the result.
+    ///
+    /// The method fails with a `RuntimeError` if the classifications share some features,
+    /// or if they use incompatible symbolic encodings (see also
+    /// `Classification.check_compatibility`).
+    ///
+    #[staticmethod]
+    pub fn merge_classification(
+        left: HashMap<Class, ColorSet>,
+        right: HashMap<Class, ColorSet>,
+    ) -> PyResult<HashMap<Class, ColorSet>> {
+        // Empty features appear when loading the "empty" class from an archive.
+        let features = |classification: &HashMap<Class, ColorSet>| {
+            classification
+                .keys()
+                .flat_map(|cls| cls.feature_list())
+                .filter(|it| !it.is_empty())
+                .collect::<HashSet<_>>()
+        };
+        let left_features = features(&left);
+        let mut shared = features(&right)
+            .intersection(&left_features)
+            .cloned()
+            .collect::<Vec<_>>();
+        if !shared.is_empty() {
+            shared.sort();
+            return throw_runtime_error(format!("Both classifications use features {shared:?}."));
+        }
+
+        let Some((ctx, left, right)) =
+            unify_classifications(&left, &right).map_err(runtime_error)?
+        else {
+            return Ok(HashMap::new());
+        };
+        let mut product = HashMap::new();
+        for (left_cls, left_set) in &left {
+            for (right_cls, right_set) in &right {
+                let both = left_set.intersect(right_set);
+                if both.is_empty() {
+                    continue;
+                }
+                let mut features = left_cls.feature_list();
+                features.extend(right_cls.feature_list());
+                features.retain(|it| !it.is_empty());
+                let cls = Class::new_native(features);
+                product.insert(cls, ColorSet::mk_native(ctx.clone(), both));
+            }
+        }
+        Ok(product)
+    }
+
+    /// Check whether two loaded classifications (see `Classification.load_classification`)
+    /// can be compared or merged. The result is a list of human-readable reasons why the
+    /// classifications are incompatible, i.e. an empty list means the classifications are
+    /// compatible.
+    ///
+    /// The classifications are incompatible if the networks differ (variables, regulations,
+    /// update functions, or parameters), or if the color sets use incompatible symbolic
+    /// encodings.
+    ///
+    #[staticmethod]
+    pub fn check_compatibility(
+        left_network: &BooleanNetwork,
+        left: HashMap<Class, ColorSet>,
+        right_network: &BooleanNetwork,
+        right: HashMap<Class, ColorSet>,
+    ) -> Vec<String> {
+        let mut issues = Vec::new();
+        let (left_bn, right_bn) = (left_network.as_native(), right_network.as_native());
+        let left_names = left_bn.variables().map(|v| left_bn.get_variable_name(v));
+        let right_names = right_bn.variables().map(|v| right_bn.get_variable_name(v));
+        if !left_names.eq(right_names) {
+            issues.push("The networks have different variables.".to_string());
+        } else if left_bn.as_graph() != right_bn.as_graph() {
+            issues.push("The networks have different regulations.".to_string());
+        } else {
+            for var in left_bn.variables() {
+                if left_bn.get_update_function(var) != right_bn.get_update_function(var) {
+                    let name = left_bn.get_variable_name(var);
+                    issues.push(format!(
+                        "The networks have different update functions of `{name}`."
+                    ));
+                }
+            }
+            if issues.is_empty() && left_bn != right_bn {
+                issues.push("The networks have different parameters.".to_string());
+            }
+        }
+
+        if let Err(error) = unify_classifications(&left, &right) {
+            issues.push(error);
+        }
+        issues
+    }
+}
+
+/// Transfer the color sets of the `left` and `right` classification into a common symbolic
+/// context (the context of an arbitrary set). Returns `None` if both classifications are empty.
+///
+/// Fails if some of the sets are not compatible with the common context.
+#[allow(clippy::type_complexity)]
+fn unify_classifications(
+    left: &HashMap<Class, ColorSet>,
+    right: &HashMap<Class, ColorSet>,
+) -> Result<
+    Option<(
+        Py<SymbolicContext>,
+        Vec<(Class, GraphColors)>,
+        Vec<(Class, GraphColors)>,
+    )>,
+    String,
+> {
+    let Some(first) = left.values().chain(right.values()).next() else {
+        return Ok(None);
+    };
+    let ctx = first.__ctx__();
+    let native_ctx = ctx.get().as_native();
+    let transfer = |classification: &HashMap<Class, ColorSet>| {
+        classification
+            .iter()
+            .map(|(cls, set)| {
+                let bdd = set.as_native().as_bdd();
+                match native_ctx.transfer_from(bdd, set.__ctx__().get().as_native()) {
+                    Some(bdd) => Ok((cls.clone(), GraphColors::new(bdd, native_ctx))),
+                    None => Err(format!(
+                        "Class {} uses an incompatible symbolic encoding.",
+                        cls.__str__()
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let (left, right) = (transfer(left)?, transfer(right)?);
+    Ok(Some((ctx.clone(), left, right)))
 }
 
 /// Extract an annotation object from a Python object.
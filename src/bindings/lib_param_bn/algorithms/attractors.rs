@@ -1,11 +1,18 @@
+use std::collections::HashSet;
+
+use crate::AsNative;
+use crate::bindings::algorithms::token_python::{CancelTokenPython, IntoPyErrWithContext};
 use crate::bindings::lib_param_bn::NetworkVariableContext;
+use crate::bindings::lib_param_bn::algorithms::warn_deprecated;
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
-use crate::internal::scc::algo_interleaved_transition_guided_reduction::interleaved_transition_guided_reduction;
-use crate::{AsNative, global_log_level};
+use crate::internal::algorithms::attractors::{Attractors as AttractorsComp, AttractorsConfig};
+use crate::internal::algorithms::configurable::{Config, Configurable};
 use pyo3::prelude::*;
 use pyo3::types::PyList;
 
+/// **Deprecated**: Use `AttractorsComp` instead. The methods of this class are forwarded
+/// to `AttractorsComp` with default configuration.
 #[pyclass(module = "biodivine_aeon", frozen)]
 pub struct Attractors {
     _dummy: (),
@@ -13,6 +20,7 @@ pub struct Attractors {
 
 #[pymethods]
 impl Attractors {
+    /// **Deprecated**: Use `AttractorsComp.transition_guided_reduction()` instead.
     /// Compute a subset of the given `restriction` set that is guaranteed to be a superset
     /// of all the attractors within the `restriction` set.
     ///
@@ -35,34 +43,15 @@ impl Attractors {
         to_reduce: Option<&Bound<'_, PyList>>,
         py: Python,
     ) -> PyResult<ColoredVertexSet> {
-        let mut to_reduce_native = Vec::new();
-        if let Some(to_reduce) = to_reduce {
-            for x in to_reduce {
-                to_reduce_native.push(graph.resolve_network_variable(&x)?);
-            }
-        } else {
-            to_reduce_native.extend(graph.as_native().variables());
-        }
-
-        let restriction_native = if let Some(r) = restriction {
-            r.as_native().clone()
-        } else {
-            graph.as_native().mk_unit_colored_vertices()
-        };
-
-        let (states, _) = interleaved_transition_guided_reduction(
-            graph.as_native(),
-            restriction_native,
-            &to_reduce_native,
-            global_log_level(py)?,
+        warn_deprecated(
+            py,
+            "Attractors.transition_guided_reduction",
+            "AttractorsComp.transition_guided_reduction",
         )?;
-
-        Ok(ColoredVertexSet::mk_native(
-            graph.symbolic_context(),
-            states,
-        ))
+        Self::reduce(graph, restriction, to_reduce)
     }
 
+    /// **Deprecated**: Use `AttractorsComp.xie_beerel()` instead.
     /// Perform attractor detection on the given symbolic set.
     ///
     /// This is similar to `Attractors.attractors`, but it does not perform
@@ -82,25 +71,11 @@ impl Attractors {
         restriction: Option<&ColoredVertexSet>,
         py: Python,
     ) -> PyResult<Vec<ColoredVertexSet>> {
-        let restriction_native = if let Some(r) = restriction {
-            r.as_native().clone()
-        } else {
-            graph.as_native().mk_unit_colored_vertices()
-        };
-
-        let transitions = graph.as_native().variables().collect::<Vec<_>>();
-        let result = crate::internal::scc::algo_xie_beerel::xie_beerel_attractors(
-            graph.as_native(),
-            &restriction_native,
-            &transitions,
-            global_log_level(py)?,
-        )?;
-        Ok(result
-            .into_iter()
-            .map(|it| ColoredVertexSet::mk_native(graph.symbolic_context(), it))
-            .collect())
+        warn_deprecated(py, "Attractors.xie_beerel", "AttractorsComp.xie_beerel")?;
+        Self::detect(graph, restriction)
     }
 
+    /// **Deprecated**: Use `AttractorsComp.attractors()` instead.
     /// Compute the (colored) attractor set of the given `AsynchronousGraph`.
     ///
     /// See `Attractors.xie_beerel` and `Attractors.transition_guided_reduction` for relevant
@@ -113,7 +88,63 @@ impl Attractors {
         to_reduce: Option<&Bound<'_, PyList>>,
         py: Python,
     ) -> PyResult<Vec<ColoredVertexSet>> {
-        let reduced = Self::transition_guided_reduction(graph, restriction, to_reduce, py)?;
-        Self::xie_beerel(graph, Some(&reduced), py)
+        warn_deprecated(py, "Attractors.attractors", "AttractorsComp.attractors")?;
+        let reduced = Self::reduce(graph, restriction, to_reduce)?;
+        Self::detect(graph, Some(&reduced))
+    }
+}
+
+impl Attractors {
+    /// Create an [AttractorsConfig] for the given `graph` and optional `restriction` that
+    /// can be interrupted from Python.
+    fn config(
+        graph: &AsynchronousGraph,
+        restriction: Option<&ColoredVertexSet>,
+    ) -> AttractorsConfig {
+        let mut config = AttractorsConfig::from(graph.as_native().clone())
+            .with_cancellation(CancelTokenPython::default());
+        if let Some(restriction) = restriction {
+            config = config.with_restriction(restriction.as_native().clone());
+        }
+        config
+    }
+
+    /// Run [AttractorsComp::transition_guided_reduction], only reducing the `to_reduce`
+    /// variables, if given. The transitions of all variables are still considered.
+    fn reduce(
+        graph: &AsynchronousGraph,
+        restriction: Option<&ColoredVertexSet>,
+        to_reduce: Option<&Bound<'_, PyList>>,
+    ) -> PyResult<ColoredVertexSet> {
+        let mut config = Self::config(graph, restriction);
+        if let Some(to_reduce) = to_reduce {
+            let mut reduced_variables = HashSet::new();
+            for x in to_reduce {
+                reduced_variables.insert(graph.resolve_network_variable(&x)?);
+            }
+            config = config.with_reduced_variables(reduced_variables);
+        }
+
+        let result = AttractorsComp::with_config(config)
+            .transition_guided_reduction()
+            .map_err(|e| e.into_py_err(&graph.symbolic_context()))?;
+        Ok(ColoredVertexSet::mk_native(
+            graph.symbolic_context(),
+            result,
+        ))
+    }
+
+    /// Run [AttractorsComp::xie_beerel] using the transitions of all variables.
+    fn detect(
+        graph: &AsynchronousGraph,
+        restriction: Option<&ColoredVertexSet>,
+    ) -> PyResult<Vec<ColoredVertexSet>> {
+        let result = AttractorsComp::with_config(Self::config(graph, restriction))
+            .xie_beerel()
+            .map_err(|e| e.into_py_err(&graph.symbolic_context()))?;
+        Ok(result
+            .into_iter()
+            .map(|it| ColoredVertexSet::mk_native(graph.symbolic_context(), it))
+            .collect())
     }
 }
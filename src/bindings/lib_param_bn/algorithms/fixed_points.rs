@@ -1,10 +1,18 @@
+use crate::AsNative;
+use crate::bindings::algorithms::token_python::{CancelTokenPython, IntoPyErrWithContext};
+use crate::bindings::lib_param_bn::algorithms::warn_deprecated;
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
 use crate::bindings::lib_param_bn::symbolic::set_color::ColorSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
 use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
-use crate::{AsNative, global_log_level};
+use crate::internal::algorithms::configurable::{Config, Configurable};
+use crate::internal::algorithms::fixed_points::{
+    FixedPoints as FixedPointsComp, FixedPointsConfig,
+};
 use pyo3::prelude::*;
 
+/// **Deprecated**: Use `FixedPointsComp` instead. The methods of this class are forwarded
+/// to `FixedPointsComp` with default configuration.
 #[pyclass(module = "biodivine_aeon", frozen)]
 pub struct FixedPoints {
     _dummy: (),
@@ -22,19 +30,14 @@ impl FixedPoints {
         stg: &AsynchronousGraph,
         restriction: Option<&ColoredVertexSet>,
     ) -> PyResult<ColoredVertexSet> {
-        let restriction = restriction
-            .map(|it| it.as_native())
-            .unwrap_or(stg.as_native().unit_colored_vertices());
-        let result = biodivine_lib_param_bn::fixed_points::FixedPoints::_symbolic(
-            stg.as_native(),
-            restriction,
-            global_log_level(py)?,
-            &|| py.check_signals(),
-        )?;
+        warn_deprecated(py, "FixedPoints.symbolic", "FixedPointsComp.symbolic")?;
+        let result = Self::component(stg, restriction)
+            .symbolic()
+            .map_err(|e| e.into_py_err(&stg.symbolic_context()))?;
         Ok(ColoredVertexSet::mk_native(stg.symbolic_context(), result))
     }
 
-    /// **Deprecated**: Use `FixedPointsComp.symbolilc_vertices()` instead.
+    /// **Deprecated**: Use `FixedPointsComp.symbolic_vertices()` instead.
     /// Iteratively compute the set of fixed-point vertices in an `AsynchronousGraph`.
     ///
     /// This is equivalent to `FixedPoints.symbolic(graph, set).vertices()`, but can be
@@ -46,15 +49,14 @@ impl FixedPoints {
         stg: &AsynchronousGraph,
         restriction: Option<&ColoredVertexSet>,
     ) -> PyResult<VertexSet> {
-        let restriction = restriction
-            .map(|it| it.as_native())
-            .unwrap_or(stg.as_native().unit_colored_vertices());
-        let result = biodivine_lib_param_bn::fixed_points::FixedPoints::_symbolic_vertices(
-            stg.as_native(),
-            restriction,
-            global_log_level(py)?,
-            &|| py.check_signals(),
+        warn_deprecated(
+            py,
+            "FixedPoints.symbolic_vertices",
+            "FixedPointsComp.symbolic_vertices",
         )?;
+        let result = Self::component(stg, restriction)
+            .symbolic_vertices()
+            .map_err(|e| e.into_py_err(&stg.symbolic_context()))?;
         Ok(VertexSet::mk_native(stg.symbolic_context(), result))
     }
 
@@ -70,15 +72,30 @@ impl FixedPoints {
         stg: &AsynchronousGraph,
         restriction: Option<&ColoredVertexSet>,
     ) -> PyResult<ColorSet> {
-        let restriction = restriction
-            .map(|it| it.as_native())
-            .unwrap_or(stg.as_native().unit_colored_vertices());
-        let result = biodivine_lib_param_bn::fixed_points::FixedPoints::_symbolic_colors(
-            stg.as_native(),
-            restriction,
-            global_log_level(py)?,
-            &|| py.check_signals(),
+        warn_deprecated(
+            py,
+            "FixedPoints.symbolic_colors",
+            "FixedPointsComp.symbolic_colors",
         )?;
+        let result = Self::component(stg, restriction)
+            .symbolic_colors()
+            .map_err(|e| e.into_py_err(&stg.symbolic_context()))?;
         Ok(ColorSet::mk_native(stg.symbolic_context(), result))
     }
 }
+
+impl FixedPoints {
+    /// Create a [FixedPointsComp] for the given `stg` and optional `restriction` that can be
+    /// interrupted from Python.
+    fn component(
+        stg: &AsynchronousGraph,
+        restriction: Option<&ColoredVertexSet>,
+    ) -> FixedPointsComp {
+        let mut config = FixedPointsConfig::from(stg.as_native().clone())
+            .with_cancellation(CancelTokenPython::default());
+        if let Some(restriction) = restriction {
+            config = config.with_restriction(restriction.as_native().clone());
+        }
+        FixedPointsComp::with_config(config)
+    }
+}
@@ -1,6 +1,17 @@
+use std::ffi::CString;
+
+use pyo3::{PyErr, PyResult, Python, exceptions::PyDeprecationWarning};
+
 pub mod attractors;
 pub mod fixed_points;
 pub mod percolation;
 pub mod reachability;
 pub mod regulation_constraint;
 pub mod trap_spaces;
+
+/// Emit a Python `DeprecationWarning` stating that the legacy algorithm method `old` is
+/// replaced by the method `new` of one of the configurable algorithm components.
+fn warn_deprecated(py: Python, old: &str, new: &str) -> PyResult<()> {
+    let message = CString::new(format!("`{old}` is deprecated, use `{new}` instead."))?;
+    PyErr::warn(py, &py.get_type::<PyDeprecationWarning>(), &message, 1)
+}
@@ -1,10 +1,19 @@
+use crate::AsNative;
+use crate::bindings::algorithms::token_python::{CancelTokenPython, IntoPyErrWithContext};
+use crate::bindings::lib_param_bn::algorithms::warn_deprecated;
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
-use crate::{AsNative, global_log_level};
+use crate::internal::algorithms::configurable::{Config, Configurable};
+use crate::internal::algorithms::reachability::{
+    Reachability as ReachabilityComp, ReachabilityConfig,
+};
 use pyo3::prelude::*;
 
 /// An "algorithm object" that facilitates reachability procedures, i.e. iterative computation
 /// of successors (or predecessors) of a particular symbolic set.
+///
+/// **Deprecated**: Use `ReachabilityComp` instead. The methods of this class are forwarded
+/// to `ReachabilityComp` with default configuration.
 #[pyclass(module = "biodivine_aeon", frozen)]
 pub struct Reachability {
     _dummy: (),
@@ -24,14 +33,18 @@ impl Reachability {
         graph: &AsynchronousGraph,
         initial: &ColoredVertexSet,
     ) -> PyResult<ColoredVertexSet> {
-        biodivine_lib_param_bn::symbolic_async_graph::reachability::Reachability::_reach_basic_saturation(
-            graph.as_native(),
-            initial.as_native(),
-            |g, s, v| g.var_post_out(v, s),
-            global_log_level(py)?,
-            &|| py.check_signals(),
-        )
-        .map(|it| ColoredVertexSet::mk_native(graph.symbolic_context(), it))
+        warn_deprecated(
+            py,
+            "Reachability.reach_fwd",
+            "ReachabilityComp.forward_closed_superset",
+        )?;
+        let result = Self::component(graph)
+            .forward_closed_superset(initial.as_native())
+            .map_err(|e| e.into_py_err(&graph.symbolic_context()))?;
+        Ok(ColoredVertexSet::mk_native(
+            graph.symbolic_context(),
+            result,
+        ))
     }
 
     /// **Deprecated**: Use `ReachabilityComp.backward_closed_superset()` instead.
@@ -46,13 +59,28 @@ impl Reachability {
         graph: &AsynchronousGraph,
         initial: &ColoredVertexSet,
     ) -> PyResult<ColoredVertexSet> {
-        biodivine_lib_param_bn::symbolic_async_graph::reachability::Reachability::_reach_basic_saturation(
-            graph.as_native(),
-            initial.as_native(),
-            |g, s, v| g.var_pre_out(v, s),
-            global_log_level(py)?,
-            &|| py.check_signals(),
+        warn_deprecated(
+            py,
+            "Reachability.reach_bwd",
+            "ReachabilityComp.backward_closed_superset",
+        )?;
+        let result = Self::component(graph)
+            .backward_closed_superset(initial.as_native())
+            .map_err(|e| e.into_py_err(&graph.symbolic_context()))?;
+        Ok(ColoredVertexSet::mk_native(
+            graph.symbolic_context(),
+            result,
+        ))
+    }
+}
+
+impl Reachability {
+    /// Create a default [ReachabilityComp] for the given `graph` that can be interrupted
+    /// from Python.
+    fn component(graph: &AsynchronousGraph) -> ReachabilityComp {
+        ReachabilityComp::with_config(
+            ReachabilityConfig::from(graph.as_native().clone())
+                .with_cancellation(CancelTokenPython::default()),
         )
-        .map(|it| ColoredVertexSet::mk_native(graph.symbolic_context(), it))
     }
 }
@@ -1,9 +1,15 @@
+use crate::bindings::algorithms::token_python::{CancelTokenPython, IntoPyErrWithContext};
+use crate::bindings::lib_param_bn::algorithms::warn_deprecated;
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
 use crate::bindings::lib_param_bn::symbolic::set_colored_space::ColoredSpaceSet;
 use crate::bindings::lib_param_bn::symbolic::symbolic_space_context::SymbolicSpaceContext;
+use crate::internal::algorithms::configurable::{Config, Configurable};
+use crate::internal::algorithms::trap_spaces::{TrapSpaces as TrapSpacesComp, TrapSpacesConfig};
 use crate::{AsNative, global_log_level};
 use pyo3::prelude::*;
 
+/// **Deprecated**: Use `TrapSpacesComp` instead. The methods of this class are forwarded
+/// to `TrapSpacesComp` with default configuration.
 #[pyclass(module = "biodivine_aeon", frozen)]
 pub struct TrapSpaces {
     _dummy: (),
@@ -24,18 +30,14 @@ impl TrapSpaces {
         graph: &AsynchronousGraph,
         restriction: Option<&ColoredSpaceSet>,
     ) -> PyResult<ColoredSpaceSet> {
-        let unit = ctx
-            .get()
-            .as_native()
-            .mk_unit_colored_spaces(graph.as_native());
-        let restriction = restriction.map(|it| it.as_native()).unwrap_or(&unit);
-        let result = biodivine_lib_param_bn::trap_spaces::TrapSpaces::_essential_symbolic(
-            ctx.get().as_native(),
-            graph.as_native(),
-            restriction,
-            global_log_level(py)?,
-            &|| py.check_signals(),
+        warn_deprecated(
+            py,
+            "TrapSpaces.essential_symbolic",
+            "TrapSpacesComp.essential_symbolic",
         )?;
+        let result = Self::component(&ctx, graph, restriction)
+            .essential_symbolic()
+            .map_err(|e| e.into_py_err(&ctx))?;
         Ok(ColoredSpaceSet::wrap_native(ctx.clone(), result))
     }
 
@@ -53,22 +55,18 @@ impl TrapSpaces {
         graph: &AsynchronousGraph,
         restriction: Option<&ColoredSpaceSet>,
     ) -> PyResult<ColoredSpaceSet> {
-        let unit = ctx
-            .get()
-            .as_native()
-            .mk_unit_colored_spaces(graph.as_native());
-        let restriction = restriction.map(|it| it.as_native()).unwrap_or(&unit);
-        let result = biodivine_lib_param_bn::trap_spaces::TrapSpaces::_minimal_symbolic(
-            ctx.get().as_native(),
-            graph.as_native(),
-            restriction,
-            global_log_level(py)?,
-            &|| py.check_signals(),
+        warn_deprecated(
+            py,
+            "TrapSpaces.minimal_symbolic",
+            "TrapSpacesComp.minimal_symbolic",
         )?;
+        let result = Self::component(&ctx, graph, restriction)
+            .minimal_symbolic()
+            .map_err(|e| e.into_py_err(&ctx))?;
         Ok(ColoredSpaceSet::wrap_native(ctx.clone(), result))
     }
 
-    /// **Deprecated**: Use `TrapSpacesComp.minimal()` instead.
+    /// **Deprecated**: Use `TrapSpacesComp.minimize()` instead.
     /// Compute the inclusion-minimal spaces within a particular subset.
     #[staticmethod]
     pub fn minimize(
@@ -76,6 +74,8 @@ impl TrapSpaces {
         ctx: Py<SymbolicSpaceContext>,
         set: &ColoredSpaceSet,
     ) -> PyResult<ColoredSpaceSet> {
+        warn_deprecated(py, "TrapSpaces.minimize", "TrapSpacesComp.minimize")?;
+        // `TrapSpacesComp` requires a graph, which is not available here.
         let result = biodivine_lib_param_bn::trap_spaces::TrapSpaces::_minimize(
             ctx.get().as_native(),
             set.as_native(),
@@ -85,7 +85,7 @@ impl TrapSpaces {
         Ok(ColoredSpaceSet::wrap_native(ctx.clone(), result))
     }
 
-    /// **Deprecated**: Use `TrapSpacesComp.maximal()` instead.
+    /// **Deprecated**: Use `TrapSpacesComp.maximize()` instead.
     /// Compute the inclusion-maximal spaces within a particular subset.
     #[staticmethod]
     pub fn maximize(
@@ -93,6 +93,8 @@ impl TrapSpaces {
         ctx: Py<SymbolicSpaceContext>,
         set: &ColoredSpaceSet,
     ) -> PyResult<ColoredSpaceSet> {
+        warn_deprecated(py, "TrapSpaces.maximize", "TrapSpacesComp.maximize")?;
+        // `TrapSpacesComp` requires a graph, which is not available here.
         let result = biodivine_lib_param_bn::trap_spaces::TrapSpaces::_maximize(
             ctx.get().as_native(),
             set.as_native(),
@@ -102,3 +104,21 @@ impl TrapSpaces {
         Ok(ColoredSpaceSet::wrap_native(ctx.clone(), result))
     }
 }
+
+impl TrapSpaces {
+    /// Create a [TrapSpacesComp] for the given `ctx`, `graph` and optional `restriction` that
+    /// can be interrupted from Python.
+    fn component(
+        ctx: &Py<SymbolicSpaceContext>,
+        graph: &AsynchronousGraph,
+        restriction: Option<&ColoredSpaceSet>,
+    ) -> TrapSpacesComp {
+        let mut config =
+            TrapSpacesConfig::from((graph.as_native().clone(), ctx.get().as_native().clone()))
+                .with_cancellation(CancelTokenPython::default());
+        if let Some(restriction) = restriction {
+            config = config.with_restriction(restriction.as_native().clone());
+        }
+        TrapSpacesComp::with_config(config)
+    }
+}
@@ -115,7 +115,7 @@ impl ColoredSpaceSet {
         self.items(None, None, py)
     }
 
-    fn __ctx__(&self) -> Py<SymbolicSpaceContext> {
+    pub fn __ctx__(&self) -> Py<SymbolicSpaceContext> {
         self.ctx.clone()
     }
 
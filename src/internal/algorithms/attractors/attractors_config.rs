@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use biodivine_lib_param_bn::{
+    BooleanNetwork, VariableId,
+    symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
+};
+use macros::Config;
+
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
+};
+
+use super::AttractorsError;
+
+/// A configuration struct for the [Attractors] algorithms.
+#[derive(Clone, Config)]
+pub struct AttractorsConfig {
+    /// The symbolic graph that will be used to compute the attractors.
+    pub graph: SymbolicAsyncGraph,
+
+    /// Restricts the result to the given set of vertices.
+    ///
+    /// Note that the attractor property is still evaluated with respect to the transitions
+    /// within this set. As such, if the set is not a trap set, the result can contain sets
+    /// that are only attractors of the sub-graph induced by the `restriction`.
+    ///
+    /// Default: `graph.unit_colored_vertices()`.
+    pub restriction: GraphColoredVertices,
+
+    /// Specifies the set of variables whose transitions are considered by the algorithms.
+    /// Remaining variables stay constant, because they are never updated.
+    ///
+    /// Default: `graph.variables()`.
+    pub active_variables: HashSet<VariableId>,
+
+    /// Specifies the variables for which transition guided reduction starts a reduction
+    /// process. Reducing fewer variables can be faster, but the result is typically larger.
+    ///
+    /// This does not affect which transitions are considered (see `active_variables`).
+    /// Variables that are not active are never reduced.
+    ///
+    /// Default: `graph.variables()`.
+    pub reduced_variables: HashSet<VariableId>,
+
+    /// A `CancellationHandler` that can be used to stop the algorithm externally.
+    ///
+    /// Default: [CancelTokenNever].
+    pub cancellation: Box<dyn CancellationHandler>,
+
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
+    /// The maximum BDD size of the intermediate sets of vertices.
+    ///
+    /// Note that the algorithm can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub bdd_size_limit: usize,
+
+    /// The maximum number of steps that the algorithm can take before terminating.
+    ///
+    /// A step is a single extension of one of the intermediate reachable sets.
+    ///
+    /// Default: `usize::MAX`.
+    pub steps_limit: usize,
+}
+
+impl From<SymbolicAsyncGraph> for AttractorsConfig {
+    /// Create a new "default" [AttractorsConfig] from the given [SymbolicAsyncGraph].
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        AttractorsConfig {
+            restriction: graph.mk_unit_colored_vertices(),
+            active_variables: HashSet::from_iter(graph.variables()),
+            reduced_variables: HashSet::from_iter(graph.variables()),
+            cancellation: Default::default(),
+            progress: Default::default(),
+            bdd_size_limit: usize::MAX,
+            steps_limit: usize::MAX,
+            graph,
+        }
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for AttractorsConfig {
+    type Error = AttractorsError;
+
+    /// Create a new "default" [AttractorsConfig] from the given [BooleanNetwork].
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        let graph =
+            SymbolicAsyncGraph::new(boolean_network).map_err(AttractorsError::CreationFailed)?;
+
+        Ok(Self::from(graph))
+    }
+}
+
+impl AttractorsConfig {
+    /// Update the `restriction` property
+    pub fn with_restriction(mut self, restriction: GraphColoredVertices) -> Self {
+        self.restriction = restriction;
+        self
+    }
+
+    /// Update the `active_variables` property.
+    pub fn with_active_variables(mut self, active_variables: HashSet<VariableId>) -> Self {
+        self.active_variables = active_variables;
+        self
+    }
+
+    /// Update the `reduced_variables` property.
+    pub fn with_reduced_variables(mut self, reduced_variables: HashSet<VariableId>) -> Self {
+        self.reduced_variables = reduced_variables;
+        self
+    }
+
+    /// Update the `bdd_size_limit` property.
+    pub fn with_bdd_size_limit(mut self, bdd_size_limit: usize) -> Self {
+        self.bdd_size_limit = bdd_size_limit;
+        self
+    }
+
+    /// Update the `steps_limit` property.
+    pub fn with_steps_limit(mut self, steps_limit: usize) -> Self {
+        self.steps_limit = steps_limit;
+        self
+    }
+}
+
+impl AttractorsConfig {
+    /// Return the active variables sorted in ascending order.
+    pub fn sorted_active_variables(&self) -> Vec<VariableId> {
+        let mut variables: Vec<_> = self.active_variables.iter().copied().collect();
+        variables.sort();
+        variables
+    }
+
+    /// Return the reduced variables that are also active, sorted in ascending order.
+    pub fn sorted_reduced_variables(&self) -> Vec<VariableId> {
+        let mut variables: Vec<_> = self
+            .reduced_variables
+            .intersection(&self.active_variables)
+            .copied()
+            .collect();
+        variables.sort();
+        variables
+    }
+}
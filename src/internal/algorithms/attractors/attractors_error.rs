@@ -0,0 +1,49 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use thiserror::Error;
+
+use crate::internal::algorithms::cancellation::CancellationError;
+
+/// An error returned by an [Attractors] procedure.
+///
+/// The partial result depends on the procedure that failed: For transition guided reduction,
+/// it is a single set that is still a valid superset of all attractors. For Xie-Beerel,
+/// it is the list of attractors discovered so far.
+#[derive(Error)]
+pub enum AttractorsError {
+    #[error("config creation failed: {0}")]
+    CreationFailed(String),
+    #[error("operation cancelled")]
+    Cancelled(Vec<GraphColoredVertices>),
+    #[error("steps limit exceeded")]
+    StepsLimitExceeded(Vec<GraphColoredVertices>),
+    #[error("BDD size limit exceeded")]
+    BddSizeLimitExceeded(Vec<GraphColoredVertices>),
+}
+
+/// The default implementation will print the whole BDD, which can be quite large.
+impl Debug for AttractorsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            AttractorsError::CreationFailed(error) => {
+                write!(f, "CreationFailed({})", error)
+            }
+            AttractorsError::Cancelled(sets) => {
+                write!(f, "Cancelled(partial_result={})", sets.len())
+            }
+            AttractorsError::StepsLimitExceeded(sets) => {
+                write!(f, "StepsLimitExceeded(partial_result={})", sets.len())
+            }
+            AttractorsError::BddSizeLimitExceeded(sets) => {
+                write!(f, "BddSizeLimitExceeded(partial_result={})", sets.len())
+            }
+        }
+    }
+}
+
+impl From<CancellationError<Vec<GraphColoredVertices>>> for AttractorsError {
+    fn from(error_value: CancellationError<Vec<GraphColoredVertices>>) -> Self {
+        AttractorsError::Cancelled(error_value.into_partial_data())
+    }
+}
@@ -0,0 +1,423 @@
+use biodivine_lib_param_bn::{
+    BooleanNetwork, VariableId,
+    biodivine_std::traits::Set,
+    symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
+};
+use log::info;
+use macros::Configurable;
+
+use crate::{
+    debug_with_limit,
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
+    is_cancelled,
+};
+
+use super::{
+    AttractorsConfig, AttractorsError, AttractorsIterator,
+    itgr::interleaved_transition_guided_reduction,
+};
+
+const TARGET_TRANSITION_GUIDED_REDUCTION: &str = "Attractors::transition_guided_reduction";
+const TARGET_ATTRACTORS: &str = "Attractors::attractors";
+const TARGET_REACH_BWD: &str = "Attractors::reach_bwd";
+
+/// Implements attractor detection over a [SymbolicAsyncGraph].
+///
+/// See [AttractorsConfig] and [AttractorsError] for more info.
+#[derive(Clone, Configurable)]
+pub struct Attractors(AttractorsConfig);
+
+impl From<SymbolicAsyncGraph> for Attractors {
+    /// Create a new [Attractors] instance with the given [SymbolicAsyncGraph]
+    /// and otherwise default configuration.
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        Attractors(AttractorsConfig::from(graph))
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for Attractors {
+    type Error = AttractorsError;
+
+    /// Create a new [Attractors] instance with the given [BooleanNetwork]
+    /// and otherwise default configuration.
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        Ok(Attractors(AttractorsConfig::try_from(boolean_network)?))
+    }
+}
+
+impl Attractors {
+    /// Compute a subset of the `restriction` set that is guaranteed to be a superset
+    /// of all the attractors within the `restriction` set.
+    ///
+    /// The exact characterisation of the retained set is a bit complicated. You shouldn't
+    /// assume that the result is a trap set or that it is forward/backward closed. Just that
+    /// any attractor that is a subset of `restriction` is still a subset of the result.
+    ///
+    /// Reduction processes are only started for the `reduced_variables`, but transitions of
+    /// all `active_variables` are considered.
+    ///
+    /// If the computation is cancelled, the partial result is still a valid superset of all
+    /// attractors, it just may not be reduced as much as possible.
+    pub fn transition_guided_reduction(&self) -> Result<GraphColoredVertices, AttractorsError> {
+        self.start_timer();
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_TRANSITION_GUIDED_REDUCTION,
+            "Started with {}[nodes:{}] candidates.",
+            restriction.approx_cardinality(),
+            restriction.symbolic_size()
+        );
+
+        let mut steps = 0usize;
+        let (reduced, _) = interleaved_transition_guided_reduction(
+            self,
+            restriction.clone(),
+            &self.config().sorted_active_variables(),
+            &self.config().sorted_reduced_variables(),
+            &mut steps,
+        )?;
+
+        info!(
+            target: TARGET_TRANSITION_GUIDED_REDUCTION,
+            "Done. Result: {}[nodes:{}] candidates.",
+            reduced.approx_cardinality(),
+            reduced.symbolic_size()
+        );
+
+        Ok(reduced)
+    }
+
+    /// Uses a simplified Xie-Beerel algorithm adapted to coloured setting to find all bottom
+    /// SCCs in the `restriction` set. It only tests transitions of the `active_variables`.
+    ///
+    /// Note that the result is a collection of sets, such that for each set and each color
+    /// holds that if the color is present in the set, the vertices of this color in the set
+    /// together form an attractor. It is not guaranteed that this "decomposition" into colored
+    /// sets is in some sense canonical (but the method should be deterministic).
+    pub fn xie_beerel(&self) -> Result<Vec<GraphColoredVertices>, AttractorsError> {
+        self.xie_beerel_iter().collect()
+    }
+
+    /// The same as [Self::xie_beerel], but the attractors are computed lazily, one at a time,
+    /// as the returned [AttractorsIterator] is consumed.
+    ///
+    /// If the computation fails, the iterator yields the error and then stops. The error
+    /// contains all attractors that were yielded before it.
+    pub fn xie_beerel_iter(&self) -> AttractorsIterator {
+        self.start_timer();
+        AttractorsIterator::new(
+            self.clone(),
+            self.config().restriction.clone(),
+            self.config().sorted_active_variables(),
+            0,
+        )
+    }
+
+    /// Compute the (colored) attractors within the `restriction` set.
+    ///
+    /// The candidate set is first pruned using [Self::transition_guided_reduction] and the
+    /// remaining states are then processed using [Self::xie_beerel]. Variables that can no
+    /// longer update after the reduction are not considered by the second phase.
+    pub fn attractors(&self) -> Result<Vec<GraphColoredVertices>, AttractorsError> {
+        let result = self.attractors_iter()?.collect::<Result<Vec<_>, _>>()?;
+
+        info!(target: TARGET_ATTRACTORS, "Done. Found {} attractor sets.", result.len());
+
+        Ok(result)
+    }
+
+    /// The same as [Self::attractors], but the attractors are computed lazily, one at a time,
+    /// as the returned [AttractorsIterator] is consumed.
+    ///
+    /// The transition guided reduction is still performed eagerly, before the iterator
+    /// is returned.
+    pub fn attractors_iter(&self) -> Result<AttractorsIterator, AttractorsError> {
+        self.start_timer();
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_ATTRACTORS,
+            "Started with {}[nodes:{}] candidates.",
+            restriction.approx_cardinality(),
+            restriction.symbolic_size()
+        );
+
+        let mut steps = 0usize;
+        let (reduced, active_variables) = interleaved_transition_guided_reduction(
+            self,
+            restriction.clone(),
+            &self.config().sorted_active_variables(),
+            &self.config().sorted_reduced_variables(),
+            &mut steps,
+        )?;
+
+        // The attractors were not found yet, so there is no partial result to return.
+        is_cancelled!(self, Vec::<GraphColoredVertices>::new)?;
+
+        Ok(AttractorsIterator::new(
+            self.clone(),
+            reduced,
+            active_variables,
+            steps,
+        ))
+    }
+}
+
+impl Attractors {
+    /// Performs one reachability step using the saturation scheme.
+    ///
+    /// The `universe` is an upper bound on what elements can be added to the `set`. Using
+    /// `variables` you can restrict the considered transitions. Finally, `step` implements
+    /// update in one variable.
+    ///
+    /// Each successful step is counted in `steps`, reported as a [ProgressEvent] of the given
+    /// `target`, and checked against the configured limits. If the operation fails, the error
+    /// contains the result of the `partial` function.
+    ///
+    /// Returns `true` if fixpoint has been reached.
+    pub(super) fn reachability_step<F, P>(
+        &self,
+        target: &str,
+        set: &mut GraphColoredVertices,
+        universe: &GraphColoredVertices,
+        variables: &[VariableId],
+        steps: &mut usize,
+        step: F,
+        partial: &P,
+    ) -> Result<bool, AttractorsError>
+    where
+        F: Fn(VariableId, &GraphColoredVertices) -> GraphColoredVertices,
+        P: Fn() -> Vec<GraphColoredVertices>,
+    {
+        if variables.is_empty() {
+            return Ok(true);
+        }
+
+        for var in variables.iter().rev() {
+            is_cancelled!(self, partial)?;
+
+            let stepped = step(*var, set).minus(set).intersect(universe);
+
+            if !stepped.is_empty() {
+                *set = set.union(&stepped);
+                *steps += 1;
+
+                self.report(&ProgressEvent {
+                    target,
+                    step: *steps,
+                    bdd_size: set.symbolic_size(),
+                    candidates: set.approx_cardinality(),
+                });
+
+                if set.as_bdd().size() > self.config().bdd_size_limit {
+                    return Err(AttractorsError::BddSizeLimitExceeded(partial()));
+                }
+
+                if *steps > self.config().steps_limit {
+                    return Err(AttractorsError::StepsLimitExceeded(partial()));
+                }
+
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Fully compute back-reachable states from `initial` inside `universe` using transitions
+    /// under `variables`.
+    ///
+    /// See [Self::reachability_step] for the meaning of `steps` and `partial`.
+    pub(super) fn reach_bwd<P>(
+        &self,
+        initial: &GraphColoredVertices,
+        universe: &GraphColoredVertices,
+        variables: &[VariableId],
+        steps: &mut usize,
+        partial: &P,
+    ) -> Result<GraphColoredVertices, AttractorsError>
+    where
+        P: Fn() -> Vec<GraphColoredVertices>,
+    {
+        let graph = &self.config().graph;
+        let mut set = initial.clone();
+        while !self.reachability_step(
+            TARGET_REACH_BWD,
+            &mut set,
+            universe,
+            variables,
+            steps,
+            |var, set| graph.var_pre(var, set),
+            partial,
+        )? {
+            debug_with_limit!(
+                target: TARGET_REACH_BWD,
+                size: set.symbolic_size(),
+                " >> Backward reachability progress: {}[nodes:{}] candidates.",
+                set.approx_cardinality(),
+                set.symbolic_size()
+            );
+        }
+
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+
+    use crate::internal::algorithms::attractors::{Attractors, AttractorsConfig};
+    use crate::internal::algorithms::cancellation::CancelTokenNever;
+    use crate::internal::algorithms::configurable::{Config, Configurable};
+    use crate::internal::algorithms::progress::{ProgressEvent, ProgressReporter};
+    use crate::internal::scc::algo_interleaved_transition_guided_reduction::interleaved_transition_guided_reduction;
+    use crate::internal::scc::algo_xie_beerel::xie_beerel_attractors;
+
+    /// A [ProgressReporter] that records the target and step of every event.
+    #[derive(Clone, Default)]
+    struct ProgressRecorder(Arc<Mutex<Vec<(String, usize)>>>);
+
+    impl ProgressReporter for ProgressRecorder {
+        fn report(&self, event: &ProgressEvent) {
+            let mut events = self.0.lock().unwrap();
+            events.push((event.target.to_string(), event.step));
+        }
+    }
+
+    fn mk_graph(model: &str) -> (BooleanNetwork, SymbolicAsyncGraph) {
+        let bn = BooleanNetwork::try_from(model).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        (bn, graph)
+    }
+
+    fn union(graph: &SymbolicAsyncGraph, sets: &[GraphColoredVertices]) -> GraphColoredVertices {
+        sets.iter()
+            .fold(graph.mk_empty_colored_vertices(), |a, b| a.union(b))
+    }
+
+    #[test]
+    fn test_attractors_toggle_switch() {
+        let (_, graph) = mk_graph("a -| b\nb -| a\n$a: !b\n$b: !a\n");
+        let attractors = Attractors::from(graph.clone()).attractors().unwrap();
+        assert_eq!(attractors.len(), 2);
+        assert!(attractors.iter().all(|it| it.approx_cardinality() == 1.0));
+
+        let xie_beerel = Attractors::from(graph.clone()).xie_beerel().unwrap();
+        assert_eq!(union(&graph, &attractors), union(&graph, &xie_beerel));
+    }
+
+    #[test]
+    fn test_attractors_reduced_variables() {
+        // `b` oscillates and `a` follows `b`, hence all four states form one attractor.
+        let (bn, graph) = mk_graph("b -> a\nb -| b\n$a: b\n$b: !b\n");
+        let a = bn.as_graph().find_variable("a").unwrap();
+        let b = bn.as_graph().find_variable("b").unwrap();
+
+        for reduced in [
+            HashSet::from([a]),
+            HashSet::from([b]),
+            HashSet::from([a, b]),
+        ] {
+            let config = AttractorsConfig::from(graph.clone()).with_reduced_variables(reduced);
+            let attractors = Attractors::with_config(config);
+            let reduced = attractors.transition_guided_reduction().unwrap();
+            assert_eq!(&reduced, graph.unit_colored_vertices());
+            let result = attractors.attractors().unwrap();
+            assert_eq!(result.len(), 1);
+            assert_eq!(&result[0], graph.unit_colored_vertices());
+        }
+    }
+
+    #[test]
+    fn test_attractors_active_variables() {
+        // Without transitions of `a`, its value is constant and each half of the state space
+        // becomes a separate attractor.
+        let (bn, graph) = mk_graph("b -> a\nb -| b\n$a: b\n$b: !b\n");
+        let b = bn.as_graph().find_variable("b").unwrap();
+        let config =
+            AttractorsConfig::from(graph.clone()).with_active_variables(HashSet::from([b]));
+        let result = Attractors::with_config(config).attractors().unwrap();
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|it| it.approx_cardinality() == 2.0));
+        assert_eq!(&union(&graph, &result), graph.unit_colored_vertices());
+    }
+
+    #[test]
+    fn test_attractors_match_legacy() {
+        for path in ["tests/model-2.aeon", "tests/model-myeloid-3-unknown.aeon"] {
+            let bn = BooleanNetwork::try_from_file(path).unwrap();
+            let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+            let unit = graph.mk_unit_colored_vertices();
+            let variables = graph.variables().collect::<Vec<_>>();
+
+            let (legacy_tgr, _) = interleaved_transition_guided_reduction(
+                &graph,
+                unit.clone(),
+                &variables,
+                &CancelTokenNever,
+                0,
+            )
+            .unwrap();
+            let legacy =
+                xie_beerel_attractors(&graph, &legacy_tgr, &variables, &CancelTokenNever, 0)
+                    .unwrap();
+            let legacy_xb =
+                xie_beerel_attractors(&graph, &unit, &variables, &CancelTokenNever, 0).unwrap();
+
+            let attractors = Attractors::from(graph.clone());
+            let tgr = attractors.transition_guided_reduction().unwrap();
+            let result = attractors.attractors().unwrap();
+            let xie_beerel = attractors.xie_beerel().unwrap();
+
+            assert_eq!(union(&graph, &result), union(&graph, &legacy));
+            assert_eq!(union(&graph, &xie_beerel), union(&graph, &legacy_xb));
+            assert_eq!(union(&graph, &result), union(&graph, &xie_beerel));
+            assert!(union(&graph, &result).is_subset(&tgr));
+            assert!(union(&graph, &legacy).is_subset(&legacy_tgr));
+        }
+    }
+
+    #[test]
+    fn test_reduced_variables_match_legacy() {
+        let (bn, graph) = mk_graph("b -> a\nb -| b\n$a: b\n$b: !b\n");
+        let a = bn.as_graph().find_variable("a").unwrap();
+        let unit = graph.mk_unit_colored_vertices();
+
+        let (legacy, _) =
+            interleaved_transition_guided_reduction(&graph, unit, &[a], &CancelTokenNever, 0)
+                .unwrap();
+
+        let config =
+            AttractorsConfig::from(graph.clone()).with_reduced_variables(HashSet::from([a]));
+        let reduced = Attractors::with_config(config)
+            .transition_guided_reduction()
+            .unwrap();
+        assert_eq!(reduced, legacy);
+    }
+
+    #[test]
+    fn test_attractors_progress() {
+        let (_, graph) = mk_graph("b -> a\nb -| b\n$a: b\n$b: !b\n");
+        let recorder = ProgressRecorder::default();
+        let config = AttractorsConfig::from(graph.clone()).with_progress(recorder.clone());
+        Attractors::with_config(config).xie_beerel().unwrap();
+
+        // Every reachability step is reported, and the steps never decrease.
+        let events = recorder.0.lock().unwrap();
+        for target in ["Attractors::xie_beerel", "Attractors::reach_bwd"] {
+            assert!(events.iter().any(|(it, step)| it == target && *step > 0));
+        }
+        assert!(events.windows(2).all(|it| it[0].1 <= it[1].1));
+    }
+}
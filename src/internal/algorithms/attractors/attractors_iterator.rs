@@ -0,0 +1,180 @@
+use biodivine_lib_param_bn::{
+    VariableId, biodivine_std::traits::Set, symbolic_async_graph::GraphColoredVertices,
+};
+use log::{debug, info};
+
+use crate::{
+    debug_with_limit,
+    internal::algorithms::{
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
+    is_cancelled,
+};
+
+use super::{Attractors, AttractorsError};
+
+const TARGET_XIE_BEEREL: &str = "Attractors::xie_beerel";
+
+/// An iterator that lazily computes attractors using the Xie-Beerel algorithm.
+///
+/// Each item is either a new attractor set, or an error. After an error is returned,
+/// the iterator is exhausted. The partial result of the error contains all attractor sets
+/// that were returned by the iterator so far.
+///
+/// See [Attractors::xie_beerel_iter] and [Attractors::attractors_iter].
+pub struct AttractorsIterator {
+    attractors: Attractors,
+    universe: GraphColoredVertices,
+    variables: Vec<VariableId>,
+    steps: usize,
+    found: Vec<GraphColoredVertices>,
+    done: bool,
+}
+
+impl AttractorsIterator {
+    /// Create a new iterator that searches for attractors in the given `universe`, only
+    /// considering transitions of the given `variables`. The `steps` are the number of steps
+    /// already performed by the preceding phases of the computation.
+    pub(super) fn new(
+        attractors: Attractors,
+        universe: GraphColoredVertices,
+        variables: Vec<VariableId>,
+        steps: usize,
+    ) -> AttractorsIterator {
+        info!(
+            target: TARGET_XIE_BEEREL,
+            "Started with {}[nodes:{}] candidates.",
+            universe.approx_cardinality(),
+            universe.symbolic_size()
+        );
+
+        AttractorsIterator {
+            attractors,
+            universe,
+            variables,
+            steps,
+            found: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// The attractor sets that were returned by this iterator so far.
+    pub fn found(&self) -> &[GraphColoredVertices] {
+        &self.found
+    }
+
+    /// Search for the next attractor set. Returns `None` once all candidate states
+    /// are exhausted.
+    fn next_attractor(&mut self) -> Result<Option<GraphColoredVertices>, AttractorsError> {
+        let graph = &self.attractors.config().graph;
+
+        while !self.universe.is_empty() {
+            is_cancelled!(&self.attractors, || self.found.clone())?;
+
+            self.attractors.report(&ProgressEvent {
+                target: TARGET_XIE_BEEREL,
+                step: self.steps,
+                bdd_size: self.universe.symbolic_size(),
+                candidates: self.universe.approx_cardinality(),
+            });
+
+            debug_with_limit!(
+                target: TARGET_XIE_BEEREL,
+                size: self.universe.symbolic_size(),
+                " > Start new bottom SCC search. Remaining: {}[nodes:{}].",
+                self.universe.approx_cardinality(),
+                self.universe.symbolic_size()
+            );
+
+            let pivots = self.universe.pick_vertex();
+
+            let pivot_basin = self.attractors.reach_bwd(
+                &pivots,
+                &self.universe,
+                &self.variables,
+                &mut self.steps,
+                &|| self.found.clone(),
+            )?;
+
+            let mut pivot_component = pivots.clone();
+
+            // Iteratively compute the pivot component. If some color leaves `pivot_basin`, it is
+            // removed from `pivot_component`, as it does not have to be processed anymore.
+            //
+            // At the end of the loop, `pivot_component` contains only colors for which the
+            // component is an attractor (other colors will leave the `pivot_basin` at some point).
+            loop {
+                let done = self.attractors.reachability_step(
+                    TARGET_XIE_BEEREL,
+                    &mut pivot_component,
+                    &self.universe,
+                    &self.variables,
+                    &mut self.steps,
+                    |var, set| graph.var_post(var, set),
+                    &|| self.found.clone(),
+                )?;
+
+                debug_with_limit!(
+                    target: TARGET_XIE_BEEREL,
+                    size: pivot_component.symbolic_size(),
+                    " >> Forward reachability progress: {}[nodes:{}] candidates.",
+                    pivot_component.approx_cardinality(),
+                    pivot_component.symbolic_size()
+                );
+
+                // This ensures `pivot_component` is still a subset of `pivot_basin` even if we
+                // do not enforce it explicitly in `reachability_step`, since anything that leaks
+                // out is eliminated.
+                let escaped_basin = pivot_component.minus(&pivot_basin);
+                if !escaped_basin.is_empty() {
+                    pivot_component = pivot_component.minus_colors(&escaped_basin.colors());
+                }
+
+                if done {
+                    break;
+                }
+            }
+
+            self.universe = self.universe.minus(&pivot_basin);
+
+            if !pivot_component.is_empty() {
+                debug!(
+                    target: TARGET_XIE_BEEREL,
+                    " > Found a bottom SCC: {}x{}[nodes:{}].",
+                    pivot_component.vertices().approx_cardinality(),
+                    pivot_component.colors().approx_cardinality(),
+                    pivot_component.symbolic_size(),
+                );
+                self.found.push(pivot_component.clone());
+                return Ok(Some(pivot_component));
+            }
+        }
+
+        info!(target: TARGET_XIE_BEEREL, "Done. Found {} attractor sets.", self.found.len());
+
+        Ok(None)
+    }
+}
+
+impl Iterator for AttractorsIterator {
+    type Item = Result<GraphColoredVertices, AttractorsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_attractor() {
+            Ok(Some(attractor)) => Some(Ok(attractor)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+
+use crate::debug_with_limit;
+use crate::internal::algorithms::attractors::itgr::{
+    BwdProcess, FwdProcess, Process, Scheduler, TARGET_ITGR,
+};
+use crate::internal::algorithms::attractors::{Attractors, AttractorsError};
+use crate::internal::algorithms::configurable::Configurable;
+
+impl BwdProcess {
+    pub fn new(initial: GraphColoredVertices, universe: GraphColoredVertices) -> BwdProcess {
+        BwdProcess {
+            universe,
+            bwd: initial,
+        }
+    }
+
+    pub fn get_reachable_set(&self) -> &GraphColoredVertices {
+        &self.bwd
+    }
+}
+
+impl FwdProcess {
+    pub fn new(initial: GraphColoredVertices, universe: GraphColoredVertices) -> FwdProcess {
+        FwdProcess {
+            universe,
+            fwd: initial,
+        }
+    }
+
+    pub fn get_reachable_set(&self) -> &GraphColoredVertices {
+        &self.fwd
+    }
+}
+
+impl Process for BwdProcess {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        attractors: &Attractors,
+    ) -> Result<bool, AttractorsError> {
+        let graph = &attractors.config().graph;
+        let result = attractors.reachability_step(
+            TARGET_ITGR,
+            &mut self.bwd,
+            &self.universe,
+            &scheduler.active_variables,
+            &mut scheduler.steps,
+            |var, set| graph.var_pre(var, set),
+            &|| vec![scheduler.universe.clone()],
+        );
+
+        let problem_size = self.bwd.symbolic_size();
+        let current = self.bwd.approx_cardinality();
+        let max = scheduler.universe.approx_cardinality();
+        debug_with_limit!(
+            target: TARGET_ITGR,
+            size: problem_size,
+            " >> [BWD process] Reachability progress: {}[nodes:{}] candidates ({:.2} log-%).",
+            current,
+            problem_size,
+            (current.log2() / max.log2()) * 100.0
+        );
+
+        result
+    }
+
+    fn weight(&self) -> usize {
+        self.bwd.symbolic_size()
+    }
+
+    fn discard_states(&mut self, set: &GraphColoredVertices) {
+        self.universe = self.universe.minus(set);
+        self.bwd = self.bwd.minus(set);
+    }
+}
+
+impl Process for FwdProcess {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        attractors: &Attractors,
+    ) -> Result<bool, AttractorsError> {
+        let graph = &attractors.config().graph;
+        let result = attractors.reachability_step(
+            TARGET_ITGR,
+            &mut self.fwd,
+            &self.universe,
+            &scheduler.active_variables,
+            &mut scheduler.steps,
+            |var, set| graph.var_post(var, set),
+            &|| vec![scheduler.universe.clone()],
+        );
+
+        let problem_size = self.fwd.symbolic_size();
+        let current = self.fwd.approx_cardinality();
+        let max = scheduler.universe.approx_cardinality();
+        debug_with_limit!(
+            target: TARGET_ITGR,
+            size: problem_size,
+            " >> [FWD process] Reachability progress: {}[nodes:{}] candidates ({:.2} log-%).",
+            current,
+            problem_size,
+            (current.log2() / max.log2()) * 100.0
+        );
+
+        result
+    }
+
+    fn weight(&self) -> usize {
+        self.fwd.symbolic_size()
+    }
+
+    fn discard_states(&mut self, set: &GraphColoredVertices) {
+        self.universe = self.universe.minus(set);
+        self.fwd = self.fwd.minus(set);
+    }
+}
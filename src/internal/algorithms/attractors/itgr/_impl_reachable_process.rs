@@ -0,0 +1,94 @@
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use log::debug;
+
+use crate::debug_with_limit;
+use crate::internal::algorithms::attractors::itgr::{
+    ExtendedComponentProcess, FwdProcess, Process, ReachableProcess, Scheduler, TARGET_ITGR,
+};
+use crate::internal::algorithms::attractors::{Attractors, AttractorsError};
+use crate::internal::algorithms::configurable::Configurable;
+
+impl ReachableProcess {
+    pub fn new(
+        var: VariableId,
+        graph: &SymbolicAsyncGraph,
+        universe: GraphColoredVertices,
+    ) -> ReachableProcess {
+        let var_can_post = graph.var_can_post(var, &universe);
+        ReachableProcess {
+            variable: var,
+            fwd: FwdProcess::new(var_can_post, universe),
+        }
+    }
+}
+
+impl Process for ReachableProcess {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        attractors: &Attractors,
+    ) -> Result<bool, AttractorsError> {
+        if self.fwd.step(scheduler, attractors)? {
+            let graph = &attractors.config().graph;
+            let fwd_set = self.fwd.get_reachable_set();
+
+            // If fwd set is not the whole universe, it probably has a basin.
+            if fwd_set != scheduler.get_universe() {
+                debug_with_limit!(
+                    target: TARGET_ITGR,
+                    size: fwd_set.symbolic_size(),
+                    " > Completed forward-reachability for {} transitions. Start pruning the basin...",
+                    self.variable
+                );
+
+                let basin_only = attractors
+                    .reach_bwd(
+                        fwd_set,
+                        &scheduler.universe,
+                        &scheduler.active_variables,
+                        &mut scheduler.steps,
+                        &|| vec![scheduler.universe.clone()],
+                    )?
+                    .minus(fwd_set);
+
+                debug!(
+                    target: TARGET_ITGR,
+                    " > Discarded {} instances using the {} transition basin.",
+                    basin_only.approx_cardinality(),
+                    self.variable
+                );
+
+                if !basin_only.is_empty() {
+                    scheduler.discard_vertices(&basin_only);
+                }
+            } else {
+                debug_with_limit!(
+                    target: TARGET_ITGR,
+                    size: fwd_set.symbolic_size(),
+                    " > Completed forward-reachability for {} transitions. Basin is empty.",
+                    self.variable
+                );
+            }
+
+            scheduler.spawn(ExtendedComponentProcess::new(
+                self.variable,
+                fwd_set.clone(),
+                scheduler.get_universe().clone(),
+                graph,
+            ));
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn weight(&self) -> usize {
+        self.fwd.weight()
+    }
+
+    fn discard_states(&mut self, set: &GraphColoredVertices) {
+        self.fwd.discard_states(set)
+    }
+}
@@ -0,0 +1,125 @@
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+
+use crate::debug_with_limit;
+use crate::internal::algorithms::attractors::itgr::{Process, Scheduler, TARGET_ITGR};
+use crate::internal::algorithms::attractors::{Attractors, AttractorsError};
+use crate::internal::algorithms::progress::{ProgressEvent, ProgressReporter};
+
+impl Scheduler {
+    /// Create a new `Scheduler` with an initial universe, active variables and the number
+    /// of already performed steps.
+    pub fn new(
+        initial: GraphColoredVertices,
+        variables: Vec<VariableId>,
+        steps: usize,
+    ) -> Scheduler {
+        Scheduler {
+            active_variables: variables,
+            universe: initial,
+            processes: Vec::new(),
+            to_discard: None,
+            steps,
+        }
+    }
+
+    /// Finalize this scheduler, returning the current universe and active variables.
+    pub fn finalize(self) -> (GraphColoredVertices, Vec<VariableId>) {
+        (self.universe, self.active_variables)
+    }
+
+    /// Remove given `var` from the list of active variables.
+    pub fn discard_variable(&mut self, var: VariableId) {
+        self.active_variables
+            .iter()
+            .position(|v| *v == var)
+            .into_iter()
+            .for_each(|index| {
+                self.active_variables.remove(index);
+            });
+    }
+
+    /// Remove given `set` from the universe of this scheduler.
+    pub fn discard_vertices(&mut self, set: &GraphColoredVertices) {
+        self.universe = self.universe.minus(set);
+        if let Some(to_discard) = self.to_discard.as_mut() {
+            *to_discard = to_discard.union(set);
+        } else {
+            self.to_discard = Some(set.clone());
+        }
+    }
+
+    /// Add a new process into this scheduler.
+    pub fn spawn<P: 'static + Process>(&mut self, process: P) {
+        self.processes.push((process.weight(), Box::new(process)));
+    }
+
+    /// Get the current universe set of the scheduler.
+    pub fn get_universe(&self) -> &GraphColoredVertices {
+        &self.universe
+    }
+
+    /// Get the number of reachability steps performed so far.
+    pub fn get_steps(&self) -> usize {
+        self.steps
+    }
+
+    /// True if all processes are finished.
+    pub fn is_done(&self) -> bool {
+        self.processes.is_empty()
+    }
+
+    /// If possible, perform one computational step for one of the processes.
+    pub fn step(&mut self, attractors: &Attractors) -> Result<(), AttractorsError> {
+        if self.is_done() {
+            return Ok(());
+        }
+
+        // First, apply to_discard in all processes:
+        if let Some(to_discard) = self.to_discard.as_ref() {
+            for (w, process) in self.processes.iter_mut() {
+                process.discard_states(to_discard);
+                *w = process.weight();
+            }
+            self.to_discard = None;
+
+            attractors.report(&ProgressEvent {
+                target: TARGET_ITGR,
+                step: self.steps,
+                bdd_size: self.universe.symbolic_size(),
+                candidates: self.universe.approx_cardinality(),
+            });
+
+            debug_with_limit!(
+                target: TARGET_ITGR,
+                size: self.universe.symbolic_size(),
+                " > State space reduced to {}[nodes:{}].",
+                self.universe.approx_cardinality(),
+                self.universe.symbolic_size(),
+            );
+        }
+
+        // Second, put the best process in the last place
+        self.processes.sort_by_key(|(w, _)| usize::MAX - (*w));
+
+        let total_weight = self.processes.iter().map(|it| it.1.weight()).sum::<usize>();
+
+        // Perform one step in a process
+        if let Some((_, mut process)) = self.processes.pop() {
+            let is_done = process.step(self, attractors)?;
+            if !is_done {
+                self.processes.push((process.weight(), process));
+            } else {
+                debug_with_limit!(
+                    target: TARGET_ITGR,
+                    size: total_weight,
+                    " > Finished ITGR process. {} processes remaining.",
+                    self.processes.len()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+//! Interleaved transition guided reduction algorithm.
+//!
+//! Implements interleaved transition guided reduction. This technique does not remove
+//! all non-attractor states, but can very significantly prune the state space in
+//! a very reasonable amount of time.
+//!
+
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use log::debug;
+
+use crate::{internal::algorithms::configurable::Configurable, is_cancelled};
+
+use super::{Attractors, AttractorsError};
+
+mod _impl_extended_component_process;
+mod _impl_fwd_bwd_process;
+mod _impl_reachable_process;
+mod _impl_scheduler;
+
+const TARGET_ITGR: &str = "Attractors::interleaved_transition_guided_reduction";
+
+/// Removes from `initial` as many non-attractor states as possible
+/// using interleaved transition guided reduction.
+///
+/// Only transitions of the `active_variables` are considered, and reduction processes are
+/// only started for the `to_reduce` variables. The function also returns a list of those
+/// active variables for which there are still transitions in the graph (other variables
+/// are effectively constant).
+///
+/// If cancelled, the partial result is still valid, but not necessarily complete.
+pub(super) fn interleaved_transition_guided_reduction(
+    attractors: &Attractors,
+    initial: GraphColoredVertices,
+    active_variables: &[VariableId],
+    to_reduce: &[VariableId],
+    steps: &mut usize,
+) -> Result<(GraphColoredVertices, Vec<VariableId>), AttractorsError> {
+    let graph = &attractors.config().graph;
+
+    debug!(
+        target: TARGET_ITGR,
+        "Start interleaved transition guided reduction with {}[nodes:{}] candidates.",
+        initial.approx_cardinality(),
+        initial.symbolic_size()
+    );
+
+    let mut scheduler = Scheduler::new(initial, active_variables.to_vec(), *steps);
+    for variable in to_reduce {
+        is_cancelled!(attractors, || vec![scheduler.get_universe().clone()])?;
+        scheduler.spawn(ReachableProcess::new(
+            *variable,
+            graph,
+            scheduler.get_universe().clone(),
+        ));
+    }
+
+    while !scheduler.is_done() {
+        is_cancelled!(attractors, || vec![scheduler.get_universe().clone()])?;
+        scheduler.step(attractors)?;
+    }
+
+    *steps = scheduler.get_steps();
+    let result = scheduler.finalize();
+
+    debug!(
+        target: TARGET_ITGR,
+        "Interleaved transition guided reduction finished with {}[nodes:{}] candidates.",
+        result.0.approx_cardinality(),
+        result.0.symbolic_size()
+    );
+
+    Ok(result)
+}
+
+/// **(internal)** A process trait is a unit of work that is managed by a `Scheduler`.
+/// The Process has a *weight* that approximates how symbolically hard is to work with
+/// its intermediate representation.
+trait Process {
+    /// Perform one step in the process. This can perform multiple symbolic operations,
+    /// but should be fairly simple (i.e., does not need interrupting).
+    ///
+    /// If you still need to run a complex operation, you should check the cancellation
+    /// handler of the provided `Attractors` instance.
+    ///
+    /// Returns true if the process cannot perform more steps.
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        attractors: &Attractors,
+    ) -> Result<bool, AttractorsError>;
+
+    /// Approximate symbolic complexity of the process.
+    fn weight(&self) -> usize;
+
+    /// Mark the given set of states as eliminated - i.e., they can be disregarded by this process.
+    fn discard_states(&mut self, set: &GraphColoredVertices);
+}
+
+/// **(internal)** Scheduler manages work divided into `Processes`. It keeps a `universe`
+/// of unprocessed vertices, a list of remaining active variables, and the number of
+/// reachability steps performed so far.
+struct Scheduler {
+    active_variables: Vec<VariableId>,
+    universe: GraphColoredVertices,
+    processes: Vec<(usize, Box<dyn Process>)>,
+    to_discard: Option<GraphColoredVertices>,
+    steps: usize,
+}
+
+/// **(internal)** Basic backward reachability process.
+struct BwdProcess {
+    bwd: GraphColoredVertices,
+    universe: GraphColoredVertices,
+}
+
+/// **(internal)** Basic forward reachability process.
+struct FwdProcess {
+    fwd: GraphColoredVertices,
+    universe: GraphColoredVertices,
+}
+
+/// **(internal)** Computes the set of vertices reachable from states that can perform `var_post`.
+///
+/// When a reachable set is computed, it automatically starts the extended component process.
+struct ReachableProcess {
+    variable: VariableId,
+    fwd: FwdProcess,
+}
+
+/// **(internal)** Computes the extended component of a forward-reachable set.
+struct ExtendedComponentProcess {
+    variable: VariableId,
+    fwd_set: GraphColoredVertices,
+    bwd: BwdProcess,
+}
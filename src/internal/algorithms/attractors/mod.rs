@@ -0,0 +1,10 @@
+mod attractors_config;
+mod attractors_error;
+mod attractors_impl;
+mod attractors_iterator;
+mod itgr;
+
+pub use attractors_config::AttractorsConfig;
+pub use attractors_error::AttractorsError;
+pub use attractors_impl::Attractors;
+pub use attractors_iterator::AttractorsIterator;
@@ -0,0 +1,30 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+
+/// The basins of a single attractor, as computed by [Basins::basins].
+#[derive(Clone)]
+pub struct Basin {
+    /// All states that can reach the attractor (including the attractor itself).
+    pub weak: GraphColoredVertices,
+
+    /// All states that always reach the attractor, i.e. the greatest forward-closed subset
+    /// of the `weak` basin.
+    pub strong: GraphColoredVertices,
+
+    /// The states of the `weak` basin that can also reach at least one other attractor.
+    pub shared: GraphColoredVertices,
+}
+
+/// The default implementation will print the whole BDD, which can be quite large.
+impl Debug for Basin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Basin(weak={}, strong={}, shared={})",
+            self.weak.approx_cardinality(),
+            self.strong.approx_cardinality(),
+            self.shared.approx_cardinality()
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use biodivine_lib_param_bn::{BooleanNetwork, symbolic_async_graph::SymbolicAsyncGraph};
+use macros::Config;
+
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
+    reachability::ReachabilityConfig,
+};
+
+use super::BasinsError;
+
+/// A configuration struct for the [Basins] algorithms.
+#[derive(Clone, Config)]
+pub struct BasinsConfig {
+    /// The symbolic graph that will be used to compute the basins.
+    pub graph: SymbolicAsyncGraph,
+
+    /// A `CancellationHandler` that can be used to stop the algorithm externally.
+    ///
+    /// Default: [CancelTokenNever].
+    pub cancellation: Box<dyn CancellationHandler>,
+
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
+    /// The maximum BDD size of the intermediate reachable sets.
+    ///
+    /// Note that the algorithm can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub bdd_size_limit: usize,
+
+    /// The maximum number of steps that the computation of a single weak or strong basin
+    /// can take before terminating. The limit applies to every basin separately, i.e. the
+    /// whole computation can perform more steps in total.
+    ///
+    /// Default: `usize::MAX`.
+    pub steps_limit: usize,
+}
+
+impl From<SymbolicAsyncGraph> for BasinsConfig {
+    /// Create a new "default" [BasinsConfig] from the given [SymbolicAsyncGraph].
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        BasinsConfig {
+            cancellation: Default::default(),
+            progress: Default::default(),
+            bdd_size_limit: usize::MAX,
+            steps_limit: usize::MAX,
+            graph,
+        }
+    }
+}
+
+impl From<ReachabilityConfig> for BasinsConfig {
+    /// Create a new [BasinsConfig] that shares the `graph`, limits, cancellation
+    /// and progress reporting of the given [ReachabilityConfig].
+    fn from(config: ReachabilityConfig) -> Self {
+        BasinsConfig {
+            cancellation: config.cancellation,
+            progress: config.progress,
+            bdd_size_limit: config.bdd_size_limit,
+            steps_limit: config.steps_limit,
+            graph: config.graph,
+        }
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for BasinsConfig {
+    type Error = BasinsError;
+
+    /// Create a new "default" [BasinsConfig] from the given [BooleanNetwork].
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        let graph =
+            SymbolicAsyncGraph::new(boolean_network).map_err(BasinsError::CreationFailed)?;
+
+        Ok(Self::from(graph))
+    }
+}
+
+impl BasinsConfig {
+    /// Update the `bdd_size_limit` property.
+    pub fn with_bdd_size_limit(mut self, bdd_size_limit: usize) -> Self {
+        self.bdd_size_limit = bdd_size_limit;
+        self
+    }
+
+    /// Update the `steps_limit` property.
+    pub fn with_steps_limit(mut self, steps_limit: usize) -> Self {
+        self.steps_limit = steps_limit;
+        self
+    }
+}
@@ -0,0 +1,64 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use thiserror::Error;
+
+use crate::internal::algorithms::{
+    cancellation::CancellationError, reachability::FromReachabilityError,
+};
+
+use super::Basin;
+
+/// An error returned by a [Basins] procedure.
+///
+/// The partial result is the list of basins that were fully computed so far.
+#[derive(Error)]
+pub enum BasinsError {
+    #[error("config creation failed: {0}")]
+    CreationFailed(String),
+    #[error("operation cancelled")]
+    Cancelled(Vec<Basin>),
+    #[error("steps limit exceeded")]
+    StepsLimitExceeded(Vec<Basin>),
+    #[error("BDD size limit exceeded")]
+    BddSizeLimitExceeded(Vec<Basin>),
+}
+
+/// The default implementation will print the whole BDD, which can be quite large.
+impl Debug for BasinsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            BasinsError::CreationFailed(error) => {
+                write!(f, "CreationFailed({})", error)
+            }
+            BasinsError::Cancelled(basins) => {
+                write!(f, "Cancelled(partial_result={})", basins.len())
+            }
+            BasinsError::StepsLimitExceeded(basins) => {
+                write!(f, "StepsLimitExceeded(partial_result={})", basins.len())
+            }
+            BasinsError::BddSizeLimitExceeded(basins) => {
+                write!(f, "BddSizeLimitExceeded(partial_result={})", basins.len())
+            }
+        }
+    }
+}
+
+impl From<CancellationError<Vec<Basin>>> for BasinsError {
+    fn from(error_value: CancellationError<Vec<Basin>>) -> Self {
+        BasinsError::Cancelled(error_value.into_partial_data())
+    }
+}
+
+impl FromReachabilityError<Vec<Basin>> for BasinsError {
+    fn creation_failed(error: String) -> Self {
+        BasinsError::CreationFailed(error)
+    }
+
+    fn steps_limit_exceeded(partial: Vec<Basin>) -> Self {
+        BasinsError::StepsLimitExceeded(partial)
+    }
+
+    fn bdd_size_limit_exceeded(partial: Vec<Basin>) -> Self {
+        BasinsError::BddSizeLimitExceeded(partial)
+    }
+}
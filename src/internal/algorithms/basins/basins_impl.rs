@@ -0,0 +1,198 @@
+use biodivine_lib_param_bn::{
+    BooleanNetwork,
+    biodivine_std::traits::Set,
+    symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
+};
+use log::info;
+use macros::Configurable;
+
+use crate::{
+    debug_with_limit,
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::{Config as _, Configurable},
+        reachability::{FromReachabilityError, Reachability, ReachabilityConfig},
+    },
+    is_cancelled,
+};
+
+use super::{Basin, BasinsConfig, BasinsError};
+
+const TARGET_BASINS: &str = "Basins::basins";
+
+/// Implements the computation of attractor basins over a [SymbolicAsyncGraph].
+///
+/// See [BasinsConfig] and [BasinsError] for more info.
+#[derive(Clone, Configurable)]
+pub struct Basins(BasinsConfig);
+
+impl From<SymbolicAsyncGraph> for Basins {
+    /// Create a new [Basins] instance with the given [SymbolicAsyncGraph]
+    /// and otherwise default configuration.
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        Basins(BasinsConfig::from(graph))
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for Basins {
+    type Error = BasinsError;
+
+    /// Create a new [Basins] instance with the given [BooleanNetwork]
+    /// and otherwise default configuration.
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        Ok(Basins(BasinsConfig::try_from(boolean_network)?))
+    }
+}
+
+impl Basins {
+    /// Compute the [Basin] of each of the given `attractors`.
+    ///
+    /// The weak basin contains all states that can reach the attractor, the strong basin
+    /// contains the states that always reach it (the greatest forward-closed subset of
+    /// the weak basin). Finally, the shared states are the states of the weak basin that
+    /// can also reach one of the other `attractors`.
+    ///
+    /// Note that the strong basin does not depend on the other `attractors`, but the
+    /// shared states do. Hence, to obtain meaningful shared states, `attractors` should
+    /// contain all attractors of the graph.
+    pub fn basins(&self, attractors: &[GraphColoredVertices]) -> Result<Vec<Basin>, BasinsError> {
+        self.start_timer();
+        info!(target: TARGET_BASINS, "Started with {} attractors.", attractors.len());
+
+        let mut weak_basins = Vec::new();
+        for attractor in attractors {
+            is_cancelled!(self, Vec::<Basin>::new)?;
+
+            let weak = self
+                .reachability()
+                .backward_closed_superset(attractor)
+                .map_err(|e| BasinsError::from_reachability_error(e, Vec::new()))?;
+
+            debug_with_limit!(
+                target: TARGET_BASINS,
+                size: weak.symbolic_size(),
+                " > Computed weak basin: {}[nodes:{}].",
+                weak.approx_cardinality(),
+                weak.symbolic_size()
+            );
+
+            weak_basins.push(weak);
+        }
+
+        let graph = &self.config().graph;
+        let mut result: Vec<Basin> = Vec::new();
+        for (i, weak) in weak_basins.iter().enumerate() {
+            is_cancelled!(self, || result.clone())?;
+
+            let strong = self
+                .reachability()
+                .forward_closed_subset(weak)
+                .map_err(|e| BasinsError::from_reachability_error(e, result.clone()))?;
+
+            let other_basins = weak_basins
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(graph.mk_empty_colored_vertices(), |acc, (_, it)| {
+                    acc.union(it)
+                });
+            let shared = weak.intersect(&other_basins);
+
+            debug_with_limit!(
+                target: TARGET_BASINS,
+                size: strong.symbolic_size() + shared.symbolic_size(),
+                " > Computed strong basin {}[nodes:{}] and shared states {}[nodes:{}].",
+                strong.approx_cardinality(),
+                strong.symbolic_size(),
+                shared.approx_cardinality(),
+                shared.symbolic_size()
+            );
+
+            result.push(Basin {
+                weak: weak.clone(),
+                strong,
+                shared,
+            });
+        }
+
+        info!(target: TARGET_BASINS, "Done. Computed {} basins.", result.len());
+
+        Ok(result)
+    }
+}
+
+impl Basins {
+    /// Create a [Reachability] instance that shares the cancellation, progress reporting
+    /// and limits of this instance. Every weak and strong basin is computed by a separate
+    /// reachability procedure, i.e. the `steps_limit` applies to each of them individually.
+    fn reachability(&self) -> Reachability {
+        Reachability::with_config(
+            ReachabilityConfig::from(self.config().graph.clone())
+                .with_cancellation_nowrap(self.config().cancellation.clone())
+                .with_progress_nowrap(self.config().progress.clone())
+                .with_bdd_size_limit(self.config().bdd_size_limit)
+                .with_steps_limit(self.config().steps_limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+
+    use crate::internal::algorithms::basins::{Basins, BasinsConfig, BasinsError};
+    use crate::internal::algorithms::configurable::Configurable;
+
+    #[test]
+    fn test_basins_toggle_switch() {
+        // A toggle switch between `a` and `b` with two fixed points. The states where `a == b`
+        // can reach both fixed points, while the states where `a != b` are already decided.
+        let bn =
+            BooleanNetwork::try_from("a -| b\nb -| a\na -> c\n$a: !b\n$b: !a\n$c: a\n").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let a = bn.as_graph().find_variable("a").unwrap();
+        let b = bn.as_graph().find_variable("b").unwrap();
+        let c = bn.as_graph().find_variable("c").unwrap();
+
+        let attractors = vec![
+            graph.mk_subspace(&[(a, true), (b, false), (c, true)]),
+            graph.mk_subspace(&[(a, false), (b, true), (c, false)]),
+        ];
+        let undecided = graph
+            .mk_subspace(&[(a, true), (b, true)])
+            .union(&graph.mk_subspace(&[(a, false), (b, false)]));
+        let strong = [
+            graph.mk_subspace(&[(a, true), (b, false)]),
+            graph.mk_subspace(&[(a, false), (b, true)]),
+        ];
+
+        let basins = Basins::from(graph.clone()).basins(&attractors).unwrap();
+        assert_eq!(basins.len(), 2);
+        for (basin, strong) in basins.iter().zip(strong) {
+            assert_eq!(basin.weak, strong.union(&undecided));
+            assert_eq!(basin.strong, strong);
+            assert_eq!(basin.shared, undecided);
+        }
+
+        // With only one attractor, nothing is shared, but the strong basin is the same.
+        let basins = Basins::from(graph.clone())
+            .basins(&attractors[..1])
+            .unwrap();
+        assert_eq!(basins.len(), 1);
+        assert!(basins[0].shared.is_empty());
+        assert_eq!(
+            basins[0].strong,
+            graph.mk_subspace(&[(a, true), (b, false)])
+        );
+
+        // The limits are reported with an (empty) partial result.
+        let config = BasinsConfig::from(graph.clone()).with_steps_limit(0);
+        let result = Basins::with_config(config).basins(&attractors);
+        assert!(matches!(
+            result,
+            Err(BasinsError::StepsLimitExceeded(partial)) if partial.is_empty()
+        ));
+    }
+}
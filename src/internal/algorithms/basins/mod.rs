@@ -0,0 +1,9 @@
+mod basin;
+mod basins_config;
+mod basins_error;
+mod basins_impl;
+
+pub use basin::Basin;
+pub use basins_config::BasinsConfig;
+pub use basins_error::BasinsError;
+pub use basins_impl::Basins;
@@ -0,0 +1,206 @@
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use biodivine_lib_bdd::{Bdd, BddPartialValuation, BddPointer, BddVariable, BddVariableSet};
+use z3::{Config, Context, SatResult, Solver, ast::Bool};
+
+use crate::internal::algorithms::cancellation::CancellationHandler;
+
+/// How often a running [BddSolver::solve] checks whether it should be interrupted.
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The result of [BddSolver::solve].
+pub enum BddSolverResult {
+    /// The asserted constraints are satisfiable. The model is restricted to the requested
+    /// variables.
+    Sat(BddPartialValuation),
+    /// The asserted constraints are not satisfiable.
+    Unsat,
+    /// The solver gave up (e.g. because it was interrupted by the cancellation handler).
+    Unknown,
+}
+
+/// A Z3 solver whose constraints are given as [Bdd] objects over a fixed [BddVariableSet].
+///
+/// This allows the algorithms to compute "local" constraints symbolically, but to delegate
+/// their conjunction (which is often the source of the symbolic explosion) to the solver.
+pub struct BddSolver {
+    // SAFETY: The `'static` lifetimes below are a lie: the solver and the variables actually
+    // reference the context in `z3`. Fields are dropped in declaration order, hence `solver`
+    // and `variables` are always dropped before `z3`. The references also never escape
+    // this struct (all methods only return owned, context-independent values).
+    solver: Solver<'static>,
+    /// Solver variables, indexed by the corresponding [BddVariable].
+    variables: Vec<Bool<'static>>,
+    z3: Box<Context>,
+}
+
+impl BddSolver {
+    /// Create a new solver with no constraints over the variables of the given `bdd_vars`.
+    pub fn new(bdd_vars: &BddVariableSet) -> BddSolver {
+        let z3 = Box::new(Context::new(&Config::new()));
+        // SAFETY: The context is boxed, hence its address does not change when the box is
+        // moved into the result. It is owned by the result and dropped only after all the
+        // objects that reference it (see the field order of `BddSolver`).
+        let z3_ref: &'static Context = unsafe { (z3.as_ref() as *const Context).as_ref().unwrap() };
+
+        let variables = bdd_vars
+            .variables()
+            .into_iter()
+            .map(|var| Bool::new_const(z3_ref, bdd_vars.name_of(var)))
+            .collect::<Vec<_>>();
+
+        BddSolver {
+            solver: Solver::new(z3_ref),
+            variables,
+            z3,
+        }
+    }
+
+    /// Assert that the given `constraint` must hold in every model.
+    ///
+    /// The formula has the same structure as the BDD, hence its size is linear in the size
+    /// of the BDD.
+    pub fn assert_bdd(&self, constraint: &Bdd) {
+        // Nodes are ordered such that the children always precede their parent, and the root
+        // is the last node.
+        let z3 = self.solver.get_context();
+        let mut nodes: Vec<Bool<'static>> = Vec::with_capacity(constraint.size());
+        for index in 0..constraint.size() {
+            let pointer = BddPointer::from_index(index);
+            let node = if pointer.is_zero() {
+                Bool::from_bool(z3, false)
+            } else if pointer.is_one() {
+                Bool::from_bool(z3, true)
+            } else {
+                let var = constraint.var_of(pointer);
+                let low = &nodes[constraint.low_link_of(pointer).to_index()];
+                let high = &nodes[constraint.high_link_of(pointer).to_index()];
+                self.variables[var.to_index()].ite(high, low)
+            };
+            nodes.push(node);
+        }
+
+        let root = nodes.pop().expect("Every BDD has at least one node.");
+        self.solver.assert(&root);
+    }
+
+    /// Create a new backtracking point. All constraints asserted after this call are
+    /// removed by the corresponding [Self::pop].
+    pub fn push(&self) {
+        self.solver.push();
+    }
+
+    /// Remove all constraints asserted since the last [Self::push].
+    pub fn pop(&self) {
+        self.solver.pop(1);
+    }
+
+    /// Find a model of the asserted constraints, restricted to the `retained` variables.
+    ///
+    /// While the solver is running, a helper thread polls the `cancellation` handler. Once
+    /// it is cancelled, the solver is interrupted and [BddSolverResult::Unknown] is returned.
+    ///
+    /// The helper only uses [CancellationHandler::is_cancelled_off_thread], since it must not
+    /// wait for anything held by the current thread (e.g. the Python GIL). The helper is woken
+    /// up as soon as the solver finishes, hence it does not delay the result.
+    pub fn solve(
+        &self,
+        retained: &[BddVariable],
+        cancellation: &dyn CancellationHandler,
+    ) -> BddSolverResult {
+        let z3_handle = &self.z3.handle();
+        let (finished, wait_for_finish) = mpsc::channel::<()>();
+        let result = thread::scope(|scope| {
+            scope.spawn(move || {
+                while !cancellation.is_cancelled_off_thread() {
+                    match wait_for_finish.recv_timeout(CANCELLATION_POLL_INTERVAL) {
+                        Err(RecvTimeoutError::Timeout) => continue,
+                        _ => return,
+                    }
+                }
+                z3_handle.interrupt();
+            });
+            let result = self.solver.check();
+            drop(finished);
+            result
+        });
+
+        match result {
+            SatResult::Unsat => return BddSolverResult::Unsat,
+            SatResult::Unknown => return BddSolverResult::Unknown,
+            SatResult::Sat => (),
+        }
+
+        let model = self
+            .solver
+            .get_model()
+            .expect("Satisfiable solver must provide a model.");
+
+        let mut valuation = BddPartialValuation::empty();
+        for var in retained {
+            let value = model
+                .eval(&self.variables[var.to_index()], true)
+                .and_then(|it| it.as_bool())
+                .expect("Model completion must provide a constant value.");
+            valuation.set_value(*var, value);
+        }
+
+        BddSolverResult::Sat(valuation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biodivine_lib_bdd::BddVariableSet;
+
+    use crate::internal::algorithms::bdd_solver::{BddSolver, BddSolverResult};
+    use crate::internal::algorithms::cancellation::{CancelTokenAtomic, CancelTokenNever};
+
+    #[test]
+    fn test_solver_drop_order() {
+        let bdd_vars = BddVariableSet::new_anonymous(3);
+        let vars = bdd_vars.variables();
+        // Repeatedly create, use and drop solvers with live constraints and backtracking
+        // points, such that a context dropped before its objects would be detected.
+        for _ in 0..10 {
+            let solver = BddSolver::new(&bdd_vars);
+            solver.assert_bdd(&bdd_vars.mk_var(vars[0]));
+            solver.push();
+            solver.assert_bdd(&bdd_vars.mk_not_var(vars[1]));
+            let BddSolverResult::Sat(valuation) = solver.solve(&vars, &CancelTokenNever) else {
+                panic!("The constraints are satisfiable.");
+            };
+            assert_eq!(valuation.get_value(vars[0]), Some(true));
+            assert_eq!(valuation.get_value(vars[1]), Some(false));
+            // Moving the solver must not invalidate the context references.
+            let moved = Box::new(solver);
+            moved.assert_bdd(&bdd_vars.mk_var(vars[1]));
+            assert!(matches!(
+                moved.solve(&vars, &CancelTokenNever),
+                BddSolverResult::Unsat
+            ));
+            moved.pop();
+            drop(moved);
+        }
+    }
+
+    #[test]
+    fn test_solver_cancelled() {
+        let bdd_vars = BddVariableSet::new_anonymous(3);
+        let solver = BddSolver::new(&bdd_vars);
+        solver.assert_bdd(&bdd_vars.mk_true());
+        let token = CancelTokenAtomic::default();
+        token.cancel();
+        // An interrupt can arrive after the (trivial) query is solved, hence both
+        // results are valid here. The point is that the call terminates.
+        let result = solver.solve(&bdd_vars.variables(), &token);
+        assert!(matches!(
+            result,
+            BddSolverResult::Sat(_) | BddSolverResult::Unknown
+        ));
+    }
+}
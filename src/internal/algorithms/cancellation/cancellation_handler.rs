@@ -2,7 +2,9 @@ use std::fmt::{Debug, Formatter, Result};
 
 use dyn_clone::{DynClone, clone_trait_object};
 
-use crate::internal::algorithms::cancellation::tokens::CancelTokenNever;
+use crate::internal::algorithms::{
+    cancellation::tokens::CancelTokenNever, progress::ProgressEvent,
+};
 
 pub trait CancellationHandler: Send + Sync + DynClone {
     /// Returns `true` if the computation associated with this handler is cancelled.
@@ -11,9 +13,21 @@ pub trait CancellationHandler: Send + Sync + DynClone {
     /// that is set by the thread responsible for user interactions)
     fn is_cancelled(&self) -> bool;
 
+    /// The same as [Self::is_cancelled], but only performs checks that can be safely polled
+    /// from a helper thread while the computation is running (i.e. checks that never block on
+    /// the thread that runs the computation). By default, this is just [Self::is_cancelled].
+    fn is_cancelled_off_thread(&self) -> bool {
+        self.is_cancelled()
+    }
+
     /// This is a no-op by default, but if cancellation is implemented using a timer,
     /// this function starts the timer.
     fn start_timer(&self) {}
+
+    /// Called every time the algorithm associated with this handler reports progress
+    /// (see [ProgressEvent]). This is a no-op by default, but it allows handlers to
+    /// cancel the computation based on the work that has been performed so far.
+    fn observe_progress(&self, _event: &ProgressEvent) {}
 }
 
 impl Default for Box<dyn CancellationHandler> {
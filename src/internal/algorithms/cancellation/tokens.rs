@@ -1,15 +1,15 @@
 use std::{
     fmt::Debug,
     sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicBool, AtomicUsize, Ordering::SeqCst},
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use log::info;
 
-use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::algorithms::{cancellation::CancellationHandler, progress::ProgressEvent};
 
 /* Never - Start */
 
@@ -49,26 +49,52 @@ impl CancelTokenAtomic {
 
 /* Timer - Start */
 
-/// A [CancellationHandler] implemented using a timer that can be started for a specified duration.
+/// A [CancellationHandler] implemented using a deadline that can be started for a specified
+/// duration.
+///
+/// The timer does not use any background thread. Instead, the elapsed time is computed
+/// whenever the token is queried. The timer can be paused, resumed, extended, or reset,
+/// so that a single time budget can be shared (or split) across multiple stages
+/// of a computation. All clones of the token share the same state.
+///
+/// A timer that was not started yet is never cancelled.
 #[derive(Clone, Debug, Default)]
-pub struct CancelTokenTimer {
-    cancelled: Arc<AtomicBool>,
-    started: Arc<AtomicBool>,
+pub struct CancelTokenTimer(Arc<Mutex<TimerState>>);
+
+/// The internal state of a [CancelTokenTimer].
+#[derive(Clone, Debug, Default)]
+struct TimerState {
+    /// The total time budget of the timer.
     time_limit: Duration,
+    /// The time elapsed before the timer was last paused (or `0` if it was never paused).
+    elapsed: Duration,
+    /// If the timer is running, this is the instant when it was last started or resumed.
+    running_since: Option<Instant>,
+    /// Set once the timer is started for the first time (and cleared by a reset).
+    started: bool,
+}
+
+impl TimerState {
+    fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(since) => self.elapsed + since.elapsed(),
+            None => self.elapsed,
+        }
+    }
 }
 
 impl CancellationHandler for CancelTokenTimer {
     fn is_cancelled(&self) -> bool {
-        assert!(self.started.load(SeqCst));
-
-        self.cancelled.load(SeqCst)
+        let state = self.state();
+        state.started && state.elapsed() >= state.time_limit
     }
 
     fn start_timer(&self) {
-        if self.cancel_after(self.time_limit) {
-            info!(target: "cancellation", "Timer for {}ms started.", self.time_limit.as_millis());
+        let time_limit = self.state().time_limit;
+        if self.start() {
+            info!(target: "cancellation", "Timer for {}ms started.", time_limit.as_millis());
         } else {
-            info!(target: "cancellation", "Timer for {}ms already started.", self.time_limit.as_millis());
+            info!(target: "cancellation", "Timer for {}ms already started.", time_limit.as_millis());
         }
     }
 }
@@ -76,30 +102,303 @@ impl CancellationHandler for CancelTokenTimer {
 impl CancelTokenTimer {
     /// Create a new timer with the specified `duration` that is not running.
     pub fn new(duration: Duration) -> CancelTokenTimer {
-        CancelTokenTimer {
-            cancelled: Default::default(),
-            started: Default::default(),
+        CancelTokenTimer(Arc::new(Mutex::new(TimerState {
             time_limit: duration,
-        }
+            ..Default::default()
+        })))
     }
 
     /// Start the cancellation timer for the given `duration`.
     ///
     /// Returns `true` if the timer was started successfully, and `false` if the timer
-    /// is either running, or the token is already cancelled.
+    /// was already started before (in which case the `duration` is ignored).
     pub fn cancel_after(&self, duration: Duration) -> bool {
-        let is_started = self.started.fetch_or(true, SeqCst);
-        if !is_started {
-            let thread_copy = self.clone();
-            std::thread::spawn(move || {
-                std::thread::sleep(duration);
-                thread_copy.cancelled.store(true, SeqCst);
-                info!(target: "cancellation", "Timer for {}ms elapsed. Operation cancelled.", duration.as_millis());
-            });
+        let mut state = self.state();
+        if state.started {
+            return false;
         }
+        state.time_limit = duration;
+        state.started = true;
+        state.running_since = Some(Instant::now());
+        true
+    }
+
+    /// Start the timer if it was not started before.
+    ///
+    /// Returns `true` if the timer was started, and `false` if it was already started
+    /// before. Note that a paused timer is not resumed by this method, see [Self::resume].
+    pub fn start(&self) -> bool {
+        let time_limit = self.state().time_limit;
+        self.cancel_after(time_limit)
+    }
+
+    /// Pause the timer. The time elapsed while the timer is paused does not count towards
+    /// the time limit.
+    ///
+    /// Returns `true` if the timer was running, and `false` otherwise.
+    pub fn pause(&self) -> bool {
+        let mut state = self.state();
+        let Some(since) = state.running_since.take() else {
+            return false;
+        };
+        state.elapsed += since.elapsed();
+        true
+    }
+
+    /// Resume a paused timer.
+    ///
+    /// Returns `true` if the timer was paused, and `false` if it is running or was not
+    /// started yet.
+    pub fn resume(&self) -> bool {
+        let mut state = self.state();
+        if !state.started || state.running_since.is_some() {
+            return false;
+        }
+        state.running_since = Some(Instant::now());
+        true
+    }
+
+    /// Reset the timer into its initial state: the elapsed time is cleared and the timer
+    /// is not running. The time limit is preserved.
+    pub fn reset(&self) {
+        let mut state = self.state();
+        state.elapsed = Duration::ZERO;
+        state.running_since = None;
+        state.started = false;
+    }
+
+    /// Increase the time limit of this timer by `duration`. This also works for a timer
+    /// that is already cancelled.
+    pub fn extend(&self, duration: Duration) {
+        let mut state = self.state();
+        state.time_limit += duration;
+    }
+
+    /// The total time limit of this timer.
+    pub fn time_limit(&self) -> Duration {
+        self.state().time_limit
+    }
+
+    /// The time that counted towards the time limit so far.
+    pub fn elapsed(&self) -> Duration {
+        self.state().elapsed()
+    }
 
-        !is_started
+    /// The time remaining until the timer is cancelled (zero if it is already cancelled).
+    pub fn remaining(&self) -> Duration {
+        let state = self.state();
+        state.time_limit.saturating_sub(state.elapsed())
+    }
+
+    /// Returns `true` if the timer is currently running (i.e. started and not paused).
+    pub fn is_running(&self) -> bool {
+        self.state().running_since.is_some()
+    }
+
+    fn state(&self) -> MutexGuard<'_, TimerState> {
+        // The state is always consistent, hence it is safe to ignore poisoning.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
 /* Timer - End */
+
+/* AnyOf - Start */
+
+/// A [CancellationHandler] that is cancelled once at least one of its inner handlers
+/// is cancelled.
+///
+/// This can be used to combine multiple cancellation conditions, e.g. a time limit and
+/// a manual cancellation using [CancelTokenAtomic].
+#[derive(Clone, Debug, Default)]
+pub struct CancelTokenAnyOf(Vec<Box<dyn CancellationHandler>>);
+
+impl CancellationHandler for CancelTokenAnyOf {
+    fn is_cancelled(&self) -> bool {
+        self.0.iter().any(|it| it.is_cancelled())
+    }
+
+    fn is_cancelled_off_thread(&self) -> bool {
+        self.0.iter().any(|it| it.is_cancelled_off_thread())
+    }
+
+    fn start_timer(&self) {
+        self.0.iter().for_each(|it| it.start_timer())
+    }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        self.0.iter().for_each(|it| it.observe_progress(event))
+    }
+}
+
+impl CancelTokenAnyOf {
+    /// Create a new token that is cancelled once any of the given `handlers` is cancelled.
+    ///
+    /// An empty [CancelTokenAnyOf] is never cancelled.
+    pub fn new(handlers: Vec<Box<dyn CancellationHandler>>) -> CancelTokenAnyOf {
+        CancelTokenAnyOf(handlers)
+    }
+}
+
+/* AnyOf - End */
+
+/* AllOf - Start */
+
+/// A [CancellationHandler] that is cancelled once all of its inner handlers are cancelled.
+#[derive(Clone, Debug, Default)]
+pub struct CancelTokenAllOf(Vec<Box<dyn CancellationHandler>>);
+
+impl CancellationHandler for CancelTokenAllOf {
+    fn is_cancelled(&self) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|it| it.is_cancelled())
+    }
+
+    fn is_cancelled_off_thread(&self) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|it| it.is_cancelled_off_thread())
+    }
+
+    fn start_timer(&self) {
+        self.0.iter().for_each(|it| it.start_timer())
+    }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        self.0.iter().for_each(|it| it.observe_progress(event))
+    }
+}
+
+impl CancelTokenAllOf {
+    /// Create a new token that is cancelled once all of the given `handlers` are cancelled.
+    ///
+    /// An empty [CancelTokenAllOf] is never cancelled.
+    pub fn new(handlers: Vec<Box<dyn CancellationHandler>>) -> CancelTokenAllOf {
+        CancelTokenAllOf(handlers)
+    }
+}
+
+/* AllOf - End */
+
+/* Memory - Start */
+
+/// A [CancellationHandler] that is cancelled once the memory usage of the computation
+/// crosses one of the given thresholds:
+///
+///  - `rss_limit`: The resident set size of the whole process, in bytes. This is currently
+///    only supported on Linux (using `/proc/self/status`). On other platforms, this
+///    limit is ignored.
+///  - `bdd_size_limit`: The BDD size of the intermediate results, as reported by the
+///    algorithms through [ProgressEvent]s.
+///
+/// Once cancelled, the token stays cancelled. The token can be cloned and shared across
+/// multiple algorithms.
+#[derive(Clone, Debug, Default)]
+pub struct CancelTokenMemory {
+    cancelled: Arc<AtomicBool>,
+    rss_limit: Option<usize>,
+    bdd_size_limit: Option<usize>,
+}
+
+impl CancellationHandler for CancelTokenMemory {
+    fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(SeqCst) {
+            return true;
+        }
+
+        let Some(rss_limit) = self.rss_limit else {
+            return false;
+        };
+
+        if current_rss().is_some_and(|rss| rss > rss_limit) {
+            if !self.cancelled.fetch_or(true, SeqCst) {
+                info!(target: "cancellation", "Process RSS exceeded {} bytes. Operation cancelled.", rss_limit);
+            }
+            return true;
+        }
+
+        false
+    }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        let bdd_size_exceeded = self
+            .bdd_size_limit
+            .is_some_and(|limit| event.bdd_size > limit);
+        if bdd_size_exceeded && !self.cancelled.fetch_or(true, SeqCst) {
+            info!(target: "cancellation", "BDD size exceeded {} nodes. Operation cancelled.", event.bdd_size);
+        }
+    }
+}
+
+impl CancelTokenMemory {
+    /// Create a new token with the given process RSS limit (in bytes) and BDD size limit
+    /// (in BDD nodes). Use `None` to disable the respective limit.
+    pub fn new(rss_limit: Option<usize>, bdd_size_limit: Option<usize>) -> CancelTokenMemory {
+        CancelTokenMemory {
+            cancelled: Default::default(),
+            rss_limit,
+            bdd_size_limit,
+        }
+    }
+}
+
+/// Read the resident set size of the current process in bytes, if available.
+fn current_rss() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kilobytes = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/* Memory - End */
+
+/* StepBudget - Start */
+
+/// A [CancellationHandler] that is cancelled once the algorithms using it have performed
+/// the given number of steps in total.
+///
+/// A step corresponds to a single [ProgressEvent]. Since the budget is shared between all
+/// clones of the token, one token can limit the total amount of work performed by
+/// a whole pipeline of algorithms.
+#[derive(Clone, Debug)]
+pub struct CancelTokenStepBudget {
+    used: Arc<AtomicUsize>,
+    budget: usize,
+}
+
+impl CancellationHandler for CancelTokenStepBudget {
+    fn is_cancelled(&self) -> bool {
+        self.used.load(SeqCst) >= self.budget
+    }
+
+    fn observe_progress(&self, _event: &ProgressEvent) {
+        if self.used.fetch_add(1, SeqCst) + 1 == self.budget {
+            info!(target: "cancellation", "Step budget of {} steps exhausted. Operation cancelled.", self.budget);
+        }
+    }
+}
+
+impl CancelTokenStepBudget {
+    /// Create a new token with the given step `budget`.
+    pub fn new(budget: usize) -> CancelTokenStepBudget {
+        CancelTokenStepBudget {
+            used: Default::default(),
+            budget,
+        }
+    }
+
+    /// The number of steps performed so far.
+    pub fn used(&self) -> usize {
+        self.used.load(SeqCst)
+    }
+
+    /// The number of steps that can still be performed before the token is cancelled.
+    pub fn remaining(&self) -> usize {
+        self.budget.saturating_sub(self.used())
+    }
+}
+
+/* StepBudget - End */
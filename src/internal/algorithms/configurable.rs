@@ -1,13 +1,14 @@
 use std::convert::TryFrom;
 
-use biodivine_lib_param_bn::BooleanNetwork;
+use biodivine_lib_param_bn::{BooleanNetwork, symbolic_async_graph::SymbolicAsyncGraph};
 use dyn_clone::DynClone;
 
-use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler,
+    progress::{ProgressEvent, ProgressReporter},
+};
 
-// TODO: once we are able to create symbolic space context from a graph, add a trait bound
-// for From<SymbolicSpaceContext>, also add to Configurable
-pub trait Config: for<'a> TryFrom<&'a BooleanNetwork> {
+pub trait Config: for<'a> TryFrom<&'a BooleanNetwork> + TryFrom<SymbolicAsyncGraph> {
     fn cancellation(&self) -> &dyn CancellationHandler;
 
     fn set_cancellation(&mut self, cancellation: Box<dyn CancellationHandler>);
@@ -28,9 +29,30 @@ pub trait Config: for<'a> TryFrom<&'a BooleanNetwork> {
         self.set_cancellation(cancellation);
         self
     }
+
+    fn progress(&self) -> &dyn ProgressReporter;
+
+    fn set_progress(&mut self, progress: Box<dyn ProgressReporter>);
+
+    /// Update the `progress` property, automatically wrapping the [ProgressReporter]
+    /// in a `Box`.
+    fn with_progress<P>(mut self, progress: P) -> Self
+    where
+        P: ProgressReporter + 'static,
+        Self: Sized,
+    {
+        self.set_progress(Box::new(progress));
+        self
+    }
+
+    /// Update the `progress` property. For internal use only. Do not use for the Creation API.
+    fn with_progress_nowrap(mut self, progress: Box<dyn ProgressReporter>) -> Self {
+        self.set_progress(progress);
+        self
+    }
 }
 
-pub trait Configurable: for<'a> TryFrom<&'a BooleanNetwork> {
+pub trait Configurable: for<'a> TryFrom<&'a BooleanNetwork> + TryFrom<SymbolicAsyncGraph> {
     type ConfigType: Config;
 
     /// Retrieve the internal configuration struct of this instance.
@@ -51,7 +73,25 @@ where
         self.config().cancellation().is_cancelled()
     }
 
+    fn is_cancelled_off_thread(&self) -> bool {
+        self.config().cancellation().is_cancelled_off_thread()
+    }
+
     fn start_timer(&self) {
         self.config().cancellation().start_timer()
     }
+
+    fn observe_progress(&self, event: &ProgressEvent) {
+        self.config().cancellation().observe_progress(event)
+    }
+}
+
+impl<T> ProgressReporter for T
+where
+    T: Configurable + Send + Sync + DynClone,
+{
+    fn report(&self, event: &ProgressEvent) {
+        self.config().cancellation().observe_progress(event);
+        self.config().progress().report(event)
+    }
 }
@@ -4,7 +4,9 @@ use biodivine_lib_param_bn::{
 };
 use macros::Config;
 
-use crate::internal::algorithms::{cancellation::CancellationHandler, configurable::Config};
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
+};
 
 use super::FixedPointsError;
 
@@ -24,6 +26,11 @@ pub struct FixedPointsConfig {
     /// Default: [CancelTokenNever].
     pub cancellation: Box<dyn CancellationHandler>,
 
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
     /// The maximum size of the BDD used in the merging process.
     ///
     /// Note that the algorithm can use other auxiliary BDDs that do not
@@ -39,6 +46,7 @@ impl From<SymbolicAsyncGraph> for FixedPointsConfig {
         FixedPointsConfig {
             restriction: graph.mk_unit_colored_vertices(),
             cancellation: Default::default(),
+            progress: Default::default(),
             bdd_size_limit: usize::MAX,
             graph,
         }
@@ -2,25 +2,32 @@ use std::collections::{HashMap, HashSet};
 
 use biodivine_lib_bdd::{Bdd, BddVariable};
 use biodivine_lib_param_bn::{
-    BooleanNetwork,
+    BooleanNetwork, ParameterId, VariableId,
     biodivine_std::traits::Set,
     symbolic_async_graph::{GraphColoredVertices, GraphColors, GraphVertices, SymbolicAsyncGraph},
 };
+use either::Either;
 use log::{debug, info, trace};
 use macros::Configurable;
 
 use crate::{
     debug_with_limit,
-    internal::algorithms::{cancellation::CancellationHandler, configurable::Configurable},
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
     is_cancelled,
 };
 
-use super::{FixedPointsConfig, FixedPointsError};
+use super::{FixedPointsConfig, FixedPointsError, FixedPointsSolverIterator};
 
 const TARGET_NAIVE_SYMBOLIC: &str = "FixedPoints::naive_symbolic";
 const TARGET_SYMBOLIC: &str = "FixedPoints::symbolic";
 const TARGET_SYMBOLIC_VERTICES: &str = "FixedPoints::symbolic_vertices";
 const TARGET_SYMBOLIC_COLORS: &str = "FixedPoints::symbolic_colors";
+const TARGET_SYMBOLIC_PROJECTION: &str = "FixedPoints::symbolic_projection";
+const TARGET_SOLVER_ITERATOR: &str = "FixedPoints::solver_iterator";
 
 /// Implements fixed-point search over a [SymbolicAsyncGraph].
 ///
@@ -208,7 +215,7 @@ impl FixedPoints {
     /// faster than running [Self::symbolic] directly.
     ///
     /// If you are only interested in certain combinations of variables within the fixed-points,
-    /// you can also write a custom projection query using [Self::symbolic_merge].
+    /// you can also compute a custom projection using [Self::symbolic_projection].
     pub fn symbolic_vertices(&self) -> Result<GraphVertices, FixedPointsError> {
         self.start_timer();
         let stg = &self.config().graph;
@@ -297,6 +304,122 @@ impl FixedPoints {
 
         Ok(colors)
     }
+
+    /// Compute the fixed points within `restriction`, projected onto the given network
+    /// `variables` and uninterpreted `functions`. Each function is either the implicit function
+    /// of a network variable (`Left`), or an explicit network parameter (`Right`).
+    ///
+    /// The result contains every vertex-color pair that agrees with some fixed-point on all
+    /// retained variables and functions. In other words, the result does not depend on
+    /// the eliminated variables and functions, only on the retained ones. To obtain the unique
+    /// combinations of the retained values, you can use a
+    /// [biodivine_lib_param_bn::symbolic_async_graph::projected_iteration::RawProjection]
+    /// over the retained symbolic variables.
+    ///
+    /// Implicit functions of variables that have an explicit update function are not
+    /// represented symbolically and are ignored.
+    ///
+    /// **Characteristics:** This is a generalization of [Self::symbolic_vertices] (all variables
+    /// and no functions are retained) and [Self::symbolic_colors] (all functions and no
+    /// variables are retained). As such, the fewer variables and functions are retained,
+    /// the faster the computation tends to be.
+    pub fn symbolic_projection(
+        &self,
+        variables: &[VariableId],
+        functions: &[Either<VariableId, ParameterId>],
+    ) -> Result<GraphColoredVertices, FixedPointsError> {
+        self.start_timer();
+        let stg = &self.config().graph;
+        let ctx = stg.symbolic_context();
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_SYMBOLIC_PROJECTION,
+            "Started search with {}[nodes:{}] candidates, retaining {} variables and {} functions.",
+            restriction.approx_cardinality(),
+            restriction.symbolic_size(),
+            variables.len(),
+            functions.len()
+        );
+
+        let mut to_merge = self.prepare_to_merge(TARGET_SYMBOLIC_PROJECTION)?;
+
+        // Finally add the global requirement on the whole state space, if it is relevant.
+        if !stg.unit_colored_vertices().is_subset(restriction) {
+            to_merge.push(restriction.as_bdd().clone());
+        }
+
+        let mut retained: HashSet<BddVariable> = variables
+            .iter()
+            .map(|var| ctx.get_state_variable(*var))
+            .collect();
+        for function in functions {
+            let table = match function {
+                Either::Left(var) => ctx.get_implicit_function_table(*var),
+                Either::Right(par) => Some(ctx.get_explicit_function_table(*par)),
+            };
+            if let Some(table) = table {
+                retained.extend(table.symbolic_variables().iter().cloned());
+            }
+        }
+
+        let projections: HashSet<BddVariable> = ctx
+            .state_variables()
+            .iter()
+            .chain(ctx.parameter_variables())
+            .filter(|it| !retained.contains(it))
+            .cloned()
+            .collect();
+
+        is_cancelled!(self, || { to_merge.pop().unwrap().clone() })?;
+
+        let bdd = self.symbolic_merge(to_merge, projections, TARGET_SYMBOLIC_PROJECTION)?;
+
+        is_cancelled!(self, || { bdd.clone() })?;
+
+        // Eliminating parameters can introduce invalid colors, which are removed here.
+        let unit = stg.unit_colored_vertices();
+        let fixed_points = unit.copy(bdd).intersect(unit);
+
+        info!(
+            target: TARGET_SYMBOLIC_PROJECTION,
+            "Found {}[nodes:{}] projected fixed-points.",
+            fixed_points.approx_cardinality(),
+            fixed_points.symbolic_size(),
+        );
+
+        Ok(fixed_points)
+    }
+
+    /// Enumerate the fixed points within `restriction` one by one using the Z3 solver.
+    ///
+    /// Each item of the returned [FixedPointsSolverIterator] is a singleton set with one vertex
+    /// and one color for which the vertex is a fixed-point.
+    ///
+    /// **Characteristics:** The stability constraint of each variable is still computed
+    /// symbolically, but the constraints are never merged into a single BDD. Instead, they are
+    /// translated into a solver formula. This avoids the symbolic explosion of [Self::symbolic]
+    /// on large networks, but each fixed point has to be enumerated explicitly. Hence, it is
+    /// best suited for finding a few fixed points, or for networks with few fixed points.
+    ///
+    /// A running solver is interrupted once the computation is cancelled. However, Python
+    /// interrupts are only detected between the individual fixed points.
+    pub fn solver_iterator(&self) -> Result<FixedPointsSolverIterator, FixedPointsError> {
+        self.start_timer();
+        let stg = &self.config().graph;
+        let restriction = &self.config().restriction;
+
+        let mut constraints = self.prepare_to_merge(TARGET_SOLVER_ITERATOR)?;
+
+        // Finally add the global requirement on the whole state space, if it is relevant.
+        if !stg.unit_colored_vertices().is_subset(restriction) {
+            constraints.push(restriction.as_bdd().clone());
+        }
+
+        is_cancelled!(self, || { restriction.as_bdd().clone() })?;
+
+        Ok(FixedPointsSolverIterator::new(self.clone(), &constraints))
+    }
 }
 
 impl FixedPoints {
@@ -335,15 +458,14 @@ impl FixedPoints {
         Ok(result)
     }
 
-    /// This is a helper method that is used by [Self::symbolic], [Self::symbolic_vertices] and
-    /// [Self::symbolic_colors].
+    /// This is a helper method that is used by [Self::symbolic], [Self::symbolic_vertices],
+    /// [Self::symbolic_colors] and [Self::symbolic_projection].
     ///
     /// It greedily performs a conjunction of the given BDDs, but eliminates the symbolic
-    /// variables given in `projections`. Using this method, you can implement arbitrary projected
-    /// fixed-point detection. However, the method is inherently unsafe because currently
-    /// there is no way to give a type-safe result for operations other than `symbolic_vertices`
-    /// and `symbolic_colors`, so it is up to you to understand whether the result is
-    /// actually what you wanted.
+    /// variables given in `projections`. The method is inherently unsafe because the result
+    /// is an untyped BDD, so it is up to you to understand whether the result is actually
+    /// what you wanted. Prefer [Self::symbolic_projection] for arbitrary projected
+    /// fixed-point detection.
     pub(crate) fn symbolic_merge(
         &self,
         to_merge: Vec<Bdd>,
@@ -458,6 +580,13 @@ impl FixedPoints {
                 return Ok(universe.mk_false());
             }
 
+            self.report(&ProgressEvent {
+                target,
+                step: merged.len(),
+                bdd_size: result.size(),
+                candidates: result.cardinality(),
+            });
+
             debug_with_limit!(
                 target: target,
                 size: result.size(),
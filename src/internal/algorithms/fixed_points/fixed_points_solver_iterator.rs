@@ -0,0 +1,191 @@
+use biodivine_lib_bdd::{Bdd, BddVariable};
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use log::info;
+
+use crate::{
+    internal::algorithms::{
+        bdd_solver::{BddSolver, BddSolverResult},
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
+    is_cancelled,
+};
+
+use super::{FixedPoints, FixedPointsError};
+
+const TARGET_SOLVER_ITERATOR: &str = "FixedPoints::solver_iterator";
+
+/// An iterator that lazily enumerates fixed points using the Z3 solver.
+///
+/// Each item is a singleton set containing one fixed-point vertex together with one color
+/// for which the vertex is a fixed-point, or an error. After an error is returned, the iterator
+/// is exhausted. The partial result of the error contains all fixed points that were returned
+/// by the iterator so far.
+///
+/// See [FixedPoints::solver_iterator].
+pub struct FixedPointsSolverIterator {
+    fixed_points: FixedPoints,
+    solver: BddSolver,
+    /// The state and parameter variables that determine a single fixed point.
+    variables: Vec<BddVariable>,
+    found: Bdd,
+    found_count: usize,
+    done: bool,
+}
+
+impl FixedPointsSolverIterator {
+    /// Create a new iterator which enumerates the satisfying valuations of the conjunction
+    /// of the given `constraints`.
+    pub(super) fn new(fixed_points: FixedPoints, constraints: &[Bdd]) -> FixedPointsSolverIterator {
+        let ctx = fixed_points.config().graph.symbolic_context();
+        let bdd_vars = ctx.bdd_variable_set();
+
+        let solver = BddSolver::new(bdd_vars);
+        for constraint in constraints {
+            solver.assert_bdd(constraint);
+        }
+
+        let mut variables = ctx.state_variables().clone();
+        variables.extend(ctx.parameter_variables());
+
+        info!(
+            target: TARGET_SOLVER_ITERATOR,
+            "Started with {} constraints over {} variables.",
+            constraints.len(),
+            variables.len()
+        );
+
+        FixedPointsSolverIterator {
+            found: bdd_vars.mk_false(),
+            fixed_points,
+            solver,
+            variables,
+            found_count: 0,
+            done: false,
+        }
+    }
+
+    /// The union of all fixed points that were returned by this iterator so far.
+    pub fn found(&self) -> GraphColoredVertices {
+        let unit = self.fixed_points.config().graph.unit_colored_vertices();
+        unit.copy(self.found.clone())
+    }
+
+    /// Search for the next fixed point. Returns `None` once all fixed points are enumerated.
+    fn next_fixed_point(&mut self) -> Result<Option<GraphColoredVertices>, FixedPointsError> {
+        is_cancelled!(&self.fixed_points, || self.found.clone())?;
+
+        let valuation = match self.solver.solve(&self.variables, &self.fixed_points) {
+            BddSolverResult::Sat(valuation) => valuation,
+            BddSolverResult::Unsat => {
+                info!(
+                    target: TARGET_SOLVER_ITERATOR,
+                    "Done. Found {} fixed-points.",
+                    self.found_count
+                );
+                return Ok(None);
+            }
+            // The solver gave up, which only happens once it is interrupted by cancellation.
+            BddSolverResult::Unknown => {
+                return Err(FixedPointsError::Cancelled(self.found.clone()));
+            }
+        };
+
+        let graph = &self.fixed_points.config().graph;
+        let bdd = graph
+            .symbolic_context()
+            .bdd_variable_set()
+            .mk_conjunctive_clause(&valuation);
+
+        // Block this valuation, such that the next model is different.
+        self.solver.assert_bdd(&bdd.not());
+
+        self.found = self.found.or(&bdd);
+        self.found_count += 1;
+
+        self.fixed_points.report(&ProgressEvent {
+            target: TARGET_SOLVER_ITERATOR,
+            step: self.found_count,
+            bdd_size: self.found.size(),
+            candidates: self.found_count as f64,
+        });
+
+        Ok(Some(graph.unit_colored_vertices().copy(bdd)))
+    }
+}
+
+impl Iterator for FixedPointsSolverIterator {
+    type Item = Result<GraphColoredVertices, FixedPointsError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_fixed_point() {
+            Ok(Some(fixed_point)) => Some(Ok(fixed_point)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+
+    use crate::internal::algorithms::cancellation::CancelTokenAtomic;
+    use crate::internal::algorithms::configurable::{Config, Configurable};
+    use crate::internal::algorithms::fixed_points::{
+        FixedPoints, FixedPointsConfig, FixedPointsError,
+    };
+
+    #[test]
+    fn test_solver_iterator() {
+        let bn = BooleanNetwork::try_from_file("tests/model-myeloid-3-unknown.aeon").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let fixed_points = FixedPoints::from(graph.clone());
+
+        let mut iterator = fixed_points.solver_iterator().unwrap();
+        let mut found = graph.mk_empty_colored_vertices();
+        for fixed_point in iterator.by_ref() {
+            let fixed_point = fixed_point.unwrap();
+            assert_eq!(fixed_point.approx_cardinality(), 1.0);
+            assert!(fixed_point.intersect(&found).is_empty());
+            found = found.union(&fixed_point);
+        }
+
+        assert_eq!(found, fixed_points.symbolic().unwrap());
+        assert_eq!(iterator.found(), found);
+        assert!(iterator.next().is_none());
+    }
+
+    #[test]
+    fn test_solver_iterator_cancelled() {
+        let bn = BooleanNetwork::try_from_file("tests/model-myeloid-3-unknown.aeon").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let token = CancelTokenAtomic::default();
+        let config = FixedPointsConfig::from(graph).with_cancellation(token.clone());
+
+        let mut iterator = FixedPoints::with_config(config).solver_iterator().unwrap();
+        let first = iterator.next().unwrap().unwrap();
+        token.cancel();
+
+        // The error contains the fixed points found so far, and ends the iteration.
+        match iterator.next() {
+            Some(Err(FixedPointsError::Cancelled(partial))) => {
+                assert_eq!(&partial, first.as_bdd());
+            }
+            _ => panic!("Expected the iterator to be cancelled."),
+        }
+        assert!(iterator.next().is_none());
+    }
+}
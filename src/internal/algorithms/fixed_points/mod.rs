@@ -1,7 +1,9 @@
 mod fixed_points_config;
 mod fixed_points_error;
 mod fixed_points_impl;
+mod fixed_points_solver_iterator;
 
 pub use fixed_points_config::FixedPointsConfig;
 pub use fixed_points_error::FixedPointsError;
 pub use fixed_points_impl::FixedPoints;
+pub use fixed_points_solver_iterator::FixedPointsSolverIterator;
@@ -24,38 +24,3 @@ macro_rules! debug_with_limit {
         }
     };
 }
-
-/// A macro to conditionally add the `#[pyclass]` attribute with a custom name
-/// to a struct based on the `algorithms-pyo3-bindings` feature.
-///
-/// # Example:
-/// ```rust
-/// maybe_pyclass!(
-///     "PercolationComp",
-///     /// Implements subspace percolation over a [SymbolicAsyncGraph].
-///     #[derive(Clone, Configurable)]
-///     pub struct Percolation {
-///         ...
-///     }
-/// )
-/// ```
-#[macro_export]
-macro_rules! maybe_pyclass {
-    (
-        $python_name:literal,
-        $(#[$meta:meta])*
-        $vis:vis struct $name:ident $($rest:tt)*
-    ) => {
-        #[cfg(feature = "algorithms-pyo3-bindings")]
-        use pyo3::pyclass;
-
-        #[cfg(feature = "algorithms-pyo3-bindings")]
-        #[pyclass(name = $python_name, module = "biodivine_aeon", frozen)]
-        $(#[$meta])*
-        $vis struct $name $($rest)*
-
-        #[cfg(not(feature = "algorithms-pyo3-bindings"))]
-        $(#[$meta])*
-        $vis struct $name $($rest)*
-    };
-}
@@ -1,8 +1,13 @@
 #![allow(dead_code)]
+pub mod attractors;
+pub mod basins;
+pub mod bdd_solver;
 pub mod cancellation;
 pub mod configurable;
 pub mod fixed_points;
 pub mod macros;
 pub mod percolation;
+pub mod progress;
 pub mod reachability;
+pub mod scc_decomposition;
 pub mod trap_spaces;
@@ -1,7 +1,9 @@
+mod percolated_space;
 mod percolation_config;
 mod percolation_error;
 mod percolation_impl;
 
+pub use percolated_space::PercolatedSpace;
 pub use percolation_config::PercolationConfig;
 pub use percolation_error::PercolationError;
 #[allow(unused_imports)]
@@ -0,0 +1,19 @@
+use biodivine_lib_param_bn::VariableId;
+
+/// The result of percolating a single space, as computed by [Percolation::percolate_spaces].
+#[derive(Clone, Debug)]
+pub struct PercolatedSpace {
+    /// The original space that was percolated.
+    pub original: Vec<(VariableId, bool)>,
+
+    /// The percolated space. This is always a subspace of the `original` space.
+    pub percolated: Vec<(VariableId, bool)>,
+
+    /// Variables fixed in the `original` space whose update function percolates to
+    /// the opposite value.
+    pub conflicts: Vec<VariableId>,
+
+    /// Indicates that the `percolated` space is a trap space (for all interpretations of
+    /// the network parameters).
+    pub is_trap_space: bool,
+}
@@ -1,27 +1,30 @@
 use biodivine_lib_param_bn::{BooleanNetwork, symbolic_async_graph::SymbolicAsyncGraph};
 use macros::Config;
+use pyo3::pyclass;
 
-use crate::{
-    internal::algorithms::{cancellation::CancellationHandler, configurable::Config},
-    maybe_pyclass,
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
 };
 
 use super::PercolationError;
 
-maybe_pyclass!(
-    "PercolationConfig",
-    /// A configuration struct for the [Percolation] algorithm.
-    #[derive(Clone, Config)]
-    pub struct PercolationConfig {
-        /// The symbolic graph whose variables will be used for subspace percolation.
-        pub graph: SymbolicAsyncGraph,
-
-        /// A `CancellationHandler` that can be used to stop the algorithm externally.
-        ///
-        /// Default: [CancelTokenNever].
-        pub cancellation: Box<dyn CancellationHandler>,
-    }
-);
+/// A configuration struct for the [Percolation] algorithm.
+#[pyclass(name = "PercolationConfig", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Config)]
+pub struct PercolationConfig {
+    /// The symbolic graph whose variables will be used for subspace percolation.
+    pub graph: SymbolicAsyncGraph,
+
+    /// A `CancellationHandler` that can be used to stop the algorithm externally.
+    ///
+    /// Default: [CancelTokenNever].
+    pub cancellation: Box<dyn CancellationHandler>,
+
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+}
 
 impl From<SymbolicAsyncGraph> for PercolationConfig {
     /// Create a new "default" [PercolationConfig] from the given [SymbolicAsyncGraph].
@@ -29,6 +32,7 @@ impl From<SymbolicAsyncGraph> for PercolationConfig {
         PercolationConfig {
             graph,
             cancellation: Default::default(),
+            progress: Default::default(),
         }
     }
 }
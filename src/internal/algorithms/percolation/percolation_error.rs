@@ -1,16 +1,50 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter, Result};
 
+use biodivine_lib_bdd::Bdd;
 use thiserror::Error;
 
 use crate::internal::algorithms::cancellation::CancellationError;
 
+use super::PercolatedSpace;
+
 /// An error returned by a [Percolation] procedure.
-#[derive(Error, Debug)]
+#[derive(Error)]
 pub enum PercolationError {
     #[error("config creation failed: {0}")]
     CreationFailed(String),
     #[error("operation cancelled")]
     Cancelled(Vec<Option<bool>>),
+    #[error("operation cancelled")]
+    CancelledSymbolic(Bdd),
+    #[error("operation cancelled")]
+    CancelledSpaces(Vec<PercolatedSpace>),
+    #[error("spaces not compatible with the given graph")]
+    IncompatibleSpaces,
+}
+
+/// The default implementation will print the whole BDD, which can be quite large.
+impl Debug for PercolationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            PercolationError::CreationFailed(error) => {
+                write!(f, "CreationFailed({})", error)
+            }
+            PercolationError::Cancelled(subspace) => {
+                write!(f, "Cancelled(partial_result={:?})", subspace)
+            }
+            PercolationError::CancelledSymbolic(bdd) => {
+                write!(
+                    f,
+                    "CancelledSymbolic(partial_result={})",
+                    bdd.exact_cardinality()
+                )
+            }
+            PercolationError::CancelledSpaces(spaces) => {
+                write!(f, "CancelledSpaces(partial_result={})", spaces.len())
+            }
+            PercolationError::IncompatibleSpaces => write!(f, "IncompatibleSpaces"),
+        }
+    }
 }
 
 impl From<CancellationError<Vec<Option<bool>>>> for PercolationError {
@@ -18,3 +52,15 @@ impl From<CancellationError<Vec<Option<bool>>>> for PercolationError {
         PercolationError::Cancelled(error_value.into_partial_data())
     }
 }
+
+impl From<CancellationError<Bdd>> for PercolationError {
+    fn from(error_value: CancellationError<Bdd>) -> Self {
+        PercolationError::CancelledSymbolic(error_value.into_partial_data())
+    }
+}
+
+impl From<CancellationError<Vec<PercolatedSpace>>> for PercolationError {
+    fn from(error_value: CancellationError<Vec<PercolatedSpace>>) -> Self {
+        PercolationError::CancelledSpaces(error_value.into_partial_data())
+    }
+}
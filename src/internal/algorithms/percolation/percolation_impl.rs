@@ -1,29 +1,37 @@
 use std::collections::HashSet;
 
-use biodivine_lib_bdd::{Bdd, BddVariable};
+use biodivine_lib_bdd::{Bdd, BddVariable, bdd};
 use biodivine_lib_param_bn::{
-    BooleanNetwork, VariableId, symbolic_async_graph::SymbolicAsyncGraph,
+    BooleanNetwork, VariableId,
+    symbolic_async_graph::{SymbolicAsyncGraph, projected_iteration::RawProjection},
+    trap_spaces::{NetworkColoredSpaces, NetworkSpaces, SymbolicSpaceContext},
 };
 use log::{debug, info, trace};
 use macros::Configurable;
+use pyo3::pyclass;
 
 use crate::{
-    internal::algorithms::{cancellation::CancellationHandler, configurable::Configurable},
-    is_cancelled, maybe_pyclass,
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+        trap_spaces::SymbolicSpaceContextExt,
+    },
+    is_cancelled,
 };
 
-use super::{PercolationConfig, PercolationError};
+use super::{PercolatedSpace, PercolationConfig, PercolationError};
 
 const TARGET_PERCOLATE_SUBSPACE: &str = "Percolation::percolate_subspace";
+const TARGET_PERCOLATE_SPACES: &str = "Percolation::percolate_spaces";
+const TARGET_PERCOLATE_SYMBOLIC: &str = "Percolation::percolate_symbolic";
 
-maybe_pyclass!(
-    "PercolationComp",
-    /// Implements subspace percolation over a [SymbolicAsyncGraph].
-    ///
-    /// See [PercolationConfig] and [PercolationError] for more info.
-    #[derive(Clone, Configurable)]
-    pub struct Percolation(pub PercolationConfig);
-);
+/// Implements subspace percolation over a [SymbolicAsyncGraph].
+///
+/// See [PercolationConfig] and [PercolationError] for more info.
+#[pyclass(name = "PercolationComp", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Configurable)]
+pub struct Percolation(pub PercolationConfig);
 
 impl From<SymbolicAsyncGraph> for Percolation {
     /// Create a new [Percolation] instance from the given [SymbolicAsyncGraph]
@@ -53,7 +61,8 @@ impl Percolation {
     ///
     /// This method should technically work on parametrized networks as well, but the constant
     /// check is performed across all interpretations, hence a lot of sub-spaces will not
-    /// percolate meaningfully. We recommend using other symbolic methods for such systems.
+    /// percolate meaningfully. We recommend using [Percolation::percolate_symbolic] for such
+    /// systems.
     pub fn percolate_subspace(
         &self,
         subspace: Vec<(VariableId, bool)>,
@@ -65,6 +74,250 @@ impl Percolation {
             subspace.len()
         );
 
+        // Variables that have a known fixed value.
+        let mut fixed: Vec<Option<bool>> = vec![None; self.config().graph.num_vars()];
+        for (var, v) in &subspace {
+            fixed[var.to_index()] = Some(*v);
+        }
+
+        self.percolate_fixed(&mut fixed, TARGET_PERCOLATE_SUBSPACE)?;
+
+        let result = fixed_to_subspace(&fixed);
+
+        info!(target: TARGET_PERCOLATE_SUBSPACE, "Done. Result: {} fixed variables.", result.len());
+        Ok(result)
+    }
+
+    /// Performs a percolation of every space in the given set of `spaces`.
+    ///
+    /// Each space is percolated the same way as in [Percolation::percolate_subspace]. Instead of
+    /// silently ignoring values that percolate to a conflict with the original space, each
+    /// [PercolatedSpace] reports the conflicting variables explicitly. It also indicates whether
+    /// the percolated space is a trap space. Spaces that are not encoded correctly (i.e. contain
+    /// no states) are skipped.
+    ///
+    /// The `spaces` must use the encoding of the given `ctx`, and `ctx` must be compatible with
+    /// the underlying `graph`. Otherwise, [PercolationError::IncompatibleSpaces] is returned.
+    pub fn percolate_spaces(
+        &self,
+        ctx: &SymbolicSpaceContext,
+        spaces: &NetworkSpaces,
+    ) -> Result<Vec<PercolatedSpace>, PercolationError> {
+        self.start_timer();
+        info!(
+            target: TARGET_PERCOLATE_SPACES,
+            "Started with {} spaces.",
+            spaces.exact_cardinality()
+        );
+
+        let graph = &self.config().graph;
+        if spaces.as_bdd().num_vars() != ctx.bdd_variable_set().num_vars()
+            || ctx.inner_context().num_state_variables() != graph.num_vars()
+        {
+            return Err(PercolationError::IncompatibleSpaces);
+        }
+
+        let state_variables = graph.symbolic_context().state_variables();
+        let dual_variables = ctx.inner_context().all_extra_state_variables().clone();
+        let projection = RawProjection::new(dual_variables, spaces.as_bdd());
+
+        let mut results: Vec<PercolatedSpace> = Vec::new();
+        for space in projection.into_iter() {
+            is_cancelled!(self, || results.clone())?;
+
+            let mut fixed: Vec<Option<bool>> = Vec::with_capacity(graph.num_vars());
+            for var in graph.variables() {
+                let can_be_true = space.get_value(ctx.get_positive_variable(var)) == Some(true);
+                let can_be_false = space.get_value(ctx.get_negative_variable(var)) == Some(true);
+                match (can_be_true, can_be_false) {
+                    (true, true) => fixed.push(None),
+                    (true, false) => fixed.push(Some(true)),
+                    (false, true) => fixed.push(Some(false)),
+                    (false, false) => break,
+                }
+            }
+
+            if fixed.len() != graph.num_vars() {
+                trace!(target: TARGET_PERCOLATE_SPACES, "Skipped invalid space encoding.");
+                continue;
+            }
+
+            let original = fixed_to_subspace(&fixed);
+            self.percolate_fixed(&mut fixed, TARGET_PERCOLATE_SPACES)
+                .map_err(|error| match error {
+                    PercolationError::Cancelled(_) => {
+                        PercolationError::CancelledSpaces(results.clone())
+                    }
+                    error => error,
+                })?;
+
+            // The original variables are never overwritten, hence they must be checked
+            // explicitly. The percolated variables are constant by construction.
+            let restriction = fixed
+                .iter()
+                .enumerate()
+                .filter_map(|(i, v)| v.map(|v| (state_variables[i], v)))
+                .collect::<Vec<_>>();
+            let mut conflicts = Vec::new();
+            let mut is_trap_space = true;
+            for (var, value) in &original {
+                let fn_bdd = graph.get_symbolic_fn_update(*var).restrict(&restriction);
+                match (fn_bdd.is_true(), fn_bdd.is_false()) {
+                    (true, _) if !*value => conflicts.push(*var),
+                    (_, true) if *value => conflicts.push(*var),
+                    (false, false) => is_trap_space = false,
+                    _ => (),
+                }
+            }
+            is_trap_space = is_trap_space && conflicts.is_empty();
+
+            debug!(
+                target: TARGET_PERCOLATE_SPACES,
+                "Percolated space #{}: {} fixed variables, {} conflicts, trap space: {}.",
+                results.len(),
+                fixed.iter().filter(|v| v.is_some()).count(),
+                conflicts.len(),
+                is_trap_space,
+            );
+
+            results.push(PercolatedSpace {
+                original,
+                percolated: fixed_to_subspace(&fixed),
+                conflicts,
+                is_trap_space,
+            });
+        }
+
+        info!(
+            target: TARGET_PERCOLATE_SPACES,
+            "Done. Result: {} percolated spaces.",
+            results.len()
+        );
+        Ok(results)
+    }
+
+    /// Performs a colour-aware percolation of all the given colored subspaces.
+    ///
+    /// The result contains, for every colour and space in `spaces`, the space percolated
+    /// for this particular colour. Unlike [Percolation::percolate_subspace], the constant check
+    /// is performed for each colour separately, meaning that parameters of partially specified
+    /// networks do not prevent percolation. Similar to [Percolation::percolate_subspace],
+    /// values fixed in the original space are never overwritten.
+    ///
+    /// The `spaces` must use the encoding of the given `ctx`, and `ctx` must be compatible with
+    /// the underlying `graph` (i.e. it must describe the same network variables and parameters).
+    /// Otherwise, [PercolationError::IncompatibleSpaces] is returned.
+    pub fn percolate_symbolic(
+        &self,
+        ctx: &SymbolicSpaceContext,
+        spaces: &NetworkColoredSpaces,
+    ) -> Result<NetworkColoredSpaces, PercolationError> {
+        self.start_timer();
+        info!(
+            target: TARGET_PERCOLATE_SYMBOLIC,
+            "Started with {} colored spaces.",
+            spaces.as_bdd().cardinality()
+        );
+
+        let graph = &self.config().graph;
+        let bdd_vars = ctx.bdd_variable_set();
+        if spaces.as_bdd().num_vars() != bdd_vars.num_vars() {
+            return Err(PercolationError::IncompatibleSpaces);
+        }
+
+        // For every variable, the colored spaces in which its update function can be
+        // `true` and `false`, respectively.
+        let mut can_be_true = Vec::with_capacity(graph.num_vars());
+        let mut can_be_false = Vec::with_capacity(graph.num_vars());
+        for var in graph.variables() {
+            let update_bdd = ctx
+                .inner_context()
+                .transfer_from(graph.get_symbolic_fn_update(var), graph.symbolic_context())
+                .ok_or(PercolationError::IncompatibleSpaces)?;
+
+            let cancelled = |_| PercolationError::CancelledSymbolic(spaces.as_bdd().clone());
+            can_be_true.push(
+                ctx.mk_can_go_to_true_ext(&update_bdd, self)
+                    .map_err(cancelled)?,
+            );
+            can_be_false.push(
+                ctx.mk_can_go_to_true_ext(&update_bdd.not(), self)
+                    .map_err(cancelled)?,
+            );
+        }
+
+        let mut result = spaces.as_bdd().clone();
+        let mut done = false;
+        let mut steps = 0usize;
+        while !done {
+            steps += 1;
+            self.report(&ProgressEvent {
+                target: TARGET_PERCOLATE_SYMBOLIC,
+                step: steps,
+                bdd_size: result.size(),
+                candidates: result.cardinality(),
+            });
+
+            done = true;
+            for var in graph.variables() {
+                is_cancelled!(self, || result.clone())?;
+
+                let t_var = ctx.get_positive_variable(var);
+                let f_var = ctx.get_negative_variable(var);
+                let has_true = &can_be_true[var.to_index()];
+                let has_false = &can_be_false[var.to_index()];
+
+                // Spaces where `var` is free, but its update function is constant. Spaces that
+                // contain no states (invalid encoding) are never percolated.
+                let free = bdd!(bdd_vars, t_var & f_var).and(&result);
+                let to_true = bdd!(bdd_vars, free & (has_true & (!has_false)));
+                let to_false = bdd!(bdd_vars, free & (has_false & (!has_true)));
+                if to_true.is_false() && to_false.is_false() {
+                    continue;
+                }
+
+                let fixed_true = to_true.var_exists(f_var).and(&bdd!(bdd_vars, !f_var));
+                let fixed_false = to_false.var_exists(t_var).and(&bdd!(bdd_vars, !t_var));
+                result = result
+                    .and_not(&to_true)
+                    .and_not(&to_false)
+                    .or(&fixed_true)
+                    .or(&fixed_false);
+                done = false;
+
+                trace!(
+                    target: TARGET_PERCOLATE_SYMBOLIC,
+                    "Percolated variable {:?}: {}[nodes:{}].",
+                    var,
+                    result.cardinality(),
+                    result.size(),
+                );
+            }
+
+            debug!(
+                target: TARGET_PERCOLATE_SYMBOLIC,
+                "Finished step {}: {}[nodes:{}].",
+                steps,
+                result.cardinality(),
+                result.size(),
+            );
+        }
+
+        info!(
+            target: TARGET_PERCOLATE_SYMBOLIC,
+            "Done. Result: {} colored spaces.",
+            result.cardinality()
+        );
+        Ok(NetworkColoredSpaces::new(result, ctx))
+    }
+
+    /// Propagate the values of the `fixed` variables until no other variable can be fixed.
+    /// Values that are already fixed are never overwritten.
+    fn percolate_fixed(
+        &self,
+        fixed: &mut [Option<bool>],
+        target: &'static str,
+    ) -> Result<(), PercolationError> {
         let graph = &self.config().graph;
 
         let mut network_variables =
@@ -76,32 +329,36 @@ impl Percolation {
             network_variables[bdd_var.to_index()] = Some(var);
         }
 
-        // Variables that have a known fixed value.
-        let mut fixed: Vec<Option<bool>> = vec![None; graph.num_vars()];
-        for (var, v) in &subspace {
-            fixed[var.to_index()] = Some(*v);
-        }
-
         let mut fns: Vec<Option<Bdd>> = vec![None; graph.num_vars()];
         let mut fn_inputs: Vec<Option<HashSet<BddVariable>>> = vec![None; graph.num_vars()];
 
         let mut restriction = Vec::new();
 
         let mut done = false;
+        let mut steps = 0usize;
         while !done {
+            let fixed_count = fixed.iter().filter(|v| v.is_some()).count();
             debug!(
-                target: TARGET_PERCOLATE_SUBSPACE,
+                target: target,
                 "Currently found {} fixed variables.",
-                fixed.iter().filter(|v| v.is_some()).count()
+                fixed_count
             );
 
+            steps += 1;
+            self.report(&ProgressEvent {
+                target,
+                step: steps,
+                bdd_size: 0,
+                candidates: (fixed.len() - fixed_count) as f64,
+            });
+
             done = true;
             for i in 0..graph.num_vars() {
                 if fixed[i].is_some() {
                     continue;
                 }
 
-                is_cancelled!(self, || { fixed.clone() })?;
+                is_cancelled!(self, || { fixed.to_vec() })?;
 
                 if fns[i].is_none() {
                     let fn_bdd = graph.get_symbolic_fn_update(VariableId::from_index(i));
@@ -111,7 +368,7 @@ impl Percolation {
                 let fn_bdd = fns[i].as_mut().unwrap();
 
                 trace!(
-                    target: TARGET_PERCOLATE_SUBSPACE,
+                    target: target,
                     "Checking fn_bdd with index {} and value: ",
                     i,
                 );
@@ -141,7 +398,7 @@ impl Percolation {
                         }
 
                         if restriction.is_empty() {
-                            trace!(target: TARGET_PERCOLATE_SUBSPACE, " > skipped");
+                            trace!(target: target, " > skipped");
                             continue;
                         }
 
@@ -150,34 +407,29 @@ impl Percolation {
                             (true, _) => true,
                             (_, true) => false,
                             _ => {
-                                trace!(target: TARGET_PERCOLATE_SUBSPACE, " > skipped");
+                                trace!(target: target, " > skipped");
                                 continue;
                             }
                         }
                     }
                 };
 
-                trace!(target: TARGET_PERCOLATE_SUBSPACE, " > {}", value);
+                trace!(target: target, " > {}", value);
 
                 done = false;
                 fixed[i] = Some(value);
             }
         }
 
-        let result: Vec<_> = fixed
-            .iter()
-            .enumerate()
-            .filter_map(|(i, v)| {
-                if let Some(v) = v.as_ref() {
-                    let var = VariableId::from_index(i);
-                    Some((var, *v))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        info!(target: TARGET_PERCOLATE_SUBSPACE, "Done. Result: {} fixed variables.", result.len());
-        Ok(result)
+        Ok(())
     }
 }
+
+/// Convert a vector of (optionally) fixed values into a list of fixed variables.
+fn fixed_to_subspace(fixed: &[Option<bool>]) -> Vec<(VariableId, bool)> {
+    fixed
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (VariableId::from_index(i), v)))
+        .collect()
+}
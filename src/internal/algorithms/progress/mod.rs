@@ -0,0 +1,7 @@
+mod progress_event;
+mod progress_reporter;
+mod reporters;
+
+pub use progress_event::ProgressEvent;
+pub use progress_reporter::ProgressReporter;
+pub use reporters::ProgressReporterNever;
@@ -0,0 +1,24 @@
+/// A single progress update of a long-running algorithm.
+///
+/// The events are reported at the same places where the algorithms log their intermediate
+/// results, so the frequency of the events roughly corresponds to the frequency of the
+/// debug/trace log messages.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressEvent<'a> {
+    /// The algorithm that reported the event. This is the same as the `log` target
+    /// of the algorithm, e.g. `Reachability::forward_closed_superset`.
+    pub target: &'a str,
+
+    /// The number of steps performed so far. The exact meaning of a step depends on the
+    /// algorithm, but the value is non-decreasing within a single run of the algorithm.
+    pub step: usize,
+
+    /// The BDD size of the current intermediate result, or `0` if the algorithm does not
+    /// maintain a symbolic intermediate result (e.g. percolation).
+    pub bdd_size: usize,
+
+    /// The (approximate) number of elements in the current intermediate result. Depending
+    /// on the algorithm, these are the reached states, the remaining candidate states
+    /// (or spaces), or the remaining free variables.
+    pub candidates: f64,
+}
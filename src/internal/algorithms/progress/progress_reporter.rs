@@ -0,0 +1,28 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use dyn_clone::{DynClone, clone_trait_object};
+
+use crate::internal::algorithms::progress::{ProgressEvent, ProgressReporterNever};
+
+pub trait ProgressReporter: Send + Sync + DynClone {
+    /// Called by the algorithm every time it makes progress.
+    ///
+    /// The method is called from the thread that runs the algorithm, so it should return
+    /// quickly. Any expensive processing (e.g. UI updates) should be throttled or moved
+    /// to a different thread.
+    fn report(&self, event: &ProgressEvent);
+}
+
+impl Default for Box<dyn ProgressReporter> {
+    fn default() -> Self {
+        Box::new(ProgressReporterNever)
+    }
+}
+
+impl Debug for Box<dyn ProgressReporter> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("Box<dyn ProgressReporter>").finish()
+    }
+}
+
+clone_trait_object!(ProgressReporter);
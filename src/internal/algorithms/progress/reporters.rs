@@ -0,0 +1,13 @@
+use crate::internal::algorithms::progress::{ProgressEvent, ProgressReporter};
+
+/* Never - Start */
+
+/// An implementation of [ProgressReporter] that ignores all events.
+#[derive(Copy, Clone, Debug)]
+pub struct ProgressReporterNever;
+
+impl ProgressReporter for ProgressReporterNever {
+    fn report(&self, _event: &ProgressEvent) {}
+}
+
+/* Never - End */
@@ -1,8 +1,10 @@
 mod reachability_config;
 mod reachability_error;
 mod reachability_impl;
+mod reachability_strategy;
 
 pub use reachability_config::ReachabilityConfig;
-pub use reachability_error::ReachabilityError;
+pub use reachability_error::{FromReachabilityError, ReachabilityError};
 #[allow(unused_imports)]
 pub use reachability_impl::Reachability;
+pub use reachability_strategy::ReachabilityStrategy;
@@ -5,65 +5,74 @@ use biodivine_lib_param_bn::{
     symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
 };
 use macros::Config;
+use pyo3::pyclass;
 
-use crate::{
-    internal::algorithms::{cancellation::CancellationHandler, configurable::Config},
-    maybe_pyclass,
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
 };
 
-use super::ReachabilityError;
-
-maybe_pyclass!(
-    "ReachabilityConfig",
-    /// A configuration struct for the [Reachability] algorithms.
-    #[derive(Clone, Config)]
-    pub struct ReachabilityConfig {
-        /// The symbolic graph that will be used to compute the successors and predecessors of
-        /// individual states.
-        pub graph: SymbolicAsyncGraph,
-
-        /// Restricts the reachability operation to the given set of vertices. This also includes
-        /// edges! For example, if a vertex `x` only has outgoing edges into vertices outside the
-        /// `subgraph`, it would be considered a fixed-point.
-        ///
-        /// The initial set must be a subset of the subgraph vertices.
-        ///
-        /// Default: `None`.
-        pub subgraph: Option<GraphColoredVertices>,
-
-        /// Specifies the set of variables that can be updated by the reachability process.
-        /// Remaining variables stay constant, because they are never updated.
-        ///
-        /// This can be used to implement "reachability within a subspace" that is faster than
-        /// providing a `subgraph`, since the variables that are constant in the subspace never
-        /// need to be updated. Alternatively, this can be used for various "multi-stage"
-        /// schemes, for example to start with only a small component of the whole network and
-        /// then gradually expand to the whole variable set.
-        ///
-        /// Default: `graph.network_variables()`.
-        pub variables: HashSet<VariableId>,
-
-        /// A `CancellationHandler` that can be used to stop the algorithm externally.
-        ///
-        /// Default: [CancelTokenNever].
-        pub cancellation: Box<dyn CancellationHandler>,
-
-        /// The maximum BDD size of the reachable set.
-        ///
-        /// Note that the algorithm can use other auxiliary BDDs that do not
-        /// count towards this limit.
-        ///
-        /// Default: `usize::MAX`.
-        pub bdd_size_limit: usize,
-
-        /// The maximum number of steps that the algorithm can take before terminating.
-        ///
-        /// A step is a single extension or reduction of the reachable set of vertices.
-        ///
-        /// Default: `usize::MAX`.
-        pub steps_limit: usize,
-    }
-);
+use super::{ReachabilityError, ReachabilityStrategy};
+
+/// A configuration struct for the [Reachability] algorithms.
+#[pyclass(name = "ReachabilityConfig", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Config)]
+pub struct ReachabilityConfig {
+    /// The symbolic graph that will be used to compute the successors and predecessors of
+    /// individual states.
+    pub graph: SymbolicAsyncGraph,
+
+    /// Restricts the reachability operation to the given set of vertices. This also includes
+    /// edges! For example, if a vertex `x` only has outgoing edges into vertices outside the
+    /// `subgraph`, it would be considered a fixed-point.
+    ///
+    /// The initial set must be a subset of the subgraph vertices.
+    ///
+    /// Default: `None`.
+    pub subgraph: Option<GraphColoredVertices>,
+
+    /// Specifies the set of variables that can be updated by the reachability process.
+    /// Remaining variables stay constant, because they are never updated.
+    ///
+    /// This can be used to implement "reachability within a subspace" that is faster than
+    /// providing a `subgraph`, since the variables that are constant in the subspace never
+    /// need to be updated. Alternatively, this can be used for various "multi-stage"
+    /// schemes, for example to start with only a small component of the whole network and
+    /// then gradually expand to the whole variable set.
+    ///
+    /// Default: `graph.network_variables()`.
+    pub variables: HashSet<VariableId>,
+
+    /// The order in which the transitions of individual variables are applied.
+    /// See [ReachabilityStrategy] for the available options.
+    ///
+    /// Default: [ReachabilityStrategy::Saturation].
+    pub strategy: ReachabilityStrategy,
+
+    /// A `CancellationHandler` that can be used to stop the algorithm externally.
+    ///
+    /// Default: [CancelTokenNever].
+    pub cancellation: Box<dyn CancellationHandler>,
+
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
+    /// The maximum BDD size of the reachable set.
+    ///
+    /// Note that the algorithm can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub bdd_size_limit: usize,
+
+    /// The maximum number of steps that the algorithm can take before terminating.
+    ///
+    /// A step is a single extension or reduction of the reachable set of vertices.
+    ///
+    /// Default: `usize::MAX`.
+    pub steps_limit: usize,
+}
 
 impl From<SymbolicAsyncGraph> for ReachabilityConfig {
     /// Create a new "default" [ReachabilityConfig] from the given [SymbolicAsyncGraph].
@@ -71,7 +80,9 @@ impl From<SymbolicAsyncGraph> for ReachabilityConfig {
         ReachabilityConfig {
             variables: HashSet::from_iter(graph.variables()),
             subgraph: None,
+            strategy: ReachabilityStrategy::default(),
             cancellation: Default::default(),
+            progress: Default::default(),
             bdd_size_limit: usize::MAX,
             steps_limit: usize::MAX,
             graph,
@@ -105,6 +116,12 @@ impl ReachabilityConfig {
         self
     }
 
+    /// Update the `strategy` property.
+    pub fn with_strategy(mut self, strategy: ReachabilityStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Update the `bdd_size_limit` property.
     pub fn with_bdd_size_limit(mut self, bdd_size_limit: usize) -> Self {
         self.bdd_size_limit = bdd_size_limit;
@@ -125,4 +142,23 @@ impl ReachabilityConfig {
         variables.sort();
         variables
     }
+
+    /// Return the variables in the order in which they should be tried by the
+    /// reachability process, as prescribed by the `strategy`.
+    pub fn ordered_variables(&self) -> Vec<VariableId> {
+        let mut default_order = self.sorted_variables();
+        default_order.reverse();
+
+        let ReachabilityStrategy::CustomOrder(order) = &self.strategy else {
+            return default_order;
+        };
+
+        let mut seen = HashSet::with_capacity(default_order.len());
+        order
+            .iter()
+            .chain(default_order.iter())
+            .filter(|var| self.variables.contains(*var) && seen.insert(**var))
+            .copied()
+            .collect()
+    }
 }
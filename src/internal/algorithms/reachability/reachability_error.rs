@@ -56,3 +56,30 @@ impl From<CancellationError<GraphColoredVertices>> for ReachabilityError {
         ReachabilityError::Cancelled(error_value.into_partial_data())
     }
 }
+
+/// Implemented by errors of algorithms that use [Reachability] as a sub-procedure, such that
+/// a [ReachabilityError] can be converted into them with the partial result `P` of the calling
+/// algorithm (the partial result of the reachability procedure itself is discarded).
+pub trait FromReachabilityError<P: Debug + 'static>: From<CancellationError<P>> {
+    fn creation_failed(error: String) -> Self;
+
+    fn steps_limit_exceeded(partial: P) -> Self;
+
+    fn bdd_size_limit_exceeded(partial: P) -> Self;
+
+    /// Convert the given [ReachabilityError] into an error with the `partial` result.
+    ///
+    /// An [ReachabilityError::InvalidSubgraph] means the sub-procedure was configured
+    /// incorrectly, and is therefore reported as a creation failure.
+    fn from_reachability_error(error: ReachabilityError, partial: P) -> Self {
+        match error {
+            ReachabilityError::CreationFailed(error) => Self::creation_failed(error),
+            ReachabilityError::InvalidSubgraph => Self::creation_failed(error.to_string()),
+            ReachabilityError::Cancelled(_) => {
+                Self::from(CancellationError::with_partial_data(partial))
+            }
+            ReachabilityError::StepsLimitExceeded(_) => Self::steps_limit_exceeded(partial),
+            ReachabilityError::BddSizeLimitExceeded(_) => Self::bdd_size_limit_exceeded(partial),
+        }
+    }
+}
@@ -1,33 +1,39 @@
 use biodivine_lib_param_bn::{
-    BooleanNetwork,
+    BooleanNetwork, VariableId,
     biodivine_std::traits::Set,
     symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
 };
 use log::{info, trace};
 use macros::Configurable;
+use pyo3::pyclass;
 
 use crate::{
     debug_with_limit,
-    internal::algorithms::{cancellation::CancellationHandler, configurable::Configurable},
-    is_cancelled, maybe_pyclass,
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
+    is_cancelled,
 };
 
-use super::{ReachabilityConfig, ReachabilityError};
+use super::{ReachabilityConfig, ReachabilityError, ReachabilityStrategy};
 
 const TARGET_FORWARD_SUPERSET: &str = "Reachability::forward_closed_superset";
 const TARGET_FORWARD_SUBSET: &str = "Reachability::forward_closed_subset";
 const TARGET_BACKWARD_SUPERSET: &str = "Reachability::backward_closed_superset";
 const TARGET_BACKWARD_SUBSET: &str = "Reachability::backward_closed_subset";
-
-maybe_pyclass!(
-    "ReachabilityComp",
-    /// Implements symbolic reachability operations over a [SymbolicAsyncGraph]. This means the
-    /// computation of both the largest and the smallest forward- or backward-closed sets of states.
-    ///
-    /// See [ReachabilityConfig] and [ReachabilityError] for more info.
-    #[derive(Clone, Configurable)]
-    pub struct Reachability(pub ReachabilityConfig);
-);
+const TARGET_FORWARD_LAYERS: &str = "Reachability::forward_layers";
+const TARGET_BACKWARD_LAYERS: &str = "Reachability::backward_layers";
+const TARGET_WITNESS_PATH: &str = "Reachability::witness_path";
+
+/// Implements symbolic reachability operations over a [SymbolicAsyncGraph]. This means the
+/// computation of both the largest and the smallest forward- or backward-closed sets of states.
+///
+/// See [ReachabilityConfig] and [ReachabilityError] for more info.
+#[pyclass(name = "ReachabilityComp", module = "biodivine_aeon", frozen)]
+#[derive(Clone, Configurable)]
+pub struct Reachability(pub ReachabilityConfig);
 
 impl From<SymbolicAsyncGraph> for Reachability {
     /// Create a new [Reachability] instance from the given [SymbolicAsyncGraph]
@@ -55,277 +61,630 @@ impl Reachability {
         &self,
         initial: &GraphColoredVertices,
     ) -> Result<GraphColoredVertices, ReachabilityError> {
-        self.start_timer();
-        info!(target: TARGET_FORWARD_SUPERSET, "Started with {} initial states.", initial.exact_cardinality());
+        let graph = &self.config().graph;
+        let subgraph = self.config().subgraph.as_ref();
+
+        self.reach(
+            TARGET_FORWARD_SUPERSET,
+            initial,
+            Update::Expand,
+            "successors",
+            |var, result| {
+                let successors = graph.var_post_out(var, result);
+                match subgraph {
+                    Some(subgraph) => successors.intersect(subgraph),
+                    None => successors,
+                }
+            },
+        )
+    }
 
-        let mut result = initial.clone();
+    /// Compute the *greatest superset* of the given `initial` set that is backward closed.
+    ///
+    /// Intuitively, these are all the vertices that can reach a vertex in the `initial` set.
+    pub fn backward_closed_superset(
+        &self,
+        initial: &GraphColoredVertices,
+    ) -> Result<GraphColoredVertices, ReachabilityError> {
+        let graph = &self.config().graph;
+        let subgraph = self.config().subgraph.as_ref();
+
+        self.reach(
+            TARGET_BACKWARD_SUPERSET,
+            initial,
+            Update::Expand,
+            "predecessors",
+            |var, result| {
+                let predecessors = graph.var_pre_out(var, result);
+                match subgraph {
+                    Some(subgraph) => predecessors.intersect(subgraph),
+                    None => predecessors,
+                }
+            },
+        )
+    }
 
-        let variables = self.config().sorted_variables();
+    /// Compute the *greatest subset* of the given `initial` set that is forward closed.
+    ///
+    /// Intuitively, this removes all vertices that can reach a vertex outside the `initial`
+    /// set.
+    pub fn forward_closed_subset(
+        &self,
+        initial: &GraphColoredVertices,
+    ) -> Result<GraphColoredVertices, ReachabilityError> {
         let graph = &self.config().graph;
-        let subgraph = &self.config().subgraph;
+        let subgraph = self.config().subgraph.as_ref();
+
+        self.reach(
+            TARGET_FORWARD_SUBSET,
+            initial,
+            Update::Reduce,
+            "leaving vertices",
+            |var, result| {
+                let can_go_out = graph.var_can_post_out(var, result);
+                match subgraph {
+                    // The vertex can only escape if the successor is also
+                    // in the prescribed subgraph.
+                    // TODO: Cache this set.
+                    Some(subgraph) => can_go_out.minus(&graph.var_can_post_out(var, subgraph)),
+                    None => can_go_out,
+                }
+            },
+        )
+    }
 
-        if let Some(subgraph) = subgraph {
-            if !initial.is_subset(subgraph) {
-                info!(target: TARGET_FORWARD_SUPERSET, "Initial set is not a subset of the subgraph.");
-                return Err(ReachabilityError::InvalidSubgraph);
-            }
-        }
+    /// Compute the *greatest subset* of the given `initial` set that is backward closed.
+    ///
+    /// Intuitively, this removes all vertices that can be reached by a vertex outside
+    /// the `initial` set.
+    pub fn backward_closed_subset(
+        &self,
+        initial: &GraphColoredVertices,
+    ) -> Result<GraphColoredVertices, ReachabilityError> {
+        let graph = &self.config().graph;
+        let subgraph = self.config().subgraph.as_ref();
+
+        self.reach(
+            TARGET_BACKWARD_SUBSET,
+            initial,
+            Update::Reduce,
+            "leaving vertices",
+            |var, result| {
+                let has_predecessor_outside = graph.var_can_pre_out(var, result);
+                match subgraph {
+                    // The predecessor is only relevant if it exists within the subgraph.
+                    // TODO: Cache this set.
+                    Some(subgraph) => {
+                        has_predecessor_outside.minus(&graph.var_can_pre_out(var, subgraph))
+                    }
+                    None => has_predecessor_outside,
+                }
+            },
+        )
+    }
+}
 
-        let mut steps = 0usize;
+impl Reachability {
+    /// Compute the forward distance layers of the given `initial` set. The first layer is
+    /// the `initial` set itself, and the `i`-th layer contains the vertices that are reachable
+    /// from `initial` in exactly `i` transitions, but not fewer.
+    ///
+    /// If `max_depth` is given, at most `max_depth + 1` layers are computed, i.e. the union
+    /// of the result contains the vertices reachable in at most `max_depth` transitions.
+    ///
+    /// If the computation fails, the error contains the union of the layers computed so far.
+    /// Note that the `strategy` of the config does not apply here, since each layer is
+    /// always computed as a full one-step image.
+    pub fn forward_layers(
+        &self,
+        initial: &GraphColoredVertices,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<GraphColoredVertices>, ReachabilityError> {
+        let graph = &self.config().graph;
+        self.layers(
+            TARGET_FORWARD_LAYERS,
+            initial,
+            max_depth,
+            None,
+            |var, frontier| graph.var_post_out(var, frontier),
+        )
+    }
 
-        'reach: loop {
-            for var in variables.iter().rev() {
-                is_cancelled!(self, || { result.clone() })?;
+    /// Compute the backward distance layers of the given `initial` set. The first layer is
+    /// the `initial` set itself, and the `i`-th layer contains the vertices that can reach
+    /// `initial` in exactly `i` transitions, but not fewer.
+    ///
+    /// See [Self::forward_layers] for the meaning of `max_depth` and the partial results.
+    pub fn backward_layers(
+        &self,
+        initial: &GraphColoredVertices,
+        max_depth: Option<usize>,
+    ) -> Result<Vec<GraphColoredVertices>, ReachabilityError> {
+        let graph = &self.config().graph;
+        self.layers(
+            TARGET_BACKWARD_LAYERS,
+            initial,
+            max_depth,
+            None,
+            |var, frontier| graph.var_pre_out(var, frontier),
+        )
+    }
 
-                let mut successors = graph.var_post_out(*var, &result);
-                if let Some(subgraph) = self.config().subgraph.as_ref() {
-                    successors = successors.intersect(subgraph)
-                }
+    /// Compute a shortest path from `source` to a vertex in `target`, such that the path is
+    /// valid for a single color.
+    ///
+    /// The result contains the singleton vertex-color sets along the path (the first one is
+    /// a subset of `source`, the last one is a subset of `target`), and the variables that are
+    /// updated by the individual transitions. If `source` contains multiple vertex-color pairs,
+    /// the path starts in one of the pairs that reach `target` the fastest.
+    ///
+    /// If no vertex in `target` is reachable from `source`, the result is `None`. If the
+    /// computation is cancelled, the error contains the vertices reached so far.
+    pub fn witness_path(
+        &self,
+        source: &GraphColoredVertices,
+        target: &GraphColoredVertices,
+    ) -> Result<Option<(Vec<GraphColoredVertices>, Vec<VariableId>)>, ReachabilityError> {
+        let graph = &self.config().graph;
+        let layers = self.layers(
+            TARGET_WITNESS_PATH,
+            source,
+            None,
+            Some(target),
+            |var, frontier| graph.var_post_out(var, frontier),
+        )?;
+
+        let last_layer = layers.last().expect("There is always at least one layer.");
+        let reached = last_layer.intersect(target);
+        if reached.is_empty() {
+            info!(target: TARGET_WITNESS_PATH, "No target state is reachable.");
+            return Ok(None);
+        }
 
-                trace!(target: TARGET_FORWARD_SUPERSET, "Found {} successors for {:?}", successors.approx_cardinality(), var);
+        // Walk back through the layers, always picking one predecessor of the current vertex
+        // (for the same color) in the previous layer.
+        let variables = self.config().ordered_variables();
+        let mut current = reached.pick_singleton();
+        let mut path = vec![current.clone()];
+        let mut path_variables = Vec::new();
+        for layer in layers.iter().rev().skip(1) {
+            is_cancelled!(self, || { union_all(graph, &path) })?;
+
+            let (var, predecessor) = variables
+                .iter()
+                .find_map(|var| {
+                    let predecessors = graph.var_pre(*var, &current).intersect(layer);
+                    (!predecessors.is_empty()).then(|| (*var, predecessors.pick_singleton()))
+                })
+                .expect("Every vertex in a layer has a predecessor in the previous layer.");
+
+            current = predecessor;
+            path.push(current.clone());
+            path_variables.push(var);
+        }
 
-                if !successors.is_empty() {
-                    result = result.union(&successors);
-                    steps += 1;
+        path.reverse();
+        path_variables.reverse();
 
-                    debug_with_limit!(
-                        target: TARGET_FORWARD_SUPERSET,
-                        size: result.symbolic_size(),
-                        "Expanded result to {}[bdd_nodes:{}].",
-                        result.approx_cardinality(),
-                        result.symbolic_size()
-                    );
+        info!(target: TARGET_WITNESS_PATH, "Done. Found path of length {}.", path_variables.len());
+        Ok(Some((path, path_variables)))
+    }
+}
 
-                    if result.as_bdd().size() > self.config().bdd_size_limit {
-                        info!(target: TARGET_FORWARD_SUPERSET, "Exceeded BDD size limit.");
-                        return Err(ReachabilityError::BddSizeLimitExceeded(result));
-                    }
+/// The union of all the given `sets`.
+fn union_all(graph: &SymbolicAsyncGraph, sets: &[GraphColoredVertices]) -> GraphColoredVertices {
+    sets.iter()
+        .fold(graph.mk_empty_colored_vertices(), |a, b| a.union(b))
+}
 
-                    if steps > self.config().steps_limit {
-                        info!(target: TARGET_FORWARD_SUPERSET, "Exceeded step limit.");
-                        return Err(ReachabilityError::StepsLimitExceeded(result));
-                    }
+/// Describes how the set computed by one step of the reachability process is combined
+/// with the current result.
+#[derive(Clone, Copy)]
+enum Update {
+    /// The step computes new vertices that are added to the result.
+    Expand,
+    /// The step computes vertices that are removed from the result.
+    Reduce,
+}
 
-                    // Restart the loop.
-                    continue 'reach;
-                }
-            }
+impl Update {
+    fn apply(
+        self,
+        result: &GraphColoredVertices,
+        stepped: &GraphColoredVertices,
+    ) -> GraphColoredVertices {
+        match self {
+            Update::Expand => result.union(stepped),
+            Update::Reduce => result.minus(stepped),
+        }
+    }
 
-            info!(target: TARGET_FORWARD_SUPERSET, "Done. Result: {} states.", result.exact_cardinality());
-            return Ok(result);
+    fn name(self) -> &'static str {
+        match self {
+            Update::Expand => "Expanded",
+            Update::Reduce => "Reduced",
         }
     }
+}
 
-    /// Compute the *greatest superset* of the given `initial` set that is backward closed.
-    ///
-    /// Intuitively, these are all the vertices that can reach a vertex in the `initial` set.
-    pub fn backward_closed_superset(
+impl Reachability {
+    /// The shared implementation of all reachability operations. Starting with `initial`,
+    /// it repeatedly applies the `step` function (which computes the vertices that should
+    /// be added to or removed from the result for a single variable) according to the
+    /// configured [ReachabilityStrategy], until a fixed-point is reached.
+    fn reach<F>(
         &self,
+        target: &str,
         initial: &GraphColoredVertices,
-    ) -> Result<GraphColoredVertices, ReachabilityError> {
+        update: Update,
+        noun: &str,
+        step: F,
+    ) -> Result<GraphColoredVertices, ReachabilityError>
+    where
+        F: Fn(VariableId, &GraphColoredVertices) -> GraphColoredVertices,
+    {
         self.start_timer();
-        info!(target: TARGET_BACKWARD_SUPERSET, "Started with {} initial states.", initial.exact_cardinality());
-
-        let mut result = initial.clone();
+        info!(target: target, "Started with {} initial states.", initial.exact_cardinality());
 
-        let variables = self.config().sorted_variables();
-        let graph = &self.config().graph;
-        let subgraph = &self.config().subgraph;
-
-        if let Some(subgraph) = subgraph {
+        if let Some(subgraph) = &self.config().subgraph {
             if !initial.is_subset(subgraph) {
-                info!(target: TARGET_BACKWARD_SUPERSET, "Initial set is not a subset of the subgraph.");
+                info!(target: target, "Initial set is not a subset of the subgraph.");
                 return Err(ReachabilityError::InvalidSubgraph);
             }
         }
 
+        let variables = self.config().ordered_variables();
+        let mut result = initial.clone();
         let mut steps = 0usize;
 
-        'reach: loop {
-            for var in variables.iter().rev() {
-                is_cancelled!(self, || { result.clone() })?;
+        match self.config().strategy {
+            ReachabilityStrategy::Saturation | ReachabilityStrategy::CustomOrder(_) => {
+                'reach: loop {
+                    for var in &variables {
+                        is_cancelled!(self, || { result.clone() })?;
 
-                let mut predecessors = graph.var_pre_out(*var, &result);
-                if let Some(subgraph) = self.config().subgraph.as_ref() {
-                    predecessors = predecessors.intersect(subgraph)
-                }
+                        let stepped = step(*var, &result);
+                        trace!(target: target, "Found {} {} for {:?}", stepped.approx_cardinality(), noun, var);
 
-                trace!(target: TARGET_BACKWARD_SUPERSET, "Found {} predecessors for {:?}", predecessors.approx_cardinality(), var);
+                        if !stepped.is_empty() {
+                            result = update.apply(&result, &stepped);
+                            self.check_step(target, update, &result, &mut steps)?;
 
-                if !predecessors.is_empty() {
-                    result = result.union(&predecessors);
-                    steps += 1;
-
-                    debug_with_limit!(
-                        target: TARGET_BACKWARD_SUPERSET,
-                        size: result.symbolic_size(),
-                        "Expanded result to {}[bdd_nodes:{}].",
-                        result.approx_cardinality(),
-                        result.symbolic_size()
-                    );
-
-                    if result.as_bdd().size() > self.config().bdd_size_limit {
-                        info!(target: TARGET_BACKWARD_SUPERSET, "Exceeded BDD size limit.");
-                        return Err(ReachabilityError::BddSizeLimitExceeded(result));
+                            // Restart the loop.
+                            continue 'reach;
+                        }
                     }
 
-                    if steps > self.config().steps_limit {
-                        info!(target: TARGET_BACKWARD_SUPERSET, "Exceeded step limit.");
-                        return Err(ReachabilityError::StepsLimitExceeded(result));
+                    break;
+                }
+            }
+            ReachabilityStrategy::Chaining => loop {
+                let mut changed = false;
+                for var in &variables {
+                    is_cancelled!(self, || { result.clone() })?;
+
+                    let stepped = step(*var, &result);
+                    trace!(target: target, "Found {} {} for {:?}", stepped.approx_cardinality(), noun, var);
+
+                    if !stepped.is_empty() {
+                        result = update.apply(&result, &stepped);
+                        self.check_step(target, update, &result, &mut steps)?;
+                        changed = true;
                     }
+                }
 
-                    // Restart the loop.
-                    continue 'reach;
+                if !changed {
+                    break;
+                }
+            },
+            ReachabilityStrategy::Bfs => loop {
+                let mut stepped = self.config().graph.mk_empty_colored_vertices();
+                for var in &variables {
+                    is_cancelled!(self, || { result.clone() })?;
+                    stepped = stepped.union(&step(*var, &result));
+                }
+
+                trace!(target: target, "Found {} {} in total", stepped.approx_cardinality(), noun);
+
+                if stepped.is_empty() {
+                    break;
                 }
-            }
 
-            info!(target: TARGET_BACKWARD_SUPERSET, "Done. Result: {} states.", result.exact_cardinality());
-            return Ok(result);
+                result = update.apply(&result, &stepped);
+                self.check_step(target, update, &result, &mut steps)?;
+            },
         }
+
+        info!(target: target, "Done. Result: {} states.", result.exact_cardinality());
+        Ok(result)
     }
 
-    /// Compute the *greatest subset* of the given `initial` set that is forward closed.
-    ///
-    /// Intuitively, this removes all vertices that can reach a vertex outside the `initial`
-    /// set.
-    pub fn forward_closed_subset(
+    /// The shared implementation of [Self::forward_layers] and [Self::backward_layers]. The
+    /// `step` function computes the one-step image of the current frontier for a single
+    /// variable. If `until` is given, the computation stops as soon as the last layer
+    /// intersects it.
+    fn layers<F>(
         &self,
+        target: &str,
         initial: &GraphColoredVertices,
-    ) -> Result<GraphColoredVertices, ReachabilityError> {
+        max_depth: Option<usize>,
+        until: Option<&GraphColoredVertices>,
+        step: F,
+    ) -> Result<Vec<GraphColoredVertices>, ReachabilityError>
+    where
+        F: Fn(VariableId, &GraphColoredVertices) -> GraphColoredVertices,
+    {
         self.start_timer();
-        info!(target: TARGET_FORWARD_SUBSET, "Started with {} initial states.", initial.exact_cardinality());
-
-        let mut result = initial.clone();
-
-        let variables = self.config().sorted_variables();
-        let graph = &self.config().graph;
-        let subgraph = &self.config().subgraph;
+        info!(target: target, "Started with {} initial states.", initial.exact_cardinality());
 
+        let subgraph = self.config().subgraph.as_ref();
         if let Some(subgraph) = subgraph {
             if !initial.is_subset(subgraph) {
-                info!(target: TARGET_FORWARD_SUBSET, "Initial set is not a subset of the subgraph.");
+                info!(target: target, "Initial set is not a subset of the subgraph.");
                 return Err(ReachabilityError::InvalidSubgraph);
             }
         }
 
+        let variables = self.config().ordered_variables();
+        let mut visited = initial.clone();
+        let mut layers = vec![initial.clone()];
         let mut steps = 0usize;
 
-        'reach: loop {
-            for var in variables.iter().rev() {
-                is_cancelled!(self, || { result.clone() })?;
-
-                let mut can_go_out = graph.var_can_post_out(*var, &result);
-                if let Some(subgraph) = self.config().subgraph.as_ref() {
-                    // The vertex can only escape if the successor is also
-                    // in the prescribed subgraph.
-                    // TODO: Cache this set.
-                    let does_not_count = graph.var_can_post_out(*var, subgraph);
-                    can_go_out = can_go_out.minus(&does_not_count);
-                }
-
-                trace!(target: TARGET_FORWARD_SUBSET, "Found {} leaving vertices for {:?}", can_go_out.approx_cardinality(), var);
-
-                if !can_go_out.is_empty() {
-                    result = result.minus(&can_go_out);
-                    steps += 1;
+        while max_depth.is_none_or(|depth| layers.len() <= depth) {
+            let frontier = &layers[layers.len() - 1];
+            if until.is_some_and(|until| !frontier.intersect(until).is_empty()) {
+                break;
+            }
 
-                    debug_with_limit!(
-                        target: TARGET_FORWARD_SUBSET,
-                        size: result.symbolic_size(),
-                        "Reduced result to {}[bdd_nodes:{}].",
-                        result.approx_cardinality(),
-                        result.symbolic_size()
-                    );
+            let mut next = self.config().graph.mk_empty_colored_vertices();
+            for var in &variables {
+                is_cancelled!(self, || { visited.clone() })?;
+                next = next.union(&step(*var, frontier));
+            }
 
-                    if result.as_bdd().size() > self.config().bdd_size_limit {
-                        info!(target: TARGET_FORWARD_SUBSET, "Exceeded BDD size limit.");
-                        return Err(ReachabilityError::BddSizeLimitExceeded(result));
-                    }
+            if let Some(subgraph) = subgraph {
+                next = next.intersect(subgraph);
+            }
+            let next = next.minus(&visited);
 
-                    if steps > self.config().steps_limit {
-                        info!(target: TARGET_FORWARD_SUBSET, "Exceeded step limit.");
-                        return Err(ReachabilityError::StepsLimitExceeded(result));
-                    }
+            trace!(target: target, "Found {} new states in layer {}", next.approx_cardinality(), layers.len());
 
-                    // Restart the loop.
-                    continue 'reach;
-                }
+            if next.is_empty() {
+                break;
             }
 
-            info!(target: TARGET_FORWARD_SUBSET, "Done. Result: {} states.", result.exact_cardinality());
-            return Ok(result);
+            visited = visited.union(&next);
+            self.check_step(target, Update::Expand, &visited, &mut steps)?;
+            layers.push(next);
         }
+
+        info!(target: target, "Done. Result: {} layers, {} states.", layers.len(), visited.exact_cardinality());
+        Ok(layers)
     }
 
-    /// Compute the *greatest subset* of the given `initial` set that is backward closed.
-    ///
-    /// Intuitively, this removes all vertices that can be reached by a vertex outside
-    /// the `initial` set.
-    pub fn backward_closed_subset(
+    /// Count one successful step of the reachability process and check that `result`
+    /// is still within the configured limits.
+    fn check_step(
         &self,
-        initial: &GraphColoredVertices,
-    ) -> Result<GraphColoredVertices, ReachabilityError> {
-        self.start_timer();
-        info!(target: TARGET_BACKWARD_SUBSET, "Started with {} initial states.", initial.exact_cardinality());
+        target: &str,
+        update: Update,
+        result: &GraphColoredVertices,
+        steps: &mut usize,
+    ) -> Result<(), ReachabilityError> {
+        *steps += 1;
+
+        self.report(&ProgressEvent {
+            target,
+            step: *steps,
+            bdd_size: result.symbolic_size(),
+            candidates: result.approx_cardinality(),
+        });
+
+        debug_with_limit!(
+            target: target,
+            size: result.symbolic_size(),
+            "{} result to {}[bdd_nodes:{}].",
+            update.name(),
+            result.approx_cardinality(),
+            result.symbolic_size()
+        );
+
+        if result.as_bdd().size() > self.config().bdd_size_limit {
+            info!(target: target, "Exceeded BDD size limit.");
+            return Err(ReachabilityError::BddSizeLimitExceeded(result.clone()));
+        }
 
-        let mut result = initial.clone();
+        if *steps > self.config().steps_limit {
+            info!(target: target, "Exceeded step limit.");
+            return Err(ReachabilityError::StepsLimitExceeded(result.clone()));
+        }
 
-        let variables = self.config().sorted_variables();
-        let graph = &self.config().graph;
-        let subgraph = &self.config().subgraph;
+        Ok(())
+    }
+}
 
-        if let Some(subgraph) = subgraph {
-            if !initial.is_subset(subgraph) {
-                info!(target: TARGET_BACKWARD_SUBSET, "Initial set is not a subset of the subgraph.");
-                return Err(ReachabilityError::InvalidSubgraph);
-            }
+#[cfg(test)]
+mod tests {
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+
+    use crate::internal::algorithms::configurable::Configurable;
+    use crate::internal::algorithms::reachability::{
+        Reachability, ReachabilityConfig, ReachabilityStrategy,
+    };
+
+    #[test]
+    /// Test that all strategies reach the same fixed point for all four operations.
+    fn test_strategies_agree() {
+        let bn = BooleanNetwork::try_from_file("tests/model-myeloid-3-unknown.aeon").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let variables = graph.variables().collect::<Vec<_>>();
+
+        let pivot = graph.mk_unit_colored_vertices().pick_vertex();
+        let subspace = graph.mk_subspace(&[(variables[0], true), (variables[1], false)]);
+
+        let compute = |strategy: ReachabilityStrategy| {
+            let config = ReachabilityConfig::from(graph.clone()).with_strategy(strategy);
+            let reachability = Reachability::with_config(config);
+            [
+                reachability.forward_closed_superset(&pivot).unwrap(),
+                reachability.backward_closed_superset(&pivot).unwrap(),
+                reachability.forward_closed_subset(&subspace).unwrap(),
+                reachability.backward_closed_subset(&subspace).unwrap(),
+            ]
+        };
+
+        let expected = compute(ReachabilityStrategy::Saturation);
+        assert!(expected[0].is_subset(graph.unit_colored_vertices()));
+        assert!(pivot.is_subset(&expected[0]) && pivot.is_subset(&expected[1]));
+        assert!(expected[2].is_subset(&subspace) && expected[3].is_subset(&subspace));
+
+        // The custom order intentionally repeats variables and omits some of them.
+        let order = vec![variables[2], variables[0], variables[2]];
+        for strategy in [
+            ReachabilityStrategy::Bfs,
+            ReachabilityStrategy::Chaining,
+            ReachabilityStrategy::CustomOrder(order),
+        ] {
+            assert_eq!(compute(strategy), expected);
         }
+    }
 
-        let mut steps = 0usize;
-
-        'reach: loop {
-            for var in variables.iter().rev() {
-                is_cancelled!(self, || { result.clone() })?;
+    #[test]
+    /// Test that a custom order is completed by the remaining variables without duplicates.
+    fn test_custom_order() {
+        let bn = BooleanNetwork::try_from_file("tests/model-myeloid-3-unknown.aeon").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let variables = graph.variables().collect::<Vec<_>>();
+
+        let order = vec![variables[2], variables[0], variables[2]];
+        let config = ReachabilityConfig::from(graph.clone())
+            .with_strategy(ReachabilityStrategy::CustomOrder(order));
+        let ordered = config.ordered_variables();
+
+        assert_eq!(ordered.len(), variables.len());
+        assert_eq!(&ordered[..2], &[variables[2], variables[0]]);
+        let mut rest = variables.clone();
+        rest.retain(|it| *it != variables[0] && *it != variables[2]);
+        rest.reverse();
+        assert_eq!(&ordered[2..], rest.as_slice());
+    }
 
-                let mut has_predecessor_outside = graph.var_can_pre_out(*var, &result);
-                if let Some(subgraph) = self.config().subgraph.as_ref() {
-                    // The predecessor is only relevant if it exists within the subgraph.
-                    // TODO: Cache this set.
-                    let does_not_count = graph.var_can_pre_out(*var, subgraph);
-                    has_predecessor_outside = has_predecessor_outside.minus(&does_not_count);
+    #[test]
+    /// Test that layer `i` contains exactly the states at (forward or backward) distance `i`.
+    fn test_layers_distance() {
+        let bn = BooleanNetwork::try_from_file("tests/model-myeloid-3-unknown.aeon").unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let reachability = Reachability::from(graph.clone());
+        let pivot = graph.mk_unit_colored_vertices().pick_vertex();
+
+        let forward = reachability.forward_layers(&pivot, None).unwrap();
+        let backward = reachability.backward_layers(&pivot, None).unwrap();
+
+        for (layers, is_forward) in [(forward, true), (backward, false)] {
+            // Reference BFS using the one-step image of all variables at once.
+            let mut visited = pivot.clone();
+            let mut frontier = pivot.clone();
+            let mut expected = vec![pivot.clone()];
+            loop {
+                let image = if is_forward {
+                    graph.post(&frontier)
+                } else {
+                    graph.pre(&frontier)
+                };
+                frontier = image.minus(&visited);
+                if frontier.is_empty() {
+                    break;
                 }
+                visited = visited.union(&frontier);
+                expected.push(frontier.clone());
+            }
+            assert_eq!(layers, expected);
+
+            let closure = if is_forward {
+                reachability.forward_closed_superset(&pivot).unwrap()
+            } else {
+                reachability.backward_closed_superset(&pivot).unwrap()
+            };
+            assert_eq!(visited, closure);
+        }
+    }
 
-                trace!(target: TARGET_BACKWARD_SUBSET, "Found {} leaving vertices for {:?}", has_predecessor_outside.approx_cardinality(), var);
-
-                if !has_predecessor_outside.is_empty() {
-                    result = result.minus(&has_predecessor_outside);
-                    steps += 1;
+    #[test]
+    /// Test the layers of a simple cascade, including the `max_depth` limit.
+    fn test_layers_cascade() {
+        let bn = BooleanNetwork::try_from("a -> b\nb -> c\n$a: true\n$b: a\n$c: b\n").unwrap();
+        let var = |name: &str| bn.as_graph().find_variable(name).unwrap();
+        let (a, b, c) = (var("a"), var("b"), var("c"));
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let reachability = Reachability::from(graph.clone());
+
+        let source = graph.mk_subspace(&[(a, false), (b, false), (c, false)]);
+        let layers = reachability.forward_layers(&source, None).unwrap();
+        assert_eq!(
+            layers,
+            vec![
+                source.clone(),
+                graph.mk_subspace(&[(a, true), (b, false), (c, false)]),
+                graph.mk_subspace(&[(a, true), (b, true), (c, false)]),
+                graph.mk_subspace(&[(a, true), (b, true), (c, true)]),
+            ]
+        );
+
+        let limited = reachability.forward_layers(&source, Some(1)).unwrap();
+        assert_eq!(limited, layers[..2].to_vec());
+        let limited = reachability.forward_layers(&source, Some(0)).unwrap();
+        assert_eq!(limited, vec![source]);
+    }
 
-                    debug_with_limit!(
-                        target: TARGET_BACKWARD_SUBSET,
-                        size: result.symbolic_size(),
-                        "Reduced result to {}[bdd_nodes:{}].",
-                        result.approx_cardinality(),
-                        result.symbolic_size()
-                    );
+    #[test]
+    /// Test that the witness path follows the only possible sequence of transitions.
+    fn test_witness_path() {
+        let bn = BooleanNetwork::try_from("a -> b\nb -> c\n$a: true\n$b: a\n$c: b\n").unwrap();
+        let var = |name: &str| bn.as_graph().find_variable(name).unwrap();
+        let (a, b, c) = (var("a"), var("b"), var("c"));
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+
+        let source = graph.mk_subspace(&[(a, false), (b, false), (c, false)]);
+        let target = graph.mk_subspace(&[(c, true)]);
+
+        let reachability = Reachability::from(graph.clone());
+        let (path, variables) = reachability
+            .witness_path(&source, &target)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(variables, vec![a, b, c]);
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[0], source);
+        assert!(path[3].is_subset(&target));
+        for vertex in &path {
+            assert_eq!(vertex.approx_cardinality(), 1.0);
+        }
 
-                    if result.as_bdd().size() > self.config().bdd_size_limit {
-                        info!(target: TARGET_BACKWARD_SUBSET, "Exceeded BDD size limit.");
-                        return Err(ReachabilityError::BddSizeLimitExceeded(result));
-                    }
+        // The source itself is a valid (empty) path.
+        let (path, variables) = reachability
+            .witness_path(&source, &source)
+            .unwrap()
+            .unwrap();
+        assert_eq!(path, vec![source.clone()]);
+        assert!(variables.is_empty());
+    }
 
-                    if steps > self.config().steps_limit {
-                        info!(target: TARGET_BACKWARD_SUBSET, "Exceeded step limit.");
-                        return Err(ReachabilityError::StepsLimitExceeded(result));
-                    }
+    #[test]
+    /// Test that an unreachable target results in no path.
+    fn test_witness_path_unreachable() {
+        let bn = BooleanNetwork::try_from("a -> b\nb -> c\n$a: true\n$b: a\n$c: b\n").unwrap();
+        let var = |name: &str| bn.as_graph().find_variable(name).unwrap();
+        let (a, b, c) = (var("a"), var("b"), var("c"));
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
 
-                    // Restart the loop.
-                    continue 'reach;
-                }
-            }
+        // Once `a` is set, it can never be unset again.
+        let source = graph.mk_subspace(&[(a, true), (b, false), (c, false)]);
+        let target = graph.mk_subspace(&[(a, false)]);
 
-            info!(target: TARGET_BACKWARD_SUBSET, "Done. Result: {} states.", result.exact_cardinality());
-            return Ok(result);
-        }
+        let result = Reachability::from(graph).witness_path(&source, &target);
+        assert!(matches!(result, Ok(None)));
     }
 }
@@ -0,0 +1,30 @@
+use biodivine_lib_param_bn::VariableId;
+
+/// Determines the order in which variable transitions are applied by the [Reachability]
+/// algorithms.
+///
+/// All strategies compute the same result, but their performance can differ greatly
+/// depending on the network.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ReachabilityStrategy {
+    /// Variables are tried in descending order, and after every successful step, the
+    /// process restarts from the first variable. This tends to keep the intermediate
+    /// BDDs small.
+    #[default]
+    Saturation,
+
+    /// In every step, the transitions of all variables are applied at once, i.e.
+    /// each step computes the full (one-step) image of the current set.
+    Bfs,
+
+    /// Variables are tried in descending order, but the process does not restart
+    /// after a successful step. Instead, it continues with the next variable and
+    /// only terminates once a full pass over all variables makes no progress.
+    Chaining,
+
+    /// The same as [ReachabilityStrategy::Saturation], but the variables are tried in the
+    /// given order. Variables that are not part of the order are tried last (in
+    /// descending order), and variables that are not updated by the reachability
+    /// process are ignored.
+    CustomOrder(Vec<VariableId>),
+}
@@ -0,0 +1,7 @@
+mod scc_decomposition_config;
+mod scc_decomposition_error;
+mod scc_decomposition_impl;
+
+pub use scc_decomposition_config::SccDecompositionConfig;
+pub use scc_decomposition_error::SccDecompositionError;
+pub use scc_decomposition_impl::SccDecomposition;
@@ -0,0 +1,114 @@
+use biodivine_lib_param_bn::{
+    BooleanNetwork,
+    symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
+};
+use macros::Config;
+
+use crate::internal::algorithms::{
+    cancellation::CancellationHandler, configurable::Config, progress::ProgressReporter,
+    reachability::ReachabilityConfig,
+};
+
+use super::SccDecompositionError;
+
+/// A configuration struct for the [SccDecomposition] algorithms.
+#[derive(Clone, Config)]
+pub struct SccDecompositionConfig {
+    /// The symbolic graph that will be decomposed into strongly connected components.
+    pub graph: SymbolicAsyncGraph,
+
+    /// Restricts the decomposition to the given set of vertices. Only the edges within this
+    /// set are considered, i.e. the result are the SCCs of the sub-graph induced by
+    /// the `restriction`.
+    ///
+    /// Default: `graph.unit_colored_vertices()`.
+    pub restriction: GraphColoredVertices,
+
+    /// A `CancellationHandler` that can be used to stop the algorithm externally.
+    ///
+    /// Default: [CancelTokenNever].
+    pub cancellation: Box<dyn CancellationHandler>,
+
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
+    /// The maximum BDD size of the intermediate reachable sets.
+    ///
+    /// Note that the algorithm can use other auxiliary BDDs that do not
+    /// count towards this limit.
+    ///
+    /// Default: `usize::MAX`.
+    pub bdd_size_limit: usize,
+
+    /// The maximum number of steps of the forward (or backward) reachability procedure that
+    /// computes a single component from its pivot. The limit applies to every component
+    /// separately, i.e. the whole decomposition can perform more steps in total.
+    ///
+    /// Default: `usize::MAX`.
+    pub steps_limit: usize,
+}
+
+impl From<SymbolicAsyncGraph> for SccDecompositionConfig {
+    /// Create a new "default" [SccDecompositionConfig] from the given [SymbolicAsyncGraph].
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        SccDecompositionConfig {
+            restriction: graph.mk_unit_colored_vertices(),
+            cancellation: Default::default(),
+            progress: Default::default(),
+            bdd_size_limit: usize::MAX,
+            steps_limit: usize::MAX,
+            graph,
+        }
+    }
+}
+
+impl From<ReachabilityConfig> for SccDecompositionConfig {
+    /// Create a new [SccDecompositionConfig] that shares the `graph`, `subgraph`, limits,
+    /// cancellation and progress reporting of the given [ReachabilityConfig].
+    fn from(config: ReachabilityConfig) -> Self {
+        SccDecompositionConfig {
+            restriction: config
+                .subgraph
+                .unwrap_or_else(|| config.graph.mk_unit_colored_vertices()),
+            cancellation: config.cancellation,
+            progress: config.progress,
+            bdd_size_limit: config.bdd_size_limit,
+            steps_limit: config.steps_limit,
+            graph: config.graph,
+        }
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for SccDecompositionConfig {
+    type Error = SccDecompositionError;
+
+    /// Create a new "default" [SccDecompositionConfig] from the given [BooleanNetwork].
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        let graph = SymbolicAsyncGraph::new(boolean_network)
+            .map_err(SccDecompositionError::CreationFailed)?;
+
+        Ok(Self::from(graph))
+    }
+}
+
+impl SccDecompositionConfig {
+    /// Update the `restriction` property
+    pub fn with_restriction(mut self, restriction: GraphColoredVertices) -> Self {
+        self.restriction = restriction;
+        self
+    }
+
+    /// Update the `bdd_size_limit` property.
+    pub fn with_bdd_size_limit(mut self, bdd_size_limit: usize) -> Self {
+        self.bdd_size_limit = bdd_size_limit;
+        self
+    }
+
+    /// Update the `steps_limit` property.
+    pub fn with_steps_limit(mut self, steps_limit: usize) -> Self {
+        self.steps_limit = steps_limit;
+        self
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt::{Debug, Formatter, Result};
+
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use thiserror::Error;
+
+use crate::internal::algorithms::{
+    cancellation::CancellationError, reachability::FromReachabilityError,
+};
+
+/// An error returned by a [SccDecomposition] procedure.
+///
+/// The partial result is the list of non-trivial components discovered so far.
+#[derive(Error)]
+pub enum SccDecompositionError {
+    #[error("config creation failed: {0}")]
+    CreationFailed(String),
+    #[error("operation cancelled")]
+    Cancelled(Vec<GraphColoredVertices>),
+    #[error("steps limit exceeded")]
+    StepsLimitExceeded(Vec<GraphColoredVertices>),
+    #[error("BDD size limit exceeded")]
+    BddSizeLimitExceeded(Vec<GraphColoredVertices>),
+}
+
+/// The default implementation will print the whole BDD, which can be quite large.
+impl Debug for SccDecompositionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SccDecompositionError::CreationFailed(error) => {
+                write!(f, "CreationFailed({})", error)
+            }
+            SccDecompositionError::Cancelled(sets) => {
+                write!(f, "Cancelled(partial_result={})", sets.len())
+            }
+            SccDecompositionError::StepsLimitExceeded(sets) => {
+                write!(f, "StepsLimitExceeded(partial_result={})", sets.len())
+            }
+            SccDecompositionError::BddSizeLimitExceeded(sets) => {
+                write!(f, "BddSizeLimitExceeded(partial_result={})", sets.len())
+            }
+        }
+    }
+}
+
+impl From<CancellationError<Vec<GraphColoredVertices>>> for SccDecompositionError {
+    fn from(error_value: CancellationError<Vec<GraphColoredVertices>>) -> Self {
+        SccDecompositionError::Cancelled(error_value.into_partial_data())
+    }
+}
+
+impl FromReachabilityError<Vec<GraphColoredVertices>> for SccDecompositionError {
+    fn creation_failed(error: String) -> Self {
+        SccDecompositionError::CreationFailed(error)
+    }
+
+    fn steps_limit_exceeded(partial: Vec<GraphColoredVertices>) -> Self {
+        SccDecompositionError::StepsLimitExceeded(partial)
+    }
+
+    fn bdd_size_limit_exceeded(partial: Vec<GraphColoredVertices>) -> Self {
+        SccDecompositionError::BddSizeLimitExceeded(partial)
+    }
+}
@@ -0,0 +1,330 @@
+use biodivine_lib_param_bn::{
+    BooleanNetwork,
+    biodivine_std::traits::Set,
+    symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph},
+};
+use log::{debug, info};
+use macros::Configurable;
+
+use crate::{
+    debug_with_limit,
+    internal::algorithms::{
+        cancellation::CancellationHandler,
+        configurable::{Config as _, Configurable},
+        progress::{ProgressEvent, ProgressReporter},
+        reachability::{FromReachabilityError, Reachability, ReachabilityConfig},
+    },
+    is_cancelled,
+};
+
+use super::{SccDecompositionConfig, SccDecompositionError};
+
+const TARGET_DECOMPOSITION: &str = "SccDecomposition::decomposition";
+const TARGET_TRIM: &str = "SccDecomposition::trim";
+
+/// Implements the decomposition of a [SymbolicAsyncGraph] into its (coloured) non-trivial
+/// strongly connected components.
+///
+/// See [SccDecompositionConfig] and [SccDecompositionError] for more info.
+#[derive(Clone, Configurable)]
+pub struct SccDecomposition(SccDecompositionConfig);
+
+impl From<SymbolicAsyncGraph> for SccDecomposition {
+    /// Create a new [SccDecomposition] instance with the given [SymbolicAsyncGraph]
+    /// and otherwise default configuration.
+    fn from(graph: SymbolicAsyncGraph) -> Self {
+        SccDecomposition(SccDecompositionConfig::from(graph))
+    }
+}
+
+impl TryFrom<&BooleanNetwork> for SccDecomposition {
+    type Error = SccDecompositionError;
+
+    /// Create a new [SccDecomposition] instance with the given [BooleanNetwork]
+    /// and otherwise default configuration.
+    fn try_from(boolean_network: &BooleanNetwork) -> Result<Self, Self::Error> {
+        Ok(SccDecomposition(SccDecompositionConfig::try_from(
+            boolean_network,
+        )?))
+    }
+}
+
+impl SccDecomposition {
+    /// Compute all non-trivial strongly connected components of the sub-graph induced by
+    /// the `restriction` set.
+    ///
+    /// The algorithm is a coloured variant of the forward-backward decomposition with trimming:
+    /// for a pivot vertex, it computes its forward-reachable set and the component is then
+    /// the backward-reachable set of the pivot within this forward set. Before every pivot
+    /// selection, the remaining sub-graph is trimmed of vertices that have no predecessors
+    /// or no successors, since these cannot be part of a non-trivial component. Every pivot
+    /// is selected anew from the remaining sub-graph (i.e. the search is not "chained").
+    ///
+    /// Similar to attractors, the result is a collection of sets, such that for each set
+    /// and each color holds that if the color is present in the set, the vertices of this
+    /// color in the set together form a non-trivial SCC. The same color can appear in
+    /// multiple sets.
+    pub fn decomposition(&self) -> Result<Vec<GraphColoredVertices>, SccDecompositionError> {
+        self.start_timer();
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_DECOMPOSITION,
+            "Started with {}[nodes:{}] candidates.",
+            restriction.approx_cardinality(),
+            restriction.symbolic_size()
+        );
+
+        let mut result: Vec<GraphColoredVertices> = Vec::new();
+        let mut stack = vec![restriction.clone()];
+        let mut steps = 0usize;
+        while let Some(universe) = stack.pop() {
+            is_cancelled!(self, || result.clone())?;
+
+            let universe = self.trim(universe, &result)?;
+            if universe.is_empty() {
+                continue;
+            }
+
+            steps += 1;
+            self.report(&ProgressEvent {
+                target: TARGET_DECOMPOSITION,
+                step: steps,
+                bdd_size: universe.symbolic_size(),
+                candidates: universe.approx_cardinality(),
+            });
+
+            debug_with_limit!(
+                target: TARGET_DECOMPOSITION,
+                size: universe.symbolic_size(),
+                " > Start new component search. Universe: {}[nodes:{}]; Remaining: {} sets.",
+                universe.approx_cardinality(),
+                universe.symbolic_size(),
+                stack.len()
+            );
+
+            let pivot = universe.pick_vertex();
+
+            let fwd = self
+                .reachability(&universe)
+                .forward_closed_superset(&pivot)
+                .map_err(|e| SccDecompositionError::from_reachability_error(e, result.clone()))?;
+
+            let component = self
+                .reachability(&fwd)
+                .backward_closed_superset(&pivot)
+                .map_err(|e| SccDecompositionError::from_reachability_error(e, result.clone()))?;
+
+            // Colors where the component is only the pivot vertex are trivial.
+            let non_trivial_colors = component.minus(&pivot).colors();
+            let component_non_trivial = component.intersect_colors(&non_trivial_colors);
+
+            if !component_non_trivial.is_empty() {
+                debug!(
+                    target: TARGET_DECOMPOSITION,
+                    " > Found a non-trivial SCC: {}x{}[nodes:{}].",
+                    component_non_trivial.vertices().approx_cardinality(),
+                    component_non_trivial.colors().approx_cardinality(),
+                    component_non_trivial.symbolic_size(),
+                );
+                result.push(component_non_trivial);
+            }
+
+            // Every component that intersects `fwd` is also a subset of `fwd`, hence the
+            // remaining components are either in `fwd - component`, or `universe - fwd`.
+            let fwd_rest = fwd.minus(&component);
+            let universe_rest = universe.minus(&fwd);
+            if !fwd_rest.is_empty() {
+                stack.push(fwd_rest);
+            }
+            if !universe_rest.is_empty() {
+                stack.push(universe_rest);
+            }
+        }
+
+        info!(target: TARGET_DECOMPOSITION, "Done. Found {} component sets.", result.len());
+
+        Ok(result)
+    }
+}
+
+impl SccDecomposition {
+    /// Create a [Reachability] instance restricted to the given `subgraph` that shares
+    /// the cancellation, progress reporting and limits of this instance. The `steps_limit`
+    /// applies to each reachability procedure separately.
+    fn reachability(&self, subgraph: &GraphColoredVertices) -> Reachability {
+        Reachability::with_config(
+            ReachabilityConfig::from(self.config().graph.clone())
+                .with_subgraph(subgraph.clone())
+                .with_cancellation_nowrap(self.config().cancellation.clone())
+                .with_progress_nowrap(self.config().progress.clone())
+                .with_bdd_size_limit(self.config().bdd_size_limit)
+                .with_steps_limit(self.config().steps_limit),
+        )
+    }
+
+    /// Iteratively remove all vertices of `universe` that have no predecessors or no
+    /// successors within `universe`.
+    fn trim(
+        &self,
+        universe: GraphColoredVertices,
+        partial: &[GraphColoredVertices],
+    ) -> Result<GraphColoredVertices, SccDecompositionError> {
+        let graph = &self.config().graph;
+        let mut universe = universe;
+        loop {
+            is_cancelled!(self, || partial.to_vec())?;
+
+            let has_successor = graph.pre(&universe).intersect(&universe);
+            let has_predecessor = graph.post(&universe).intersect(&universe);
+            let trimmed = has_successor.intersect(&has_predecessor);
+
+            if trimmed == universe {
+                return Ok(universe);
+            }
+
+            debug_with_limit!(
+                target: TARGET_TRIM,
+                size: trimmed.symbolic_size(),
+                " >> Trimmed universe to {}[nodes:{}].",
+                trimmed.approx_cardinality(),
+                trimmed.symbolic_size()
+            );
+
+            universe = trimmed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering::SeqCst},
+    };
+
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+
+    use crate::internal::algorithms::cancellation::CancellationHandler;
+    use crate::internal::algorithms::configurable::{Config, Configurable};
+    use crate::internal::algorithms::scc_decomposition::{
+        SccDecomposition, SccDecompositionConfig, SccDecompositionError,
+    };
+
+    /// A [CancellationHandler] that cancels the computation once it has been checked
+    /// more than `limit` times.
+    #[derive(Clone)]
+    struct CancelAfterChecks {
+        checks: Arc<AtomicUsize>,
+        limit: usize,
+    }
+
+    impl CancellationHandler for CancelAfterChecks {
+        fn is_cancelled(&self) -> bool {
+            self.checks.fetch_add(1, SeqCst) >= self.limit
+        }
+    }
+
+    fn mk_graph(model: &str) -> (BooleanNetwork, SymbolicAsyncGraph) {
+        let bn = BooleanNetwork::try_from(model).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        (bn, graph)
+    }
+
+    fn union(graph: &SymbolicAsyncGraph, sets: &[GraphColoredVertices]) -> GraphColoredVertices {
+        sets.iter()
+            .fold(graph.mk_empty_colored_vertices(), |a, b| a.union(b))
+    }
+
+    #[test]
+    fn test_decomposition_parametrised() {
+        // The update function of `b` is unknown. Only for `b = !a`, the network oscillates
+        // through all four states. Otherwise, all components are trivial.
+        let (_, graph) = mk_graph("b -> a\na -?? b\n$a: b\n");
+        assert_eq!(graph.mk_unit_colors().approx_cardinality(), 4.0);
+
+        let result = SccDecomposition::from(graph.clone())
+            .decomposition()
+            .unwrap();
+        let components = union(&graph, &result);
+        assert_eq!(components.colors().approx_cardinality(), 1.0);
+        assert_eq!(components.vertices().approx_cardinality(), 4.0);
+        assert_eq!(
+            components,
+            graph
+                .unit_colored_vertices()
+                .intersect_colors(&components.colors())
+        );
+    }
+
+    #[test]
+    fn test_decomposition_trivial_components() {
+        // All components of the toggle switch are trivial and must be trimmed.
+        let (_, graph) = mk_graph("a -| b\nb -| a\n$a: !b\n$b: !a\n");
+        let result = SccDecomposition::from(graph).decomposition().unwrap();
+        assert!(result.is_empty());
+
+        // `a` oscillates until `c` is set, after which the network stabilizes in a sink
+        // state. The only non-trivial component is therefore not a bottom component.
+        let (bn, graph) = mk_graph("a -| a\nc -| a\na -> c\nc -> c\n$a: !a & !c\n$c: c | a\n");
+        let c = bn.as_graph().find_variable("c").unwrap();
+        let result = SccDecomposition::from(graph.clone())
+            .decomposition()
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], graph.mk_subspace(&[(c, false)]));
+
+        // Restricting the graph removes the cycle.
+        let config = SccDecompositionConfig::from(graph.clone())
+            .with_restriction(graph.mk_subspace(&[(c, true)]));
+        let result = SccDecomposition::with_config(config)
+            .decomposition()
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_decomposition_partial_result() {
+        // The constant `s` splits the state space into two oscillating components.
+        let (_, graph) = mk_graph("s -> s\nx -| x\n$s: s\n$x: !x\n");
+        let expected = SccDecomposition::from(graph.clone())
+            .decomposition()
+            .unwrap();
+        assert_eq!(expected.len(), 2);
+
+        // The decomposition is deterministic, hence a cancelled run must report a prefix
+        // of the full result. Eventually, the limit is high enough to finish the run.
+        let mut non_empty_partial = false;
+        for limit in 0.. {
+            let cancellation = CancelAfterChecks {
+                checks: Arc::new(AtomicUsize::new(0)),
+                limit,
+            };
+            let config =
+                SccDecompositionConfig::from(graph.clone()).with_cancellation(cancellation);
+            match SccDecomposition::with_config(config).decomposition() {
+                Ok(result) => {
+                    assert_eq!(result, expected);
+                    break;
+                }
+                Err(SccDecompositionError::Cancelled(partial)) => {
+                    assert!(partial.len() < expected.len());
+                    assert_eq!(partial.as_slice(), &expected[..partial.len()]);
+                    non_empty_partial |= !partial.is_empty();
+                }
+                Err(error) => panic!("Unexpected error: {:?}", error),
+            }
+        }
+        assert!(non_empty_partial);
+
+        // The steps limit is also reported with a partial result.
+        let config = SccDecompositionConfig::from(graph.clone()).with_steps_limit(0);
+        let result = SccDecomposition::with_config(config).decomposition();
+        assert!(matches!(
+            result,
+            Err(SccDecompositionError::StepsLimitExceeded(partial)) if partial.is_empty()
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use biodivine_lib_bdd::{Bdd, bdd};
-use biodivine_lib_param_bn::{VariableId, trap_spaces::SymbolicSpaceContext};
+use biodivine_lib_param_bn::{
+    VariableId, symbolic_async_graph::SymbolicAsyncGraph, trap_spaces::SymbolicSpaceContext,
+};
 use log::debug;
 
 use crate::{internal::algorithms::cancellation::CancellationHandler, is_cancelled};
@@ -8,7 +10,11 @@ use super::TrapSpacesError;
 
 // TODO: This trait exists only because I don't have access to impl `SymbolicSpaceContext` directly, as
 // it comes from another crate. Once the lib_param_bn methods use the new approach, remove this trait
-pub trait SymbolicSpaceContextExt {
+pub trait SymbolicSpaceContextExt: Sized {
+    fn from_graph_ext(
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<(Self, SymbolicAsyncGraph), TrapSpacesError>;
+
     fn mk_can_go_to_true_ext<C: CancellationHandler>(
         &self,
         function: &Bdd,
@@ -29,6 +35,39 @@ pub trait SymbolicSpaceContextExt {
 }
 
 impl SymbolicSpaceContextExt for SymbolicSpaceContext {
+    /// Create a new [SymbolicSpaceContext] for the given [SymbolicAsyncGraph], together with
+    /// a graph that uses this context and is equivalent to the original `graph`.
+    ///
+    /// The network is taken from the original `graph` if available, otherwise it is
+    /// reconstructed from the symbolic update functions (see
+    /// [SymbolicAsyncGraph::reconstruct_network]). The unit set of the original graph
+    /// (e.g. a restricted `unit_bdd`) is transferred into the new graph.
+    fn from_graph_ext(
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<(Self, SymbolicAsyncGraph), TrapSpacesError> {
+        let Some(network) = graph
+            .as_network()
+            .cloned()
+            .or_else(|| graph.reconstruct_network())
+        else {
+            return Err(TrapSpacesError::CreationFailed(
+                "cannot reconstruct network: complex parameters found".to_string(),
+            ));
+        };
+
+        let ctx = SymbolicSpaceContext::new(&network);
+        let space_graph = SymbolicAsyncGraph::with_space_context(&network, &ctx)
+            .map_err(TrapSpacesError::CreationFailed)?;
+
+        let Some(unit) = space_graph.transfer_from(&graph.unit_colored_vertices(), graph) else {
+            return Err(TrapSpacesError::CreationFailed(
+                "cannot transfer the unit set into the space context".to_string(),
+            ));
+        };
+
+        Ok((ctx, space_graph.restrict(&unit)))
+    }
+
     /// Compute a [Bdd] which encodes all spaces in which the value of `function` can be
     /// `true` for some state. We assume that `function` can depend on state variables and
     /// parameter variables, but not on the dual variables used for space encoding.
@@ -2,9 +2,11 @@ mod _impl_symbolic_space_context;
 mod trap_spaces_config;
 mod trap_spaces_error;
 mod trap_spaces_impl;
+mod trap_spaces_solver_iterator;
 
 pub use _impl_symbolic_space_context::SymbolicSpaceContextExt;
 pub use trap_spaces_config::TrapSpacesConfig;
 pub use trap_spaces_error::TrapSpacesError;
 #[allow(unused_imports)]
 pub use trap_spaces_impl::TrapSpaces;
+pub use trap_spaces_solver_iterator::TrapSpacesSolverIterator;
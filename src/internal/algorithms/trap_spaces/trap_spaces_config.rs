@@ -5,9 +5,13 @@ use biodivine_lib_param_bn::{
 };
 use macros::Config;
 
-use crate::internal::algorithms::{cancellation::CancellationHandler, configurable::Config};
+use crate::internal::algorithms::{
+    cancellation::{CancelTokenNever, CancellationHandler},
+    configurable::Config,
+    progress::ProgressReporter,
+};
 
-use super::TrapSpacesError;
+use super::{SymbolicSpaceContextExt, TrapSpacesError};
 
 /// A configuration struct for the [TrapSpaces] algorithms.
 #[derive(Clone, Config)]
@@ -28,6 +32,11 @@ pub struct TrapSpacesConfig {
     /// Default: [CancelTokenNever].
     pub cancellation: Box<dyn CancellationHandler>,
 
+    /// A `ProgressReporter` that is notified about the progress of the algorithm.
+    ///
+    /// Default: [ProgressReporterNever].
+    pub progress: Box<dyn ProgressReporter>,
+
     /// The maximum size of the BDD used in the merging process.
     ///
     /// Note that the algorithm can use other auxiliary BDDs that do not
@@ -37,8 +46,6 @@ pub struct TrapSpacesConfig {
     pub bdd_size_limit: usize,
 }
 
-// TODO: the current API does not allow creation straight from SymbolicAsyncGraph, this is a
-// temporary workaround
 impl From<(SymbolicAsyncGraph, SymbolicSpaceContext)> for TrapSpacesConfig {
     /// Create a new "default" [TrapSpacesConfig] from the given [SymbolicAsyncGraph] and
     /// [SymbolicSpaceContext].
@@ -50,6 +57,7 @@ impl From<(SymbolicAsyncGraph, SymbolicSpaceContext)> for TrapSpacesConfig {
         TrapSpacesConfig {
             restriction: ctx.mk_unit_colored_spaces(&graph),
             cancellation: Default::default(),
+            progress: Default::default(),
             bdd_size_limit: usize::MAX,
             graph,
             ctx,
@@ -57,6 +65,29 @@ impl From<(SymbolicAsyncGraph, SymbolicSpaceContext)> for TrapSpacesConfig {
     }
 }
 
+impl TryFrom<SymbolicAsyncGraph> for TrapSpacesConfig {
+    type Error = TrapSpacesError;
+
+    /// Create a new "default" [TrapSpacesConfig] from the given [SymbolicAsyncGraph].
+    ///
+    /// The symbolic encoding of the graph has no room for the dual variables of
+    /// a [SymbolicSpaceContext]. Hence, a new compatible graph is created using
+    /// [SymbolicSpaceContextExt::from_graph_ext]. The default `restriction` then only contains
+    /// the spaces that are fully within the unit set of the original graph.
+    fn try_from(graph: SymbolicAsyncGraph) -> Result<Self, Self::Error> {
+        let (ctx, graph) = SymbolicSpaceContext::from_graph_ext(&graph)?;
+
+        // Spaces that contain at least one vertex outside the unit set.
+        let outside_unit = graph.unit_colored_vertices().as_bdd().not();
+        let leaves_unit = ctx.mk_can_go_to_true_ext(&outside_unit, &CancelTokenNever)?;
+
+        let config = Self::from((graph, ctx));
+        let restriction = config.restriction.as_bdd().and_not(&leaves_unit);
+        let restriction = NetworkColoredSpaces::new(restriction, &config.ctx);
+        Ok(config.with_restriction(restriction))
+    }
+}
+
 impl TryFrom<&BooleanNetwork> for TrapSpacesConfig {
     type Error = TrapSpacesError;
 
@@ -1,10 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use biodivine_lib_bdd::bdd;
+use biodivine_lib_bdd::{Bdd, BddPartialValuation, bdd};
 use biodivine_lib_param_bn::{
     BooleanNetwork,
     biodivine_std::traits::Set,
-    symbolic_async_graph::SymbolicAsyncGraph,
+    symbolic_async_graph::{GraphColors, SymbolicAsyncGraph},
     trap_spaces::{NetworkColoredSpaces, SymbolicSpaceContext},
 };
 use log::info;
@@ -15,14 +15,20 @@ use crate::{
     internal::algorithms::{
         configurable::{Config as _, Configurable},
         fixed_points::{FixedPoints, FixedPointsConfig},
+        progress::{ProgressEvent, ProgressReporter},
     },
     is_cancelled,
 };
 
-use super::{SymbolicSpaceContextExt as _, TrapSpacesConfig, TrapSpacesError};
+use super::{
+    SymbolicSpaceContextExt as _, TrapSpacesConfig, TrapSpacesError, TrapSpacesSolverIterator,
+};
 
 const TARGET_ESSENTIAL_SYMBOLIC: &str = "TrapSpaces::essential_symbolic";
 const TARGET_MINIMAL_SYMBOLIC: &str = "TrapSpaces::minimal_symbolic";
+const TARGET_MINIMAL_SOLVER: &str = "TrapSpaces::minimal_solver";
+const TARGET_MAXIMAL_SYMBOLIC: &str = "TrapSpaces::maximal_symbolic";
+const TARGET_COUNT_PER_COLOR: &str = "TrapSpaces::count_per_color";
 const TARGET_MINIMIZE: &str = "TrapSpaces::minimize";
 const TARGET_MAXIMIZE: &str = "TrapSpaces::maximize";
 
@@ -37,6 +43,17 @@ impl From<(SymbolicAsyncGraph, SymbolicSpaceContext)> for TrapSpaces {
     }
 }
 
+impl TryFrom<SymbolicAsyncGraph> for TrapSpaces {
+    type Error = TrapSpacesError;
+
+    /// Create a new "default" [TrapSpaces] for the given [SymbolicAsyncGraph].
+    ///
+    /// See [TrapSpacesConfig] for details about the conversion.
+    fn try_from(graph: SymbolicAsyncGraph) -> Result<Self, Self::Error> {
+        Ok(TrapSpaces(TrapSpacesConfig::try_from(graph)?))
+    }
+}
+
 impl TryFrom<&BooleanNetwork> for TrapSpaces {
     type Error = TrapSpacesError;
 
@@ -63,58 +80,12 @@ impl TrapSpaces {
             restriction.symbolic_size()
         );
 
-        let bdd_ctx = ctx.bdd_variable_set();
-
-        // We always start with the restriction set, because it should carry the information
-        // about valid encoding of spaces.
-        let mut to_merge = vec![restriction.as_bdd().clone()];
-        let mut combined_bdd_size = restriction.as_bdd().size();
-        for var in graph.variables() {
-            if combined_bdd_size >= self.config().bdd_size_limit {
-                return Err(TrapSpacesError::BddSizeLimitExceeded(
-                    restriction.as_bdd().clone(),
-                ));
-            }
-
-            let update_bdd = graph.get_symbolic_fn_update(var);
-            let not_update_bdd = update_bdd.not();
-            is_cancelled!(self, || restriction.as_bdd().clone())?;
-
-            let has_up_transition = &ctx.mk_can_go_to_true_ext(update_bdd, self)?;
-            is_cancelled!(self, || restriction.as_bdd().clone())?;
-
-            let has_down_transition = &ctx.mk_can_go_to_true_ext(&not_update_bdd, self)?;
-            is_cancelled!(self, || restriction.as_bdd().clone())?;
-
-            let true_var = ctx.get_positive_variable(var);
-            let false_var = ctx.get_negative_variable(var);
-
-            let is_trap =
-                bdd!(bdd_ctx, (has_up_transition => true_var) & (has_down_transition => false_var));
-            is_cancelled!(self, || restriction.as_bdd().clone())?;
-
-            let is_essential =
-                bdd!(bdd_ctx, (true_var & false_var) => (has_up_transition & has_down_transition));
-            is_cancelled!(self, || restriction.as_bdd().clone())?;
-
-            debug_with_limit!(
-                target: TARGET_ESSENTIAL_SYMBOLIC,
-                size: is_trap.size() + is_essential.size(),
-                " > Created initial sets for {:?} using {}+{} BDD nodes.",
-                var,
-                is_trap.size(),
-                is_essential.size(),
-            );
-
-            let to_push = is_trap.and(&is_essential);
-            combined_bdd_size += to_push.size();
-
-            to_merge.push(to_push);
-        }
+        let to_merge = self.prepare_to_merge(TARGET_ESSENTIAL_SYMBOLIC, true)?;
 
         let trap_spaces = FixedPoints::with_config(
             FixedPointsConfig::from(graph.clone())
                 .with_cancellation_nowrap(self.config().cancellation.clone())
+                .with_progress_nowrap(self.config().progress.clone())
                 .with_bdd_size_limit(self.config().bdd_size_limit),
         )
         .symbolic_merge(to_merge, HashSet::new(), TARGET_ESSENTIAL_SYMBOLIC)?;
@@ -148,6 +119,161 @@ impl TrapSpaces {
             .and_then(|essential| self.minimize(&essential))
     }
 
+    /// Computes the maximal coloured trap spaces of the underlying `graph` within the configured
+    /// `restriction` set.
+    ///
+    /// Since the whole state space is trivially a trap space, it is not considered. That is,
+    /// the result contains the maximal trap spaces that are *proper* subspaces of the whole
+    /// state space. Colors for which no such trap space exists do not appear in the result.
+    pub fn maximal_symbolic(&self) -> Result<NetworkColoredSpaces, TrapSpacesError> {
+        self.start_timer();
+        let ctx = &self.config().ctx;
+        let graph = &self.config().graph;
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_MAXIMAL_SYMBOLIC,
+            "Start symbolic maximal trap space search with {}[nodes:{}] candidates.",
+            restriction.approx_cardinality(),
+            restriction.symbolic_size()
+        );
+
+        // Maximal trap spaces are not necessarily essential, hence all trap spaces are needed.
+        let to_merge = self.prepare_to_merge(TARGET_MAXIMAL_SYMBOLIC, false)?;
+
+        let trap_spaces = FixedPoints::with_config(
+            FixedPointsConfig::from(graph.clone())
+                .with_cancellation_nowrap(self.config().cancellation.clone())
+                .with_progress_nowrap(self.config().progress.clone())
+                .with_bdd_size_limit(self.config().bdd_size_limit),
+        )
+        .symbolic_merge(to_merge, HashSet::new(), TARGET_MAXIMAL_SYMBOLIC)?;
+
+        // Remove the whole state space, i.e. the space where every variable is free.
+        let mut whole_space = BddPartialValuation::empty();
+        for (t_var, f_var) in ctx.get_dual_variables() {
+            whole_space.set_value(*t_var, true);
+            whole_space.set_value(*f_var, true);
+        }
+        let whole_space = ctx.bdd_variable_set().mk_conjunctive_clause(&whole_space);
+        let proper_trap_spaces = NetworkColoredSpaces::new(trap_spaces.and_not(&whole_space), ctx);
+        is_cancelled!(self, || proper_trap_spaces.as_bdd().clone())?;
+
+        self.maximize(&proper_trap_spaces)
+    }
+
+    /// Count the number of spaces in the given colored set `spaces` for each color.
+    ///
+    /// The result maps each count to the colors which have exactly this number of spaces.
+    /// Colors of the `restriction` set that have no spaces are mapped to zero, and counts with
+    /// no colors are omitted. For example, `count_per_color(minimal_symbolic())` classifies
+    /// the colors by the number of their minimal trap spaces.
+    ///
+    /// The number of symbolic steps is proportional to the highest count, not to the total
+    /// number of spaces.
+    pub fn count_per_color(
+        &self,
+        spaces: &NetworkColoredSpaces,
+    ) -> Result<HashMap<usize, GraphColors>, TrapSpacesError> {
+        self.start_timer();
+        let restriction = &self.config().restriction;
+
+        info!(
+            target: TARGET_COUNT_PER_COLOR,
+            "Start counting {}[nodes:{}] spaces.",
+            spaces.approx_cardinality(),
+            spaces.symbolic_size()
+        );
+
+        let mut result = HashMap::new();
+
+        let mut remaining = spaces.clone();
+        let mut remaining_colors = remaining.colors();
+        let empty_colors = restriction.colors().minus(&remaining_colors);
+        if !empty_colors.is_empty() {
+            result.insert(0, empty_colors);
+        }
+
+        // In each iteration, one space is removed for each color. The colors that disappear
+        // from the remaining set in the i-th iteration have exactly i spaces.
+        let mut count = 0usize;
+        while !remaining.is_empty() {
+            is_cancelled!(self, || remaining.as_bdd().clone())?;
+
+            count += 1;
+            remaining = remaining.minus(&remaining.pick_space());
+            let colors = remaining.colors();
+            let exact_colors = remaining_colors.minus(&colors);
+            if !exact_colors.is_empty() {
+                result.insert(count, exact_colors);
+            }
+            remaining_colors = colors;
+
+            self.report(&ProgressEvent {
+                target: TARGET_COUNT_PER_COLOR,
+                step: count,
+                bdd_size: remaining.symbolic_size(),
+                candidates: remaining.approx_cardinality(),
+            });
+        }
+
+        info!(
+            target: TARGET_COUNT_PER_COLOR,
+            "Done. Highest count is {}.",
+            count
+        );
+
+        Ok(result)
+    }
+
+    /// Computes the minimal coloured trap spaces of the underlying `graph` within the configured
+    /// `restriction` set using the Z3 solver. If `limit` is given, at most `limit` minimal
+    /// trap spaces (i.e. space-color pairs) are returned.
+    ///
+    /// See [Self::minimal_solver_iterator] for details.
+    pub fn minimal_solver(
+        &self,
+        limit: Option<usize>,
+    ) -> Result<NetworkColoredSpaces, TrapSpacesError> {
+        info!(
+            target: TARGET_MINIMAL_SOLVER,
+            "Start solver minimal trap space search (limit: {:?}).",
+            limit
+        );
+
+        let mut iterator = self.minimal_solver_iterator()?;
+        for trap_space in iterator.by_ref().take(limit.unwrap_or(usize::MAX)) {
+            trap_space?;
+        }
+
+        Ok(iterator.found())
+    }
+
+    /// Enumerate the minimal coloured trap spaces within the configured `restriction` one by one
+    /// using the Z3 solver.
+    ///
+    /// Each item of the returned [TrapSpacesSolverIterator] is a singleton set with one space
+    /// and one color for which the space is a minimal trap space.
+    ///
+    /// **Characteristics:** The trap space constraint of each variable is still computed
+    /// symbolically (as in [Self::essential_symbolic]), but the constraints are never merged
+    /// into a single BDD. Instead, the solver finds a trap space and then greedily searches
+    /// for smaller trap spaces of the same color until the space is minimal. This scales to
+    /// much larger networks than [Self::minimal_symbolic], but each minimal trap space
+    /// (and each color) has to be enumerated explicitly.
+    ///
+    /// A running solver is interrupted once the computation is cancelled. However, Python
+    /// interrupts are only detected between the individual solver queries.
+    pub fn minimal_solver_iterator(&self) -> Result<TrapSpacesSolverIterator, TrapSpacesError> {
+        self.start_timer();
+        let restriction = &self.config().restriction;
+
+        let constraints = self.prepare_to_merge(TARGET_MINIMAL_SOLVER, true)?;
+        is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+        Ok(TrapSpacesSolverIterator::new(self.clone(), &constraints))
+    }
+
     /// Compute the minimal spaces within a particular subset.
     pub fn minimize(
         &self,
@@ -166,6 +292,7 @@ impl TrapSpaces {
             original.symbolic_size()
         );
 
+        let mut steps = 0usize;
         while !original.is_empty() {
             if minimal.as_bdd().size() >= self.config().bdd_size_limit {
                 return Err(TrapSpacesError::BddSizeLimitExceeded(
@@ -204,6 +331,14 @@ impl TrapSpaces {
             minimal = minimal.minus(&super_spaces).union(&minimum_candidate);
             is_cancelled!(self, || minimal.as_bdd().clone())?;
 
+            steps += 1;
+            self.report(&ProgressEvent {
+                target: TARGET_MINIMIZE,
+                step: steps,
+                bdd_size: original.symbolic_size(),
+                candidates: original.approx_cardinality(),
+            });
+
             debug_with_limit!(
                 target: TARGET_MINIMIZE,
                 size: original.symbolic_size() + minimal.symbolic_size(),
@@ -245,6 +380,7 @@ impl TrapSpaces {
             original.symbolic_size()
         );
 
+        let mut steps = 0usize;
         while !original.is_empty() {
             if maximal.as_bdd().size() >= self.config().bdd_size_limit {
                 return Err(TrapSpacesError::BddSizeLimitExceeded(
@@ -264,6 +400,14 @@ impl TrapSpaces {
             maximal = maximal.minus(&sub_spaces).union(&maximum_candidate);
             is_cancelled!(self, || maximal.as_bdd().clone())?;
 
+            steps += 1;
+            self.report(&ProgressEvent {
+                target: TARGET_MAXIMIZE,
+                step: steps,
+                bdd_size: original.symbolic_size(),
+                candidates: original.approx_cardinality(),
+            });
+
             debug_with_limit!(
                 target: TARGET_MAXIMIZE,
                 size: original.symbolic_size() + maximal.symbolic_size(),
@@ -287,3 +431,68 @@ impl TrapSpaces {
         Ok(maximal)
     }
 }
+
+impl TrapSpaces {
+    /// Compute the constraints whose conjunction gives the trap spaces within `restriction`.
+    /// If `essential` is set, only the essential trap spaces are retained
+    /// (see [Self::essential_symbolic]). The first constraint is always the `restriction` itself.
+    fn prepare_to_merge(&self, target: &str, essential: bool) -> Result<Vec<Bdd>, TrapSpacesError> {
+        let ctx = &self.config().ctx;
+        let graph = &self.config().graph;
+        let restriction = &self.config().restriction;
+
+        let bdd_ctx = ctx.bdd_variable_set();
+
+        // We always start with the restriction set, because it should carry the information
+        // about valid encoding of spaces.
+        let mut to_merge = vec![restriction.as_bdd().clone()];
+        let mut combined_bdd_size = restriction.as_bdd().size();
+        for var in graph.variables() {
+            if combined_bdd_size >= self.config().bdd_size_limit {
+                return Err(TrapSpacesError::BddSizeLimitExceeded(
+                    restriction.as_bdd().clone(),
+                ));
+            }
+
+            let update_bdd = graph.get_symbolic_fn_update(var);
+            let not_update_bdd = update_bdd.not();
+            is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+            let has_up_transition = &ctx.mk_can_go_to_true_ext(update_bdd, self)?;
+            is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+            let has_down_transition = &ctx.mk_can_go_to_true_ext(&not_update_bdd, self)?;
+            is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+            let true_var = ctx.get_positive_variable(var);
+            let false_var = ctx.get_negative_variable(var);
+
+            let is_trap =
+                bdd!(bdd_ctx, (has_up_transition => true_var) & (has_down_transition => false_var));
+            is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+            let is_essential = if essential {
+                bdd!(bdd_ctx, (true_var & false_var) => (has_up_transition & has_down_transition))
+            } else {
+                bdd_ctx.mk_true()
+            };
+            is_cancelled!(self, || restriction.as_bdd().clone())?;
+
+            debug_with_limit!(
+                target: target,
+                size: is_trap.size() + is_essential.size(),
+                " > Created initial sets for {:?} using {}+{} BDD nodes.",
+                var,
+                is_trap.size(),
+                is_essential.size(),
+            );
+
+            let to_push = is_trap.and(&is_essential);
+            combined_bdd_size += to_push.size();
+
+            to_merge.push(to_push);
+        }
+
+        Ok(to_merge)
+    }
+}
@@ -0,0 +1,227 @@
+use biodivine_lib_bdd::{Bdd, BddPartialValuation, BddVariable};
+use biodivine_lib_param_bn::trap_spaces::NetworkColoredSpaces;
+use log::{debug, info};
+
+use crate::{
+    internal::algorithms::{
+        bdd_solver::{BddSolver, BddSolverResult},
+        configurable::Configurable,
+        progress::{ProgressEvent, ProgressReporter},
+    },
+    is_cancelled,
+};
+
+use super::{TrapSpaces, TrapSpacesError};
+
+const TARGET_MINIMAL_SOLVER: &str = "TrapSpaces::minimal_solver";
+
+/// An iterator that lazily enumerates minimal trap spaces using the Z3 solver.
+///
+/// Each item is a singleton set containing one minimal trap space together with one color
+/// for which the space is a minimal trap space, or an error. After an error is returned,
+/// the iterator is exhausted. The partial result of the error contains all trap spaces that
+/// were returned by the iterator so far.
+///
+/// See [TrapSpaces::minimal_solver_iterator].
+pub struct TrapSpacesSolverIterator {
+    trap_spaces: TrapSpaces,
+    solver: BddSolver,
+    /// The `(positive, negative)` dual variables of each network variable.
+    dual_variables: Vec<(BddVariable, BddVariable)>,
+    /// The dual and parameter variables that determine a single colored space.
+    variables: Vec<BddVariable>,
+    found: Bdd,
+    found_count: usize,
+    done: bool,
+}
+
+impl TrapSpacesSolverIterator {
+    /// Create a new iterator which enumerates the minimal satisfying spaces of the conjunction
+    /// of the given `constraints`.
+    pub(super) fn new(trap_spaces: TrapSpaces, constraints: &[Bdd]) -> TrapSpacesSolverIterator {
+        let ctx = &trap_spaces.config().ctx;
+        let bdd_vars = ctx.bdd_variable_set();
+
+        let solver = BddSolver::new(bdd_vars);
+        for constraint in constraints {
+            solver.assert_bdd(constraint);
+        }
+
+        let dual_variables = trap_spaces
+            .config()
+            .graph
+            .variables()
+            .map(|var| ctx.get_dual_variable_pair(var))
+            .collect::<Vec<_>>();
+
+        let mut variables = dual_variables
+            .iter()
+            .flat_map(|(t_var, f_var)| [*t_var, *f_var])
+            .collect::<Vec<_>>();
+        variables.extend(ctx.inner_context().parameter_variables());
+
+        info!(
+            target: TARGET_MINIMAL_SOLVER,
+            "Started with {} constraints over {} variables.",
+            constraints.len(),
+            variables.len()
+        );
+
+        TrapSpacesSolverIterator {
+            found: bdd_vars.mk_false(),
+            trap_spaces,
+            solver,
+            dual_variables,
+            variables,
+            found_count: 0,
+            done: false,
+        }
+    }
+
+    /// The union of all minimal trap spaces that were returned by this iterator so far.
+    pub fn found(&self) -> NetworkColoredSpaces {
+        NetworkColoredSpaces::new(self.found.clone(), &self.trap_spaces.config().ctx)
+    }
+
+    /// Search for the next minimal trap space. Returns `None` once all minimal trap spaces
+    /// are enumerated.
+    fn next_trap_space(&mut self) -> Result<Option<NetworkColoredSpaces>, TrapSpacesError> {
+        let Some(candidate) = self.solve()? else {
+            info!(
+                target: TARGET_MINIMAL_SOLVER,
+                "Done. Found {} minimal trap spaces.",
+                self.found_count
+            );
+            return Ok(None);
+        };
+
+        let ctx = &self.trap_spaces.config().ctx;
+        let bdd_vars = ctx.bdd_variable_set();
+
+        // Fix the color of the candidate and greedily search for smaller trap spaces
+        // until the candidate is minimal.
+        let mut color = BddPartialValuation::empty();
+        for var in ctx.inner_context().parameter_variables() {
+            color.set_value(*var, candidate.get_value(*var).unwrap());
+        }
+
+        let mut minimal = candidate;
+        let mut shrink_steps = 0usize;
+        self.solver.push();
+        self.solver
+            .assert_bdd(&bdd_vars.mk_conjunctive_clause(&color));
+        loop {
+            self.solver.push();
+            self.solver.assert_bdd(&self.mk_strict_sub_spaces(&minimal));
+            let smaller = self.solve();
+            self.solver.pop();
+
+            match smaller {
+                Ok(Some(smaller)) => {
+                    minimal = smaller;
+                    shrink_steps += 1;
+                }
+                Ok(None) => break,
+                Err(error) => {
+                    self.solver.pop();
+                    return Err(error);
+                }
+            }
+        }
+        self.solver.pop();
+
+        debug!(
+            target: TARGET_MINIMAL_SOLVER,
+            " > Found minimal trap space after {} shrinking steps.",
+            shrink_steps
+        );
+
+        // Block all super spaces of the minimal trap space (for this color), as these cannot
+        // be minimal anymore. This also blocks the trap space itself.
+        let mut super_spaces = color;
+        for (t_var, f_var) in &self.dual_variables {
+            for var in [*t_var, *f_var] {
+                if minimal.get_value(var) == Some(true) {
+                    super_spaces.set_value(var, true);
+                }
+            }
+        }
+        self.solver
+            .assert_bdd(&bdd_vars.mk_conjunctive_clause(&super_spaces).not());
+
+        let bdd = bdd_vars.mk_conjunctive_clause(&minimal);
+        self.found = self.found.or(&bdd);
+        self.found_count += 1;
+
+        self.trap_spaces.report(&ProgressEvent {
+            target: TARGET_MINIMAL_SOLVER,
+            step: self.found_count,
+            bdd_size: self.found.size(),
+            candidates: self.found_count as f64,
+        });
+
+        Ok(Some(NetworkColoredSpaces::new(bdd, ctx)))
+    }
+
+    /// Find a model of the currently asserted constraints, or return `None` if there is none.
+    fn solve(&self) -> Result<Option<BddPartialValuation>, TrapSpacesError> {
+        is_cancelled!(&self.trap_spaces, || self.found.clone())?;
+
+        match self.solver.solve(&self.variables, &self.trap_spaces) {
+            BddSolverResult::Sat(valuation) => Ok(Some(valuation)),
+            BddSolverResult::Unsat => Ok(None),
+            // The solver gave up, which only happens once it is interrupted by cancellation.
+            BddSolverResult::Unknown => Err(TrapSpacesError::Cancelled(self.found.clone())),
+        }
+    }
+
+    /// Make a [Bdd] of all spaces that are strict subspaces of the given `space`.
+    fn mk_strict_sub_spaces(&self, space: &BddPartialValuation) -> Bdd {
+        let bdd_vars = self.trap_spaces.config().ctx.bdd_variable_set();
+
+        // A subspace cannot allow any value that is not allowed by `space`.
+        let mut sub_spaces = BddPartialValuation::empty();
+        // A strict subspace must fix at least one of the free variables of `space`.
+        let mut free_variables = BddPartialValuation::empty();
+        for (t_var, f_var) in &self.dual_variables {
+            let t_value = space.get_value(*t_var) == Some(true);
+            let f_value = space.get_value(*f_var) == Some(true);
+            if !t_value {
+                sub_spaces.set_value(*t_var, false);
+            }
+            if !f_value {
+                sub_spaces.set_value(*f_var, false);
+            }
+            if t_value && f_value {
+                free_variables.set_value(*t_var, true);
+                free_variables.set_value(*f_var, true);
+            }
+        }
+
+        let sub_spaces = bdd_vars.mk_conjunctive_clause(&sub_spaces);
+        let not_free = bdd_vars.mk_conjunctive_clause(&free_variables).not();
+        sub_spaces.and(&not_free)
+    }
+}
+
+impl Iterator for TrapSpacesSolverIterator {
+    type Item = Result<NetworkColoredSpaces, TrapSpacesError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_trap_space() {
+            Ok(Some(trap_space)) => Some(Ok(trap_space)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
@@ -1,14 +1,14 @@
 //! Main high-level functionality regarding the BN classification based on HCTL properties.
 
 use crate::internal::classification::load_inputs::*;
-use crate::internal::classification::write_output::{write_classifier_output, write_empty_report};
+use crate::internal::classification::write_output::{
+    ClassificationStats, refine_property_classes, write_classifier_output, write_empty_report,
+};
 
 use biodivine_hctl_model_checker::mc_utils::{
     collect_unique_hctl_vars, get_extended_symbolic_graph,
 };
-use biodivine_hctl_model_checker::model_checking::{
-    model_check_multiple_trees_dirty, model_check_tree_dirty,
-};
+use biodivine_hctl_model_checker::model_checking::model_check_multiple_trees_dirty;
 use biodivine_hctl_model_checker::postprocessing::sanitizing::sanitize_colors;
 use biodivine_hctl_model_checker::preprocessing::parser::parse_and_minimize_hctl_formula;
 
@@ -19,7 +19,10 @@ use biodivine_lib_param_bn::symbolic_async_graph::{
 use biodivine_lib_param_bn::{BooleanNetwork, ModelAnnotation};
 
 use biodivine_hctl_model_checker::preprocessing::hctl_tree::HctlTreeNode;
+use log::{debug, info, warn};
 use std::cmp::max;
+use std::collections::HashMap;
+use std::time::Instant;
 
 /// Return the set of colors for which ALL system states are contained in the given color-vertex
 /// set (i.e., if the given relation is a result of model checking a property, get colors for which
@@ -34,6 +37,41 @@ pub fn get_universal_colors(
     stg.unit_colors().minus(&complement.colors())
 }
 
+/// Model check the combined `assertion_tree` together with all `property_trees` on the given
+/// (extended) `graph`. Returns the result of the assertion and the results of the properties
+/// (in the order of `property_trees`).
+///
+/// All formulae are evaluated in a single model-checking run. As such, the result of every
+/// subformula that is shared between the assertion and the properties (or between multiple
+/// properties) is only computed once.
+pub fn model_check_assertion_and_properties(
+    graph: &SymbolicAsyncGraph,
+    assertion_tree: HctlTreeNode,
+    property_trees: Vec<HctlTreeNode>,
+) -> Result<(GraphColoredVertices, Vec<GraphColoredVertices>), String> {
+    let mut trees = Vec::with_capacity(property_trees.len() + 1);
+    trees.push(assertion_tree);
+    trees.extend(property_trees);
+
+    let mut results = model_check_multiple_trees_dirty(trees, graph)?;
+    let property_results = results.split_off(1);
+    let assertion_result = results.pop().expect("Assertion is always checked.");
+    Ok((assertion_result, property_results))
+}
+
+/// For each of the `property_results`, return the `valid_colors` for which the property
+/// holds (universally).
+pub fn get_valid_property_colors(
+    graph: &SymbolicAsyncGraph,
+    valid_colors: &GraphColors,
+    property_results: &[GraphColoredVertices],
+) -> Vec<GraphColors> {
+    property_results
+        .iter()
+        .map(|result| get_universal_colors(graph, result).intersect(valid_colors))
+        .collect()
+}
+
 /// Perform the classification of Boolean networks based on given properties.
 /// Takes a path to a file in annotated `AEON` format containing a partially defined BN model
 /// and 2 sets of HCTL formulae. Assertions are formulae that must be satisfied, and properties
@@ -44,8 +82,10 @@ pub fn get_universal_colors(
 /// of properties is satisfied (universally).
 ///
 /// Report and BDDs representing resulting classes are generated into `output_zip` archive.
+/// Progress is reported through the `log` crate.
 pub fn classify(model_path: &str, output_zip: &str) -> Result<(), String> {
-    // TODO: allow caching between model-checking assertions and properties somehow
+    let mut stats = ClassificationStats::default();
+    let mut phase_start = Instant::now();
 
     // load the model and two sets of formulae (from model annotations)
     let Ok(aeon_str) = std::fs::read_to_string(model_path) else {
@@ -56,9 +96,9 @@ pub fn classify(model_path: &str, output_zip: &str) -> Result<(), String> {
     let annotations = ModelAnnotation::from_model_string(aeon_str.as_str());
     let assertions = read_model_assertions(&annotations);
     let named_properties = read_model_properties(&annotations)?;
-    println!("Loaded model and properties out of `{model_path}`.");
+    info!("Loaded model and properties out of `{model_path}`.");
 
-    println!("Parsing formulae and generating symbolic representation...");
+    info!("Parsing formulae and generating symbolic representation...");
     // Combine all assertions into one formula and add it to the list of properties.
     let assertion = build_combined_assertion(&assertions);
     // Adjust the message depending on the number of properties (singular/multiple)
@@ -67,7 +107,7 @@ pub fn classify(model_path: &str, output_zip: &str) -> Result<(), String> {
     } else {
         "properties (assertions)"
     };
-    println!(
+    info!(
         "Successfully parsed all {} required {assertion_message} and all {} classification properties.",
         assertions.len(),
         named_properties.len(),
@@ -88,46 +128,44 @@ pub fn classify(model_path: &str, output_zip: &str) -> Result<(), String> {
     let Ok(graph) = get_extended_symbolic_graph(&bn, num_hctl_vars as u16) else {
         return Err("Unable to generate STG for provided PSBN model.".to_string());
     };
-    println!(
+    stats.state_variables = graph.symbolic_context().num_state_variables();
+    stats.parameter_variables = graph.symbolic_context().num_parameter_variables();
+    stats.hctl_variables = num_hctl_vars;
+    stats.total_instances = graph.mk_unit_colors().approx_cardinality();
+    info!(
         "Successfully encoded model with {} variables and {} parameters.",
-        graph.symbolic_context().num_state_variables(),
-        graph.symbolic_context().num_parameter_variables(),
-    );
-    println!(
-        "Model admits {:.0} instances.",
-        graph.mk_unit_colors().approx_cardinality(),
+        stats.state_variables, stats.parameter_variables,
     );
+    info!("Model admits {:.0} instances.", stats.total_instances);
+    finish_phase(&mut stats, &mut phase_start, "parsing");
 
-    println!("Evaluating required properties (this may take some time)...");
-    // Compute the colors (universally) satisfying the combined assertion formula.
-    let assertion_result = model_check_tree_dirty(assertion_tree, &graph)?;
+    // The properties are evaluated together with the assertion, hence the time of the shared
+    // model-checking run is reported as part of the `assertions` phase.
+    info!("Evaluating required and classification properties (this may take some time)...");
+    let (assertion_result, property_results) =
+        model_check_assertion_and_properties(&graph, assertion_tree, property_trees)?;
     let valid_colors = get_universal_colors(&graph, &assertion_result);
-    println!("Required properties successfully evaluated.");
-    println!(
+    info!("Required and classification properties successfully evaluated.");
+    finish_phase(&mut stats, &mut phase_start, "assertions");
+    info!(
         "{:.0} instances satisfy all required properties.",
         valid_colors.approx_cardinality(),
     );
 
     if valid_colors.is_empty() {
-        println!("No instance satisfies given required properties. Aborting.");
-        return write_empty_report(&assertions, output_zip).map_err(|e| format!("{e:?}"));
+        warn!("No instance satisfies given required properties. Aborting.");
+        return write_empty_report(
+            &assertions,
+            &named_properties,
+            &stats,
+            output_zip,
+            &aeon_str,
+        )
+        .map_err(|e| format!("{e:?}"));
     }
 
-    // restrict the colors on the symbolic graph
-    let graph = SymbolicAsyncGraph::with_custom_context(
-        &bn,
-        graph.symbolic_context().clone(),
-        valid_colors.as_bdd().clone(),
-    )?;
-
-    println!("Evaluating classification properties (this may take some time)...");
-    // Model check all properties on the restricted graph.
-    let property_result = model_check_multiple_trees_dirty(property_trees, &graph)?;
-    let property_colors: Vec<GraphColors> = property_result
-        .iter()
-        .map(|result| get_universal_colors(&graph, result))
-        .collect();
-    println!("Classification properties successfully evaluated.");
+    let property_colors = get_valid_property_colors(&graph, &valid_colors, &property_results);
+    finish_phase(&mut stats, &mut phase_start, "properties");
 
     // This is an important step where we ensure that the "model checking context"
     // does not "leak" outside the BN classifier. In essence, this ensures that the
@@ -141,28 +179,108 @@ pub fn classify(model_path: &str, output_zip: &str) -> Result<(), String> {
         .collect();
 
     // do the classification while printing the report and dumping resulting BDDs
-    println!("Generating classification mapping based on model-checking results...");
+    info!("Generating classification mapping based on model-checking results...");
     write_classifier_output(
         &assertions,
         &valid_colors,
         &named_properties,
         &property_colors,
+        &stats,
         output_zip,
         aeon_str.as_str(),
     )
     .map_err(|e| format!("{e:?}"))?;
-    println!("Results saved to `{output_zip}`.");
+    info!("Results saved to `{output_zip}`.");
 
     Ok(())
 }
 
+/// Refine an existing classification of the network `bn` using new named HCTL properties.
+///
+/// The `classes` are identified by the same names as in [refine_property_classes] and their
+/// colors use the default symbolic encoding of `bn` (i.e. as loaded from a classification
+/// archive). Only the `new_properties` are model checked, and only within the colors that
+/// already appear in one of the `classes`. Each class is then split based on which of the new
+/// properties are satisfied.
+pub fn refine_classification(
+    bn: &BooleanNetwork,
+    classes: HashMap<String, GraphColors>,
+    new_properties: &[(String, String)],
+) -> Result<HashMap<String, GraphColors>, String> {
+    let default_graph = SymbolicAsyncGraph::new(bn)?;
+    let mut valid_colors = default_graph.mk_empty_colors();
+    for colors in classes.values() {
+        valid_colors = valid_colors.union(colors);
+    }
+    if valid_colors.is_empty() || new_properties.is_empty() {
+        return Ok(classes);
+    }
+
+    // Parse the new formulae and count the max. number of HCTL variables across formulae.
+    let ctx = default_graph.symbolic_context();
+    let mut num_hctl_vars = 0;
+    let mut property_trees: Vec<HctlTreeNode> = Vec::new();
+    for (_name, formula) in new_properties {
+        let tree = parse_and_minimize_hctl_formula(ctx, formula.as_str())?;
+        let tree_vars = collect_unique_hctl_vars(tree.clone()).len();
+        num_hctl_vars = max(num_hctl_vars, tree_vars);
+        property_trees.push(tree);
+    }
+    info!(
+        "Refining {} classes using {} new properties.",
+        classes.len(),
+        new_properties.len()
+    );
+
+    // Instantiate extended STG restricted to the colors that are already classified.
+    let Ok(graph) = get_extended_symbolic_graph(bn, num_hctl_vars as u16) else {
+        return Err("Unable to generate STG for provided PSBN model.".to_string());
+    };
+    let Some(valid_colors) = graph.transfer_colors_from(&valid_colors, &default_graph) else {
+        return Err("The classification is not compatible with the provided model.".to_string());
+    };
+    let graph = SymbolicAsyncGraph::with_custom_context(
+        bn,
+        graph.symbolic_context().clone(),
+        valid_colors.as_bdd().clone(),
+    )?;
+
+    info!("Evaluating new classification properties (this may take some time)...");
+    let property_result = model_check_multiple_trees_dirty(property_trees, &graph)?;
+    // Also make sure the results use the default encoding of the original model.
+    let property_colors: Vec<GraphColors> = property_result
+        .iter()
+        .map(|result| sanitize_colors(&graph, &get_universal_colors(&graph, result)))
+        .collect();
+    info!("New classification properties successfully evaluated.");
+
+    Ok(refine_property_classes(
+        classes,
+        new_properties,
+        &property_colors,
+    ))
+}
+
+/// Record the duration of the finished `phase` and start measuring the next one.
+fn finish_phase(stats: &mut ClassificationStats, phase_start: &mut Instant, phase: &str) {
+    let elapsed = phase_start.elapsed();
+    debug!("Phase `{phase}` finished in {}ms.", elapsed.as_millis());
+    stats.timings.push((phase.to_string(), elapsed));
+    *phase_start = Instant::now();
+}
+
 #[cfg(test)]
 mod tests {
     use crate::internal::classification::classify::{
-        build_combined_assertion, read_model_assertions, read_model_properties,
+        build_combined_assertion, get_universal_colors, get_valid_property_colors,
+        model_check_assertion_and_properties, read_model_assertions, read_model_properties,
     };
-    use biodivine_hctl_model_checker::mc_utils::collect_unique_hctl_vars;
+    use biodivine_hctl_model_checker::mc_utils::{
+        collect_unique_hctl_vars, get_extended_symbolic_graph,
+    };
+    use biodivine_hctl_model_checker::model_checking::model_check_tree_dirty;
     use biodivine_hctl_model_checker::preprocessing::parser::parse_and_minimize_hctl_formula;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
     use biodivine_lib_param_bn::symbolic_async_graph::SymbolicContext;
     use biodivine_lib_param_bn::{BooleanNetwork, ModelAnnotation};
     use std::cmp::max;
@@ -237,8 +355,12 @@ mod tests {
         );
 
         assert_eq!(named_properties.len(), 2);
-        assert!(named_properties.contains(&("p1".to_string(), "3{x}: @{x}: AG EF {x}".to_string())));
-        assert!(named_properties.contains(&("p2".to_string(), "3{x}: @{x}: AX AF {x}".to_string())));
+        assert!(
+            named_properties.contains(&("p1".to_string(), "3{x}: @{x}: AG EF {x}".to_string()))
+        );
+        assert!(
+            named_properties.contains(&("p2".to_string(), "3{x}: @{x}: AX AF {x}".to_string()))
+        );
     }
 
     #[test]
@@ -261,4 +383,51 @@ mod tests {
             "Found multiple properties named `p1`."
         );
     }
+
+    #[test]
+    /// Test that checking the assertion and properties together gives the same results
+    /// as checking them one by one, restricted to the colors that satisfy the assertion.
+    fn test_model_check_assertion_and_properties() {
+        // The update function of `v_3` has four instances, one of which (negation) has
+        // no fixed point.
+        let aeon_str = r"
+            $v_2:!v_3
+            v_3 -| v_2
+            v_3 -?? v_3
+        ";
+        let bn = BooleanNetwork::try_from(aeon_str).unwrap();
+        let ctx = SymbolicContext::new(&bn).unwrap();
+        let assertion = "3{x}: @{x}: AX {x}";
+        let properties = ["AG EF v_3", "3{x}: @{x}: AX {x} & v_2"];
+        let assertion_tree = parse_and_minimize_hctl_formula(&ctx, assertion).unwrap();
+        let property_trees = properties
+            .iter()
+            .map(|it| parse_and_minimize_hctl_formula(&ctx, it).unwrap())
+            .collect::<Vec<_>>();
+        let graph = get_extended_symbolic_graph(&bn, 1).unwrap();
+
+        let (assertion_result, property_results) = model_check_assertion_and_properties(
+            &graph,
+            assertion_tree.clone(),
+            property_trees.clone(),
+        )
+        .unwrap();
+        let valid_colors = get_universal_colors(&graph, &assertion_result);
+        let property_colors = get_valid_property_colors(&graph, &valid_colors, &property_results);
+
+        let expected_valid = model_check_tree_dirty(assertion_tree, &graph).unwrap();
+        let expected_valid = get_universal_colors(&graph, &expected_valid);
+        assert_eq!(valid_colors, expected_valid);
+        assert_eq!(valid_colors.approx_cardinality(), 3.0);
+
+        assert_eq!(property_colors.len(), properties.len());
+        for (colors, tree) in property_colors.iter().zip(property_trees) {
+            let expected = model_check_tree_dirty(tree, &graph).unwrap();
+            let expected = get_universal_colors(&graph, &expected).intersect(&valid_colors);
+            assert_eq!(colors, &expected);
+        }
+
+        // The negation satisfies `AG EF v_3`, but not the assertion.
+        assert_eq!(property_colors[0].approx_cardinality(), 1.0);
+    }
 }
@@ -5,10 +5,65 @@ use std::fs::File;
 use std::io::Read;
 
 use biodivine_lib_bdd::Bdd;
-use biodivine_lib_param_bn::BooleanNetwork;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColors, SymbolicAsyncGraph};
+use biodivine_lib_param_bn::{BooleanNetwork, ModelAnnotation};
 use zip::ZipArchive;
 
+/// Combine all HCTL assertions in the given list into a single conjunction of assertions.
+///
+/// Each assertion is wrapped in parentheses. An empty list results in the `true` formula.
+pub fn build_combined_assertion(assertions: &[String]) -> String {
+    if assertions.is_empty() {
+        "true".to_string()
+    } else {
+        assertions
+            .iter()
+            .map(|it| format!("({it})"))
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+}
+
+/// Read the list of *dynamic assertions* from `.aeon` model annotations.
+///
+/// Assertions appear as `#! dynamic_assertion: CONTENT` annotation comments.
+pub fn read_model_assertions(annotations: &ModelAnnotation) -> Vec<String> {
+    let Some(list) = annotations.get_value(&["dynamic_assertion"]) else {
+        return Vec::new();
+    };
+    list.lines().map(|it| it.to_string()).collect()
+}
+
+/// Read the list of named *dynamic properties* from `.aeon` model annotations.
+///
+/// Properties appear as `#! dynamic_property: NAME: CONTENT` annotation comments. The result
+/// is sorted by property name.
+pub fn read_model_properties(
+    annotations: &ModelAnnotation,
+) -> Result<Vec<(String, String)>, String> {
+    let Some(property_node) = annotations.get_child(&["dynamic_property"]) else {
+        return Ok(Vec::new());
+    };
+    let mut properties = Vec::with_capacity(property_node.children().len());
+    for (name, child) in property_node.children() {
+        if !child.children().is_empty() {
+            // This might actually be a valid (if ugly) way for adding extra meta-data to
+            // properties, but let's forbid it for now (the same as in Python).
+            return Err(format!("Property `{name}` contains nested values."));
+        }
+        let Some(value) = child.value() else {
+            return Err(format!("Found empty dynamic property `{name}`."));
+        };
+        if value.lines().count() > 1 {
+            return Err(format!("Found multiple properties named `{name}`."));
+        }
+        properties.push((name.clone(), value.clone()));
+    }
+    // Sort alphabetically to avoid possible non-determinism down the line.
+    properties.sort_by(|(x, _), (y, _)| x.cmp(y));
+    Ok(properties)
+}
+
 /// Read the contents of a file from a zip archive into a string.
 fn read_zip_file(reader: &mut ZipArchive<File>, file_name: &str) -> String {
     let mut contents = String::new();
@@ -1,3 +1,3 @@
-//pub mod classify;
+pub mod classify;
 pub mod load_inputs;
 pub mod write_output;
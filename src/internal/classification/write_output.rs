@@ -4,9 +4,11 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::time::Duration;
 
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColors;
+use serde_json::{Map, Value, json};
 use zip::write::{SimpleFileOptions, ZipWriter};
 
 /// Create classification archive for an arbitrary "map" of `string -> color set`.
@@ -14,6 +16,224 @@ pub fn build_classification_archive(
     categories: HashMap<String, GraphColors>,
     archive_name: &str,
     original_model_str: &str,
+) -> Result<(), std::io::Error> {
+    write_archive(&categories, archive_name, original_model_str, &[])
+}
+
+/// Decompose the `valid_colors` into classes based on the properties that are satisfied.
+///
+/// One class = colors where the same set of properties is satisfied. Each class is identified
+/// by the sorted names of its properties joined by `+++` (this is the same format that is
+/// used to serialize `Class` objects in Python). Empty classes are not included.
+pub fn build_property_classes(
+    valid_colors: &GraphColors,
+    named_properties: &[(String, String)],
+    property_colors: &[GraphColors],
+) -> HashMap<String, GraphColors> {
+    let classes = HashMap::from([(String::new(), valid_colors.clone())]);
+    refine_property_classes(classes, named_properties, property_colors)
+}
+
+/// Split the existing `classes` (identified by the same names as in [build_property_classes])
+/// based on the new properties, such that the name of each property is added to the classes
+/// of the colors that satisfy it. Empty classes are not included.
+pub fn refine_property_classes(
+    classes: HashMap<String, GraphColors>,
+    named_properties: &[(String, String)],
+    property_colors: &[GraphColors],
+) -> HashMap<String, GraphColors> {
+    let mut classes = classes
+        .into_iter()
+        .map(|(name, set)| {
+            let features = name
+                .split("+++")
+                .filter(|it| !it.is_empty())
+                .map(|it| it.to_string())
+                .collect::<Vec<_>>();
+            (features, set)
+        })
+        .collect::<Vec<_>>();
+    for ((name, _), colors) in named_properties.iter().zip(property_colors) {
+        let mut refined = Vec::with_capacity(2 * classes.len());
+        for (features, set) in classes {
+            let without = set.minus(colors);
+            if !without.is_empty() {
+                refined.push((features.clone(), without));
+            }
+            let with = set.intersect(colors);
+            if !with.is_empty() {
+                let mut features = features;
+                features.push(name.clone());
+                refined.push((features, with));
+            }
+        }
+        classes = refined;
+    }
+
+    let mut result: HashMap<String, GraphColors> = HashMap::new();
+    for (mut features, set) in classes {
+        features.sort();
+        let name = features.join("+++");
+        // Different input names can (in theory) result in the same class.
+        let set = match result.remove(&name) {
+            Some(existing) => existing.union(&set),
+            None => set,
+        };
+        result.insert(name, set);
+    }
+    result
+}
+
+/// Statistics about a single run of the BN Classifier workflow that are included in the
+/// machine-readable `report.json` file.
+#[derive(Clone, Debug, Default)]
+pub struct ClassificationStats {
+    /// The number of state variables in the symbolic encoding.
+    pub state_variables: usize,
+    /// The number of parameter (color) variables in the symbolic encoding.
+    pub parameter_variables: usize,
+    /// The number of HCTL variables that the encoding was extended with.
+    pub hctl_variables: usize,
+    /// The (approximate) number of all instances admitted by the model.
+    pub total_instances: f64,
+    /// The duration of the individual phases of the workflow (in the order of execution).
+    pub timings: Vec<(String, Duration)>,
+}
+
+/// Generate the classification archive for the results of the BN Classifier workflow.
+///
+/// Besides the BDD dumps of the individual classes (see [build_property_classes]) and the
+/// original model, the archive contains a human-readable `report.txt` summary and
+/// a machine-readable `report.json` summary (see [ClassificationStats]).
+pub fn write_classifier_output(
+    assertions: &[String],
+    valid_colors: &GraphColors,
+    named_properties: &[(String, String)],
+    property_colors: &[GraphColors],
+    stats: &ClassificationStats,
+    archive_name: &str,
+    original_model_str: &str,
+) -> Result<(), std::io::Error> {
+    let classes = build_property_classes(valid_colors, named_properties, property_colors);
+    let mut class_names = classes.keys().cloned().collect::<Vec<_>>();
+    class_names.sort();
+
+    let mut report = report_intro(assertions, named_properties);
+    report.push_str(&format!(
+        "{:.0} instances satisfy all assertions.\n",
+        valid_colors.approx_cardinality()
+    ));
+    let mut json_classes = Vec::with_capacity(class_names.len());
+    for class_name in class_names {
+        let properties = class_name
+            .split("+++")
+            .filter(|it| !it.is_empty())
+            .collect::<Vec<_>>();
+        let colors = &classes[&class_name];
+        report.push_str(&format!(
+            "[{}]: {:.0} instances\n",
+            properties.join(", "),
+            colors.approx_cardinality()
+        ));
+        json_classes.push(json!({
+            "properties": properties,
+            "instances": colors.approx_cardinality(),
+            "bdd_size": colors.symbolic_size(),
+        }));
+    }
+
+    let json_report = report_json(
+        assertions,
+        named_properties,
+        stats,
+        valid_colors.approx_cardinality(),
+        json_classes,
+    );
+    write_archive(
+        &classes,
+        archive_name,
+        original_model_str,
+        &[("report.txt", report), ("report.json", json_report)],
+    )
+}
+
+/// Generate the classification archive in case no instance satisfies the `assertions`.
+///
+/// The archive contains no classes, just the original model and the report files.
+pub fn write_empty_report(
+    assertions: &[String],
+    named_properties: &[(String, String)],
+    stats: &ClassificationStats,
+    archive_name: &str,
+    original_model_str: &str,
+) -> Result<(), std::io::Error> {
+    let mut report = report_intro(assertions, named_properties);
+    report.push_str("No instance satisfies all assertions.\n");
+    let json_report = report_json(assertions, named_properties, stats, 0.0, Vec::new());
+    write_archive(
+        &HashMap::new(),
+        archive_name,
+        original_model_str,
+        &[("report.txt", report), ("report.json", json_report)],
+    )
+}
+
+/// The part of the `report.txt` that lists the assertions and properties.
+fn report_intro(assertions: &[String], named_properties: &[(String, String)]) -> String {
+    let mut report = format!("### Assertions ({})\n", assertions.len());
+    for assertion in assertions {
+        report.push_str(&format!("# {assertion}\n"));
+    }
+    report.push_str(&format!("\n### Properties ({})\n", named_properties.len()));
+    for (name, formula) in named_properties {
+        report.push_str(&format!("# {name}: {formula}\n"));
+    }
+    report.push_str("\n### Classes\n");
+    report
+}
+
+/// The contents of the `report.json` file. The `classes` are already converted to JSON objects.
+fn report_json(
+    assertions: &[String],
+    named_properties: &[(String, String)],
+    stats: &ClassificationStats,
+    valid_instances: f64,
+    classes: Vec<Value>,
+) -> String {
+    let properties = named_properties
+        .iter()
+        .map(|(name, formula)| json!({ "name": name, "formula": formula }))
+        .collect::<Vec<_>>();
+    let timings = stats
+        .timings
+        .iter()
+        .map(|(phase, duration)| (phase.clone(), Value::from(duration.as_secs_f64())))
+        .collect::<Map<_, _>>();
+    let report = json!({
+        "assertions": assertions,
+        "properties": properties,
+        "encoding": {
+            "state_variables": stats.state_variables,
+            "parameter_variables": stats.parameter_variables,
+            "hctl_variables": stats.hctl_variables,
+        },
+        "instances": {
+            "total": stats.total_instances,
+            "valid": valid_instances,
+        },
+        "classes": classes,
+        "timings_seconds": timings,
+    });
+    serde_json::to_string_pretty(&report).expect("JSON serialization cannot fail.")
+}
+
+/// Write the given `categories`, the original model and any number of `extra_files`
+/// (name and contents) into a new classification archive.
+fn write_archive(
+    categories: &HashMap<String, GraphColors>,
+    archive_name: &str,
+    original_model_str: &str,
+    extra_files: &[(&str, String)],
 ) -> Result<(), std::io::Error> {
     let archive_path = Path::new(archive_name);
     // If there are some non-existing dirs in the path, create them.
@@ -38,6 +258,13 @@ pub fn build_classification_archive(
         }
     }
 
+    for (file_name, contents) in extra_files {
+        zip_writer
+            .start_file(*file_name, SimpleFileOptions::default())
+            .map_err(std::io::Error::from)?;
+        write!(zip_writer, "{contents}")?;
+    }
+
     // Include the original model in the result bundle (we need to load it later).
     zip_writer
         .start_file("model.aeon", SimpleFileOptions::default())
@@ -1,8 +1,8 @@
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
+use crate::internal::scc::SccError;
 use crate::internal::scc::algo_interleaved_transition_guided_reduction::{
     BwdProcess, ExtendedComponentProcess, Process, Scheduler,
 };
@@ -26,7 +26,11 @@ impl ExtendedComponentProcess {
 }
 
 impl Process for ExtendedComponentProcess {
-    fn step(&mut self, scheduler: &mut Scheduler, graph: &SymbolicAsyncGraph) -> PyResult<bool> {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<bool, SccError> {
         if self.bwd.step(scheduler, graph)? {
             let extended_component = self.bwd.get_reachable_set();
             let bottom = self.fwd_set.minus(extended_component);
@@ -75,6 +79,7 @@ impl Process for ExtendedComponentProcess {
                     &bottom,
                     scheduler.get_universe(),
                     scheduler.get_active_variables(),
+                    scheduler.get_cancellation(),
                     scheduler.log_level,
                 )?
                 .minus(&bottom);
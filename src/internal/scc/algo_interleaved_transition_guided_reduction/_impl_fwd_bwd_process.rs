@@ -1,7 +1,7 @@
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
+use crate::internal::scc::SccError;
 use crate::internal::scc::algo_interleaved_transition_guided_reduction::{
     BwdProcess, FwdProcess, Process, Scheduler,
 };
@@ -35,11 +35,16 @@ impl FwdProcess {
 }
 
 impl Process for BwdProcess {
-    fn step(&mut self, scheduler: &mut Scheduler, graph: &SymbolicAsyncGraph) -> PyResult<bool> {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<bool, SccError> {
         let result = reachability_step(
             &mut self.bwd,
             &self.universe,
             scheduler.get_active_variables(),
+            scheduler.get_cancellation(),
             |var, set| graph.var_pre(var, set),
         );
 
@@ -69,11 +74,16 @@ impl Process for BwdProcess {
 }
 
 impl Process for FwdProcess {
-    fn step(&mut self, scheduler: &mut Scheduler, graph: &SymbolicAsyncGraph) -> PyResult<bool> {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<bool, SccError> {
         let result = reachability_step(
             &mut self.fwd,
             &self.universe,
             scheduler.get_active_variables(),
+            scheduler.get_cancellation(),
             |var, set| graph.var_post(var, set),
         );
 
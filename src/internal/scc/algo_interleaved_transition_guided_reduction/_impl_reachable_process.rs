@@ -1,8 +1,8 @@
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
+use crate::internal::scc::SccError;
 use crate::internal::scc::algo_interleaved_transition_guided_reduction::{
     ExtendedComponentProcess, FwdProcess, Process, ReachableProcess, Scheduler,
 };
@@ -24,7 +24,11 @@ impl ReachableProcess {
 }
 
 impl Process for ReachableProcess {
-    fn step(&mut self, scheduler: &mut Scheduler, graph: &SymbolicAsyncGraph) -> PyResult<bool> {
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<bool, SccError> {
         if self.fwd.step(scheduler, graph)? {
             let fwd_set = self.fwd.get_reachable_set();
 
@@ -42,6 +46,7 @@ impl Process for ReachableProcess {
                     fwd_set,
                     scheduler.get_universe(),
                     scheduler.get_active_variables(),
+                    scheduler.get_cancellation(),
                     scheduler.log_level,
                 )?
                 .minus(fwd_set);
@@ -1,8 +1,9 @@
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
+use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::scc::SccError;
 use crate::internal::scc::algo_interleaved_transition_guided_reduction::{Process, Scheduler};
 use crate::{log_essential, should_log};
 
@@ -11,6 +12,7 @@ impl Scheduler {
     pub fn new(
         initial: GraphColoredVertices,
         variables: Vec<VariableId>,
+        cancellation: Box<dyn CancellationHandler>,
         log_level: usize,
     ) -> Scheduler {
         Scheduler {
@@ -18,6 +20,7 @@ impl Scheduler {
             universe: initial,
             processes: Vec::new(),
             to_discard: None,
+            cancellation,
             log_level,
         }
     }
@@ -63,13 +66,27 @@ impl Scheduler {
         &self.active_variables
     }
 
+    /// Get the cancellation handler of this scheduler.
+    pub fn get_cancellation(&self) -> &dyn CancellationHandler {
+        self.cancellation.as_ref()
+    }
+
+    /// Returns [SccError::Cancelled] if the computation has been cancelled.
+    pub fn check_cancelled(&self) -> Result<(), SccError> {
+        if self.cancellation.is_cancelled() {
+            Err(SccError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
     /// True if all processes are finished.
     pub fn is_done(&self) -> bool {
         self.processes.is_empty()
     }
 
     /// If possible, perform one computational step for one of the processes.
-    pub fn step(&mut self, graph: &SymbolicAsyncGraph) -> PyResult<()> {
+    pub fn step(&mut self, graph: &SymbolicAsyncGraph) -> Result<(), SccError> {
         if self.is_done() {
             return Ok(());
         }
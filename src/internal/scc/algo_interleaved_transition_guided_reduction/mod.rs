@@ -5,11 +5,11 @@
 //! a very reasonable amount of time.
 //!
 
-use crate::bindings::global_interrupt;
+use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::scc::SccError;
 use crate::should_log;
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
 mod _impl_extended_component_process;
 mod _impl_fwd_bwd_process;
@@ -22,13 +22,15 @@ mod _impl_scheduler;
 /// It also returns a list of system variables for which there are still
 /// transitions in the graph (other variables are effectively constant).
 ///
-/// If canceled, the result is still valid, but not necessarily complete.
+/// The computation can be stopped using the `cancellation` handler, in which case
+/// [SccError::Cancelled] is returned.
 pub fn interleaved_transition_guided_reduction(
     graph: &SymbolicAsyncGraph,
     initial: GraphColoredVertices,
     to_reduce: &[VariableId],
+    cancellation: &dyn CancellationHandler,
     log_level: usize,
-) -> PyResult<(GraphColoredVertices, Vec<VariableId>)> {
+) -> Result<(GraphColoredVertices, Vec<VariableId>), SccError> {
     if should_log(log_level) {
         println!(
             "Start interleaved transition guided reduction with {}[nodes:{}] candidates.",
@@ -38,9 +40,14 @@ pub fn interleaved_transition_guided_reduction(
     }
 
     let variables = graph.variables().collect::<Vec<_>>();
-    let mut scheduler = Scheduler::new(initial, variables, log_level);
+    let mut scheduler = Scheduler::new(
+        initial,
+        variables,
+        dyn_clone::clone_box(cancellation),
+        log_level,
+    );
     for variable in to_reduce {
-        global_interrupt()?;
+        scheduler.check_cancelled()?;
         scheduler.spawn(ReachableProcess::new(
             *variable,
             graph,
@@ -49,7 +56,7 @@ pub fn interleaved_transition_guided_reduction(
     }
 
     while !scheduler.is_done() {
-        global_interrupt()?;
+        scheduler.check_cancelled()?;
         scheduler.step(graph)?;
     }
 
@@ -73,11 +80,15 @@ trait Process {
     /// Perform one step in the process. This can perform multiple symbolic operations,
     /// but should be fairly simple (i.e., does not need interrupting).
     ///
-    /// If you still need to run a complex operation, you should check `GraphTaskContext`
-    /// provided by `Scheduler` for cancellation.
+    /// If you still need to run a complex operation, you should check the cancellation handler
+    /// provided by `Scheduler`.
     ///
     /// Returns true if the process cannot perform more steps.
-    fn step(&mut self, scheduler: &mut Scheduler, graph: &SymbolicAsyncGraph) -> PyResult<bool>;
+    fn step(
+        &mut self,
+        scheduler: &mut Scheduler,
+        graph: &SymbolicAsyncGraph,
+    ) -> Result<bool, SccError>;
 
     /// Approximate symbolic complexity of the process.
     fn weight(&self) -> usize;
@@ -93,6 +104,7 @@ struct Scheduler {
     universe: GraphColoredVertices,
     processes: Vec<(usize, Box<dyn Process>)>,
     to_discard: Option<GraphColoredVertices>,
+    cancellation: Box<dyn CancellationHandler>,
     log_level: usize,
 }
 
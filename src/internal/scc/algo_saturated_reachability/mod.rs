@@ -1,9 +1,9 @@
-use crate::bindings::global_interrupt;
+use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::scc::SccError;
 use crate::log_essential;
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 
 /// Performs one reachability step using the saturation scheme.
 ///
@@ -16,8 +16,9 @@ pub fn reachability_step<F>(
     set: &mut GraphColoredVertices,
     universe: &GraphColoredVertices,
     variables: &[VariableId],
+    cancellation: &dyn CancellationHandler,
     step: F,
-) -> PyResult<bool>
+) -> Result<bool, SccError>
 where
     F: Fn(VariableId, &GraphColoredVertices) -> GraphColoredVertices,
 {
@@ -25,7 +26,9 @@ where
         return Ok(true);
     }
     for var in variables.iter().rev() {
-        global_interrupt()?;
+        if cancellation.is_cancelled() {
+            return Err(SccError::Cancelled);
+        }
         let stepped = step(*var, set).minus(set).intersect(universe);
 
         if !stepped.is_empty() {
@@ -60,18 +63,21 @@ pub fn reach_fwd(
 /// Fully compute back-reachable states from `initial` inside `universe` using transitions under
 /// `variables`.
 ///
-/// The process is cancellable using the `GraphTaskContext`, in which case the result is valid,
-/// but not complete.
+/// The process is cancellable using the `cancellation` handler, in which case
+/// [SccError::Cancelled] is returned.
 pub fn reach_bwd(
     graph: &SymbolicAsyncGraph,
     initial: &GraphColoredVertices,
     universe: &GraphColoredVertices,
     variables: &[VariableId],
+    cancellation: &dyn CancellationHandler,
     log_level: usize,
-) -> PyResult<GraphColoredVertices> {
+) -> Result<GraphColoredVertices, SccError> {
     let mut set = initial.clone();
     loop {
-        if reachability_step(&mut set, universe, variables, |v, s| graph.var_pre(v, s))? {
+        if reachability_step(&mut set, universe, variables, cancellation, |v, s| {
+            graph.var_pre(v, s)
+        })? {
             break;
         }
 
@@ -1,20 +1,24 @@
-use crate::bindings::global_interrupt;
+use crate::internal::algorithms::cancellation::CancellationHandler;
+use crate::internal::scc::SccError;
 use crate::internal::scc::algo_saturated_reachability::{reach_bwd, reachability_step};
 use crate::{log_essential, should_log};
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use pyo3::PyResult;
 use std::io::Write;
 
 /// Uses a simplified Xie-Beerel algorithm adapted to coloured setting to find all bottom
 /// SCCs in the given `universe` set. It only tests transitions using `active_variables`.
+///
+/// The computation can be stopped using the `cancellation` handler, in which case
+/// [SccError::Cancelled] is returned.
 pub fn xie_beerel_attractors(
     graph: &SymbolicAsyncGraph,
     universe: &GraphColoredVertices,
     active_variables: &[VariableId],
+    cancellation: &dyn CancellationHandler,
     log_level: usize,
-) -> PyResult<Vec<GraphColoredVertices>> {
+) -> Result<Vec<GraphColoredVertices>, SccError> {
     if should_log(log_level) {
         println!(
             "Start Xie-Beerel attractor detection on {}[nodes:{}] candidates.",
@@ -38,7 +42,14 @@ pub fn xie_beerel_attractors(
 
         let pivots = universe.pick_vertex();
 
-        let pivot_basin = reach_bwd(graph, &pivots, &universe, active_variables, log_level)?;
+        let pivot_basin = reach_bwd(
+            graph,
+            &pivots,
+            &universe,
+            active_variables,
+            cancellation,
+            log_level,
+        )?;
 
         let mut pivot_component = pivots.clone();
 
@@ -52,6 +63,7 @@ pub fn xie_beerel_attractors(
                 &mut pivot_component,
                 &universe,
                 active_variables,
+                cancellation,
                 |var, set| graph.var_post(var, set),
             )?;
 
@@ -95,7 +107,9 @@ pub fn xie_beerel_attractors(
         }
 
         universe = universe.minus(&pivot_basin);
-        global_interrupt()?;
+        if cancellation.is_cancelled() {
+            return Err(SccError::Cancelled);
+        }
     }
 
     if should_log(log_level) {
@@ -10,6 +10,9 @@ mod _impl_classifier;
 pub mod algo_interleaved_transition_guided_reduction;
 pub mod algo_saturated_reachability;
 pub mod algo_xie_beerel;
+mod scc_error;
+
+pub use scc_error::SccError;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Behaviour {
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+/// An error returned by the legacy SCC algorithms in this module.
+#[derive(Debug, Error)]
+pub enum SccError {
+    #[error("operation cancelled")]
+    Cancelled,
+    #[error("cannot write progress output: {0}")]
+    Io(#[from] std::io::Error),
+}
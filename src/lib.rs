@@ -29,6 +29,21 @@ pub mod bindings;
 mod internal;
 mod pyo3_utils;
 
+/// The legacy SCC algorithms (saturated reachability, interleaved transition guided reduction
+/// and Xie-Beerel attractor detection). These do not require a Python interpreter, and report
+/// cancellation through a [cancellation::CancellationHandler].
+pub use internal::scc::{
+    SccError, algo_interleaved_transition_guided_reduction, algo_saturated_reachability,
+    algo_xie_beerel,
+};
+
+/// Cancellation handlers that can be used to stop the algorithms of this crate.
+pub use internal::algorithms::cancellation;
+
+/// Run the full BN Classifier workflow on an annotated `.aeon` model and save the result into
+/// a classification archive. This is also available as the `classify` binary.
+pub use internal::classification::classify::classify;
+
 fn set_log_level(_py: Python, module: &Bound<'_, PyModule>) -> PyResult<()> {
     /* Disable default logging. Turns out this is not so useful after all.
 
@@ -90,7 +105,6 @@ fn biodivine_aeon(py: Python, module: &Bound<'_, PyModule>) -> PyResult<()> {
     bindings::lib_hctl_model_checker::register(module)?;
     bindings::bn_classifier::register(module)?;
     bindings::pbn_control::register(module)?;
-    #[cfg(feature = "algorithms-pyo3-bindings")]
     bindings::algorithms::register(module)?;
     Ok(())
 }
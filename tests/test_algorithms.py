@@ -1,3 +1,5 @@
+import pytest
+
 from biodivine_aeon import *
 
 
@@ -75,4 +77,305 @@ def test_percolation_case_1():
     for v in inputs:
         if bn.get_variable_name(v) == 'APC':
             continue
-        assert v not in percolated
\ No newline at end of file
+        assert v not in percolated
+
+def test_attractors_iterator():
+    bn = BooleanNetwork.from_file("./tests/model-myeloid-3-unknown.aeon")
+    stg = AsynchronousGraph(bn)
+    comp = AttractorsComp.create_from(stg)
+    expected = comp.attractors()
+    assert len(expected) > 1
+
+    # The iterator yields the attractors one by one, and `found` always contains
+    # all attractors that were yielded so far.
+    iterator = comp.attractors_iter()
+    assert iterator.found() == []
+    found = []
+    for attractor in iterator:
+        found.append(attractor)
+        assert iterator.found() == found
+    assert found == expected
+
+    with pytest.raises(StopIteration):
+        next(iterator)
+    assert iterator.found() == expected
+
+    assert list(comp.xie_beerel_iter()) == comp.xie_beerel()
+
+def test_witness_path():
+    bn = BooleanNetwork.from_aeon("a -> b\nb -> c\n$a: true\n$b: a\n$c: b\n")
+    stg = AsynchronousGraph(bn)
+    reachability = ReachabilityComp.create_from(stg)
+
+    source = stg.mk_subspace({"a": False, "b": False, "c": False})
+    (path, variables) = reachability.witness_path(source, stg.mk_subspace({"c": True}))
+    assert variables == [bn.find_variable(x) for x in ["a", "b", "c"]]
+    assert len(path) == 4
+    assert path[0]["c"] == False
+    assert path[-1]["c"] == True
+
+    # Once `a` is set, it can never be unset again.
+    source = stg.mk_subspace({"a": True})
+    assert reachability.witness_path(source, stg.mk_subspace({"a": False})) is None
+
+def test_legacy_algorithms_are_deprecated():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    stg = AsynchronousGraph(bn)
+    unit = stg.mk_unit_colored_vertices()
+
+    with pytest.warns(DeprecationWarning):
+        legacy = Reachability.reach_fwd(stg, unit.pick_vertex())
+
+    assert legacy == ReachabilityComp.create_from(stg).forward_closed_superset(unit.pick_vertex())
+
+def test_legacy_attractors_to_reduce():
+    # `b` oscillates and `a` follows `b`, hence all four states form one attractor.
+    bn = BooleanNetwork.from_aeon("b -> a\nb -| b\n$a: b\n$b: !b\n")
+    stg = AsynchronousGraph(bn)
+    unit = stg.mk_unit_colored_vertices()
+
+    with pytest.warns(DeprecationWarning):
+        reduced = Attractors.transition_guided_reduction(stg, to_reduce=["a"])
+    assert reduced == unit
+
+    with pytest.warns(DeprecationWarning):
+        attractors = Attractors.attractors(stg, to_reduce=["a"])
+    assert attractors == [unit]
+
+def test_cancelled_error_has_partial_result():
+    bn = BooleanNetwork.from_file("./example/workflow/data/g2a_p1026.aeon")
+    stg = AsynchronousGraph(bn)
+    unit = stg.mk_unit_colored_vertices()
+
+    config = ReachabilityConfig(stg, steps_limit=1)
+    try:
+        ReachabilityComp.with_config(config).forward_closed_superset(unit.pick_vertex())
+        assert False, "Expected the steps limit to be exceeded."
+    except StepsLimitExceededError as error:
+        assert isinstance(error.partial_result, ColoredVertexSet)
+        assert error.partial_result.is_subset(unit)
+
+def test_progress_callback():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    stg = AsynchronousGraph(bn)
+    unit = stg.mk_unit_colored_vertices()
+
+    events = []
+    config = ReachabilityConfig(stg, progress=events.append)
+    result = ReachabilityComp.with_config(config).forward_closed_superset(unit.pick_vertex())
+
+    assert len(events) > 0
+    assert all(e.target == "Reachability::forward_closed_superset" for e in events)
+    assert [e.step for e in events] == list(range(1, len(events) + 1))
+    assert events[-1].candidates == result.cardinality()
+
+def test_shared_cancel_token():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    stg = AsynchronousGraph(bn)
+    unit = stg.mk_unit_colored_vertices()
+
+    manual = CancelToken.manual()
+    budget = CancelToken.step_budget(1)
+    token = CancelToken.any_of([manual, budget])
+    assert not token.is_cancelled()
+
+    # Every color has a transition from this vertex, hence at least one step is needed.
+    initial = unit.minus(FixedPointsComp.create_from(stg).symbolic()).pick_vertex()
+
+    config = ReachabilityConfig(stg).with_cancellation(token)
+    try:
+        ReachabilityComp.with_config(config).forward_closed_superset(initial)
+    except CancelledError:
+        pass
+
+    # The budget is shared, hence any further computation is cancelled immediately.
+    assert budget.is_cancelled() and token.is_cancelled()
+    try:
+        ReachabilityComp.with_config(config).backward_closed_superset(initial)
+        assert False, "Expected the computation to be cancelled."
+    except CancelledError:
+        pass
+
+    assert manual.cancel()
+    assert not manual.cancel()
+    assert CancelToken.all_of([manual, CancelToken.manual()]).is_cancelled() is False
+
+def test_timer_cancel_token():
+    import pytest
+    import time
+
+    timer = CancelToken.timer(50)
+    # A timer that was not started is never cancelled.
+    assert not timer.is_cancelled()
+    assert timer.remaining_millis() == 50
+    assert not timer.pause()
+
+    assert timer.start()
+    assert not timer.start()
+    assert timer.pause()
+    time.sleep(0.1)
+    # Time spent while paused does not count.
+    assert not timer.is_cancelled()
+
+    assert timer.resume()
+    time.sleep(0.1)
+    assert timer.is_cancelled()
+    assert timer.remaining_millis() == 0
+
+    timer.extend(10_000)
+    assert not timer.is_cancelled()
+    assert timer.remaining_millis() > 0
+
+    timer.reset()
+    assert timer.elapsed_millis() == 0
+    assert not timer.is_cancelled()
+
+    with pytest.raises(TypeError):
+        CancelToken.manual().pause()
+
+def test_fixed_points_projection():
+    bn = BooleanNetwork.from_file("./example/workflow/data/g2a_p1026.aeon")
+    stg = AsynchronousGraph(bn)
+    comp = FixedPointsComp.create_from(stg)
+
+    fixed_points = comp.symbolic()
+    assert comp.symbolic_projection() == fixed_points
+    assert comp.symbolic_projection(retained_functions=[]).vertices() == comp.symbolic_vertices()
+    assert comp.symbolic_projection(retained_variables=[]).colors() == comp.symbolic_colors()
+
+    output = bn.variable_names()[0]
+    projected = comp.symbolic_projection(retained_variables=[output], retained_functions=[])
+    assert fixed_points.is_subset(projected)
+
+    expected = {m[output] for _, m in fixed_points.items(retained_variables=[output], retained_functions=[])}
+    actual = {m[output] for _, m in projected.items(retained_variables=[output], retained_functions=[])}
+    assert expected == actual
+
+def test_fixed_points_solver_iterator():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    stg = AsynchronousGraph(bn)
+    comp = FixedPointsComp.create_from(stg)
+
+    iterator = comp.solver_iterator()
+    found = stg.mk_empty_colored_vertices()
+    for color, vertex in iterator:
+        fixed_point = vertex.to_symbolic().extend_with_colors(color.to_symbolic())
+        assert fixed_point.cardinality() == 1
+        assert fixed_point.intersect(found).is_empty()
+        found = found.union(fixed_point)
+
+    assert found == comp.symbolic()
+    assert iterator.found() == found
+
+def test_minimal_trap_spaces_solver():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    ctx = SymbolicSpaceContext(bn)
+    stg = AsynchronousGraph(bn, ctx)
+    comp = TrapSpacesComp.create_from_graph_with_context(stg, ctx)
+
+    minimal = comp.minimal_symbolic()
+    assert comp.minimal_solver() == minimal
+
+    limited = comp.minimal_solver(limit=1)
+    assert limited.cardinality() == 1
+    assert limited.is_subset(minimal)
+
+    count = 0
+    for color, space in comp.minimal_solver_iterator():
+        assert space.to_symbolic().extend_with_colors(color.to_symbolic()).is_subset(minimal)
+        count += 1
+    assert count == minimal.cardinality()
+
+def test_maximal_trap_spaces_and_counts():
+    bn = BooleanNetwork.from_file("./example/workflow/data/g2a_p1026.aeon")
+    ctx = SymbolicSpaceContext(bn)
+    stg = AsynchronousGraph(bn, ctx)
+    comp = TrapSpacesComp.create_from_graph_with_context(stg, ctx)
+
+    maximal = comp.maximal_symbolic()
+    assert maximal == comp.maximize(maximal)
+    assert maximal.is_subset(ctx.mk_unit_colored_spaces(stg))
+
+    minimal = comp.minimal_symbolic()
+    counts = comp.count_per_color(minimal)
+    assert sum(colors.cardinality() * count for count, colors in counts.items()) == minimal.cardinality()
+    all_colors = stg.mk_empty_colors()
+    for colors in counts.values():
+        assert colors.intersect(all_colors).is_empty()
+        all_colors = all_colors.union(colors)
+    assert all_colors == stg.mk_unit_colors()
+
+def test_trap_spaces_from_graph():
+    bn = BooleanNetwork.from_file("./tests/model-3.aeon")
+    stg = AsynchronousGraph(bn)
+
+    minimal = TrapSpacesComp.create_from(bn).minimal_symbolic()
+    assert TrapSpacesComp.create_from(stg).minimal_symbolic() == minimal
+
+    # Only spaces within the restricted unit set are considered.
+    restricted = stg.restrict(stg.mk_subspace_vertices({"FOXP3": True}))
+    restricted_minimal = TrapSpacesComp.create_from(restricted).minimal_symbolic()
+    assert not restricted_minimal.is_empty()
+    for space in restricted_minimal.spaces():
+        assert space["FOXP3"] is True
+
+    # Graphs without an underlying network are reconstructed.
+    inlined = stg.inline_variable_symbolic("IFNG")
+    assert not TrapSpacesComp.create_from(inlined).minimal_symbolic().is_empty()
+
+def test_percolate_symbolic():
+    bn = BooleanNetwork.from_aeon("""
+    b -?? a
+    a -> b
+    $b: a
+    """)
+    ctx = SymbolicSpaceContext(bn)
+    stg = AsynchronousGraph(bn, ctx)
+    comp = PercolationComp.create_from(stg)
+
+    # The update function of `a` is unknown, hence `a` only percolates for individual colors.
+    assert len(comp.percolate_subspace({"b": True})) == 1
+
+    a_t, a_f = ctx.get_positive_space_variable("a"), ctx.get_negative_space_variable("a")
+    b_t, b_f = ctx.get_positive_space_variable("b"), ctx.get_negative_space_variable("b")
+    space = ctx.bdd_variable_set().mk_conjunctive_clause({a_t: True, a_f: True, b_t: True, b_f: False})
+    spaces = ctx.mk_unit_colored_spaces(stg).intersect(ColoredSpaceSet(ctx, space))
+
+    percolated = comp.percolate_symbolic(spaces)
+    assert percolated.colors() == spaces.colors()
+    assert percolated.cardinality() == spaces.colors().cardinality()
+    for _color, model in percolated:
+        assert model["a"] is not None
+        assert model["b"] is True
+
+def test_percolate_spaces():
+    bn = BooleanNetwork.from_aeon("""
+    a -> b
+    b -| c
+    $a: a
+    $b: a
+    $c: !b
+    """)
+    ctx = SymbolicSpaceContext(bn)
+    stg = AsynchronousGraph(bn, ctx)
+    comp = PercolationComp.create_from(stg)
+
+    a, b, c = bn.find_variable("a"), bn.find_variable("b"), bn.find_variable("c")
+    def mk_space(values):
+        clause = {}
+        for var in [a, b, c]:
+            clause[ctx.get_positive_space_variable(var)] = values.get(var, True)
+            clause[ctx.get_negative_space_variable(var)] = not values.get(var, False)
+        return SpaceSet(ctx, ctx.bdd_variable_set().mk_conjunctive_clause(clause))
+
+    spaces = mk_space({a: True}).union(mk_space({a: True, c: True}))
+    results = comp.percolate_spaces(spaces)
+    assert len(results) == 2
+    for original, percolated, conflicts, is_trap_space in results:
+        assert percolated == {a: True, b: True, c: False} or percolated == {a: True, b: True, c: True}
+        if c in original:
+            assert conflicts == [c]
+            assert not is_trap_space
+        else:
+            assert conflicts == []
+            assert is_trap_space
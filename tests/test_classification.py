@@ -1,6 +1,9 @@
 from biodivine_aeon import *
 import pickle
 import os
+import json
+import zipfile
+import pytest
 
 
 def test_class():
@@ -145,6 +148,103 @@ def test_property_classification():
 
     os.remove("classification.test.2.zip")
 
+def test_classify_archive():
+    path = "./tests/model-with-properties.aeon"
+
+    Classification.classify(path, "classification.test.3.zip")
+    (bn, mapping, annotations) = Classification.load_classification("classification.test.3.zip")
+
+    assert len(mapping) == 3
+    assert mapping[Class("p2")].cardinality() == 3
+    assert mapping[Class("p4")].cardinality() == 3
+    assert mapping[Class(["p1", "p2"])].cardinality() == 9
+    assert len(Classification.read_dynamic_properties(annotations)) == 4
+
+    with zipfile.ZipFile("classification.test.3.zip") as archive:
+        report = json.loads(archive.read("report.json"))
+    assert len(report["assertions"]) == 1
+    assert len(report["properties"]) == 4
+    assert report["instances"]["valid"] == 15
+    assert sorted(c["instances"] for c in report["classes"]) == [3, 3, 9]
+    assert report["encoding"]["state_variables"] > 0
+
+    os.remove("classification.test.3.zip")
+
+def test_refine_classification():
+    path = "./tests/model-with-properties.aeon"
+    with open(path) as file:
+        model = file.read()
+    properties = dict(Classification.read_dynamic_properties(model))
+
+    # Only keep the first property in the model and add the rest incrementally.
+    lines = [line for line in model.splitlines() if not line.startswith("#! dynamic_property: p")]
+    lines.append(f"#! dynamic_property: p1: #`{properties['p1']}`#")
+    with open("classification.test.4.aeon", "w") as file:
+        file.write("\n".join(lines))
+
+    Classification.classify("classification.test.4.aeon", "classification.test.4.zip")
+    (bn, mapping, annotations) = Classification.load_classification("classification.test.4.zip")
+    new_properties = {k: v for k, v in properties.items() if k != "p1"}
+    refined = Classification.refine_classification("classification.test.5.zip", bn, mapping, annotations, new_properties)
+
+    assert len(refined) == 3
+    assert refined[Class("p2")].cardinality() == 3
+    assert refined[Class("p4")].cardinality() == 3
+    assert refined[Class(["p1", "p2"])].cardinality() == 9
+
+    (l_bn, l_cls, l_ann) = Classification.load_classification("classification.test.5.zip")
+    assert l_cls == refined
+    assert len(Classification.read_dynamic_properties(l_ann)) == 4
+
+    # A failed refinement does not modify the annotations.
+    (bn, mapping, annotations) = Classification.load_classification("classification.test.4.zip")
+    with pytest.raises(RuntimeError):
+        Classification.refine_classification("classification.test.6.zip", bn, mapping, annotations, properties)
+    assert len(Classification.read_dynamic_properties(annotations)) == 1
+    assert not os.path.exists("classification.test.6.zip")
+
+    os.remove("classification.test.4.aeon")
+    os.remove("classification.test.4.zip")
+    os.remove("classification.test.5.zip")
+
+def test_classification_diff_and_merge():
+    path = "./tests/model-with-properties.aeon"
+    bn = BooleanNetwork.from_file(path)
+    graph = AsynchronousGraph(bn)
+    assertions = Classification.read_dynamic_assertions(path)
+    properties = dict(Classification.read_dynamic_properties(path))
+
+    full = Classification.classify_dynamic_properties(graph, properties, assertions)
+    left = Classification.classify_dynamic_properties(graph, {k: properties[k] for k in ["p1", "p2"]}, assertions)
+    right = Classification.classify_dynamic_properties(graph, {k: properties[k] for k in ["p3", "p4"]}, assertions)
+
+    assert Classification.merge_classification(left, right) == full
+    diff = Classification.diff_classification(left, full)
+    assert list(diff.keys()) == [(Class([]), Class("p4"))]
+    assert diff[(Class([]), Class("p4"))].cardinality() == 3
+    assert len(Classification.diff_classification(full, full)) == 0
+
+    # Colors that only appear in one of the classifications are paired with `None`.
+    partial = {k: v for k, v in full.items() if k != Class("p4")}
+    diff = Classification.diff_classification(left, partial)
+    assert list(diff.keys()) == [(Class([]), None)]
+    assert diff[(Class([]), None)].cardinality() == 3
+    diff = Classification.diff_classification(partial, left)
+    assert list(diff.keys()) == [(None, Class([]))]
+    assert diff[(None, Class([]))].cardinality() == 3
+
+    try:
+        Classification.merge_classification(left, full)
+        assert False
+    except RuntimeError:
+        pass
+
+    assert Classification.check_compatibility(bn, left, bn, right) == []
+    modified = BooleanNetwork.from_aeon(bn.to_aeon())
+    var = [v for v in modified.variables() if modified.get_update_function(v) is not None][0]
+    modified.set_update_function(var, None)
+    assert len(Classification.check_compatibility(bn, left, modified, right)) == 1
+
 def test_phenotype_classification():
     path = "./tests/model-myeloid-3-unknown.aeon"
 